@@ -0,0 +1,114 @@
+//!
+//! A container that adds a Clipped to the qualifier it gets.
+//!
+//! The scroll-view doesn't know which qualifier its content wants,
+//! it passes on whatever it gets together with the clip. The list
+//! looks for a Clipped and a ReadOnly and ignores the rest.
+//!
+
+use rat_event::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::util::clip_hit;
+use rat_event::{
+    ct_event, Clipped, ConsumedEvent, HandleEvent, HasQualifier, Outcome, ReadOnly, Regular,
+};
+use ratatui::layout::Rect;
+
+struct ListState {
+    area: Rect,
+    items: Vec<String>,
+    selected: Option<usize>,
+}
+
+impl<Q> HandleEvent<Event, Q, Outcome> for ListState
+where
+    Q: HasQualifier<Clipped> + HasQualifier<ReadOnly>,
+{
+    fn handle(&mut self, event: &Event, qualifier: Q) -> Outcome {
+        let clip: Option<&Clipped> = qualifier.get();
+        let clip = clip.map_or(self.area, |v| v.0);
+        let read_only: Option<&ReadOnly> = qualifier.get();
+
+        match event {
+            ct_event!(mouse down Left for column, row)
+                if clip_hit(self.area, clip, *column, *row) =>
+            {
+                self.selected = Some((*row - self.area.y) as usize);
+                Outcome::Changed
+            }
+            ct_event!(keycode press Delete) => {
+                if read_only.is_some() {
+                    Outcome::Unchanged
+                } else if let Some(selected) = self.selected.take() {
+                    self.items.remove(selected);
+                    Outcome::Changed
+                } else {
+                    Outcome::Unchanged
+                }
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+struct ScrollView<W> {
+    viewport: Rect,
+    content: W,
+}
+
+impl<W, Q> HandleEvent<Event, Q, Outcome> for ScrollView<W>
+where
+    W: HandleEvent<Event, (Clipped, Q), Outcome>,
+{
+    fn handle(&mut self, event: &Event, qualifier: Q) -> Outcome {
+        let r = self
+            .content
+            .handle(event, (Clipped(self.viewport), qualifier));
+        if r.is_consumed() {
+            return r;
+        }
+        match event {
+            ct_event!(mouse any for m) if self.viewport.contains((m.column, m.row).into()) => {
+                Outcome::Unchanged
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+fn main() {
+    // only the lower half of the list is visible.
+    let mut view = ScrollView {
+        viewport: Rect::new(0, 5, 20, 5),
+        content: ListState {
+            area: Rect::new(0, 0, 20, 10),
+            items: (0..10).map(|v| format!("item {}", v)).collect(),
+            selected: None,
+        },
+    };
+
+    let click = |row| {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 1,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let delete = Event::Key(KeyEvent::new(KeyCode::Delete, KeyModifiers::NONE));
+
+    for row in [2, 7] {
+        let r = view.handle(&click(row), Regular);
+        println!("click row {} -> {:?} {:?}", row, r, view.content.selected);
+    }
+
+    let r = view.handle(&delete, ReadOnly);
+    println!(
+        "read-only delete -> {:?} {} items",
+        r,
+        view.content.items.len()
+    );
+    let r = view.handle(&delete, Regular);
+    println!("delete -> {:?} {} items", r, view.content.items.len());
+}
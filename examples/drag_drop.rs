@@ -0,0 +1,163 @@
+//!
+//! Drag-and-drop between two lists.
+//!
+//! An item can be dragged to another position in the same list,
+//! or to the other list. Dropping it anywhere else cancels the drag
+//! and leaves both lists as they were.
+//!
+
+use rat_event::crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::crossterm::DragDropState;
+use rat_event::util::MouseFlags;
+use rat_event::{broadcast, ct_event, DragDrop, HandleEvent, Outcome, Regular};
+use ratatui::layout::Rect;
+
+struct ListState {
+    id: usize,
+    area: Rect,
+    items: Vec<&'static str>,
+    mouse: MouseFlags,
+    drag: DragDropState<&'static str>,
+    /// Index of the item under the mouse-down.
+    pressed: Option<usize>,
+    /// Index of the dragged item.
+    dragged: Option<usize>,
+}
+
+impl ListState {
+    fn new(
+        id: usize,
+        area: Rect,
+        items: &[&'static str],
+        drag: DragDropState<&'static str>,
+    ) -> Self {
+        Self {
+            id,
+            area,
+            items: items.to_vec(),
+            mouse: Default::default(),
+            drag,
+            pressed: None,
+            dragged: None,
+        }
+    }
+
+    fn row_at(&self, row: u16) -> usize {
+        ((row - self.area.y) as usize).min(self.items.len())
+    }
+
+    /// Remove the item that has been dropped somewhere else.
+    fn remove_dragged(&mut self) -> Outcome {
+        if let Some(dragged) = self.dragged.take() {
+            self.items.remove(dragged);
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+impl HandleEvent<Event, Regular, Outcome> for ListState {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            ct_event!(mouse any for m) if self.mouse.drag(self.area, m) => {
+                if let Some(pressed) = self.pressed.take() {
+                    self.dragged = Some(pressed);
+                    self.drag.start(self.items[pressed], self.id);
+                    Outcome::Changed
+                } else {
+                    Outcome::Unchanged
+                }
+            }
+            ct_event!(mouse down Left for column, row)
+                if self.area.contains((*column, *row).into()) =>
+            {
+                let row = self.row_at(*row);
+                self.pressed = (row < self.items.len()).then_some(row);
+                self.dragged = None;
+                Outcome::Unchanged
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+impl HandleEvent<Event, DragDrop, Outcome> for ListState {
+    fn handle(&mut self, event: &Event, _qualifier: DragDrop) -> Outcome {
+        match event {
+            ct_event!(mouse up Left for column, row)
+                if self.area.contains((*column, *row).into()) =>
+            {
+                let mut pos = self.row_at(*row);
+                let Some(item) = self.drag.accept(self.id) else {
+                    return Outcome::Continue;
+                };
+                // reorder within the list.
+                if let Some(dragged) = self.dragged.take() {
+                    self.items.remove(dragged);
+                    if dragged < pos {
+                        pos -= 1;
+                    }
+                }
+                self.items.insert(pos.min(self.items.len()), item);
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+fn main() {
+    let drag = DragDropState::new();
+    let mut lists = [
+        ListState::new(0, Rect::new(0, 0, 10, 5), &["a", "b", "c"], drag.clone()),
+        ListState::new(1, Rect::new(20, 0, 10, 5), &["x", "y"], drag.clone()),
+    ];
+
+    let mouse = |kind, column, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let events = [
+        // move "a" behind "c"
+        mouse(MouseEventKind::Down(MouseButton::Left), 1, 0),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 1, 1),
+        mouse(MouseEventKind::Up(MouseButton::Left), 1, 3),
+        // move "b" to the other list
+        mouse(MouseEventKind::Down(MouseButton::Left), 1, 0),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 10, 0),
+        mouse(MouseEventKind::Up(MouseButton::Left), 21, 1),
+        // drop "x" on nothing
+        mouse(MouseEventKind::Down(MouseButton::Left), 21, 0),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 15, 0),
+        mouse(MouseEventKind::Up(MouseButton::Left), 15, 0),
+    ];
+
+    for event in events {
+        let mut r = Outcome::Continue;
+        broadcast!(r: lists[0].handle(&event, Regular), lists[1].handle(&event, Regular));
+
+        // all possible targets, then the shared state itself.
+        broadcast!(r: lists[0].handle(&event, DragDrop), lists[1].handle(&event, DragDrop));
+        r = r.max(drag.clone().handle(&event, DragDrop));
+
+        if matches!(event, ct_event!(mouse up Left)) {
+            if let Some((source, target)) = drag.dropped() {
+                if source != target {
+                    r = r.max(lists[source].remove_dragged());
+                }
+            } else if let Some(source) = drag.source() {
+                lists[source].dragged = None;
+            }
+        }
+
+        println!(
+            "{:?} -> {:?} {:?} {:?}",
+            event, r, lists[0].items, lists[1].items
+        );
+    }
+}
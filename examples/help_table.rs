@@ -0,0 +1,93 @@
+//!
+//! Dumps the key bindings as an aligned help table.
+//!
+//! The help is generated from the keymaps and sequences, it
+//! doesn't need to be maintained by hand.
+//!
+
+use rat_event::crossterm::{BindingEntry, BindingHelp, KeyCombo, KeyMap, KeySequence};
+use std::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Save,
+    Quit,
+    Help,
+    Up,
+    Down,
+    Comment,
+    FindFile,
+}
+
+fn key(s: &str) -> Result<KeyCombo, Box<dyn Error>> {
+    Ok(s.parse::<KeyCombo>()?)
+}
+
+fn print_table(title: &str, entries: &[BindingEntry<'_, Action>]) {
+    let width = entries.iter().map(|v| v.key.len()).max().unwrap_or(0);
+
+    println!("{}", title);
+    let mut category = None;
+    for entry in entries {
+        if category != Some(entry.category) {
+            category = Some(entry.category);
+            println!("  {}", entry.category.unwrap_or("General"));
+        }
+        println!(
+            "    {:width$}  {}",
+            entry.key,
+            entry
+                .description
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| format!("{:?}", entry.action)),
+            width = width
+        );
+    }
+    println!();
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut keymap = KeyMap::new();
+    keymap.bind_with(
+        key("ctrl+s")?,
+        Action::Save,
+        BindingHelp::new("Save").category("File"),
+    );
+    keymap.bind_with(
+        key("ctrl+q")?,
+        Action::Quit,
+        BindingHelp::new("Quit").category("File"),
+    );
+    keymap.bind_with(
+        key("up")?,
+        Action::Up,
+        BindingHelp::new("Previous line").category("Move"),
+    );
+    keymap.bind_with(
+        key("down")?,
+        Action::Down,
+        BindingHelp::new("Next line").category("Move"),
+    );
+    keymap.bind(key("f1")?, Action::Help);
+
+    let mut sequence = KeySequence::new();
+    sequence.set_leader(key("space")?);
+    sequence.bind_with(
+        [key("ctrl+k")?, key("ctrl+c")?],
+        Action::Comment,
+        BindingHelp::new("Comment selection").category("Edit"),
+    );
+    sequence.bind_leader_with(
+        [key("f")?, key("f")?],
+        Action::FindFile,
+        BindingHelp::new("Find file").category("File"),
+    );
+
+    let mut entries = keymap.entries().collect::<Vec<_>>();
+    entries.extend(sequence.entries());
+    entries.sort_by(|a, b| (a.category, &a.keys).cmp(&(b.category, &b.keys)));
+
+    print_table("Keys", &entries);
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//!
+//! Hover event-handler for a list.
+//!
+//! Two lists report the item under the mouse, and a status line
+//! shows it. The lists are not focused, and a click still goes
+//! to the Regular event-handler.
+//!
+
+use rat_event::crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::util::MouseFlags;
+use rat_event::{broadcast, ct_event, HandleEvent, Hover, Outcome, OutcomeOf, Regular};
+use ratatui::layout::Rect;
+
+struct ListState {
+    name: &'static str,
+    area: Rect,
+    rows: Vec<Rect>,
+    selected: Option<usize>,
+    hovered: Option<usize>,
+    mouse: MouseFlags,
+}
+
+impl ListState {
+    fn new(name: &'static str, area: Rect) -> Self {
+        Self {
+            name,
+            area,
+            rows: (0..area.height)
+                .map(|v| Rect::new(area.x, area.y + v, area.width, 1))
+                .collect(),
+            selected: None,
+            hovered: None,
+            mouse: Default::default(),
+        }
+    }
+}
+
+impl HandleEvent<Event, Hover, OutcomeOf<Option<usize>>> for ListState {
+    fn handle(&mut self, event: &Event, _qualifier: Hover) -> OutcomeOf<Option<usize>> {
+        match event {
+            Event::Mouse(
+                m @ MouseEvent {
+                    kind: MouseEventKind::Moved,
+                    ..
+                },
+            ) => {
+                self.mouse.hover(self.area, m);
+                let hovered = if self.mouse.hover.get() {
+                    self.mouse.row_at(&self.rows, m.row)
+                } else {
+                    None
+                };
+                if hovered != self.hovered {
+                    self.hovered = hovered;
+                    OutcomeOf::Value(hovered)
+                } else if hovered.is_some() {
+                    OutcomeOf::Unchanged
+                } else {
+                    OutcomeOf::Continue
+                }
+            }
+            _ => OutcomeOf::Continue,
+        }
+    }
+}
+
+impl HandleEvent<Event, Regular, Outcome> for ListState {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            ct_event!(mouse down Left for column, row)
+                if self.area.contains((*column, *row).into()) =>
+            {
+                self.selected = self.mouse.row_at(&self.rows, *row);
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+fn main() {
+    let mut left = ListState::new("left", Rect::new(0, 0, 10, 5));
+    let mut right = ListState::new("right", Rect::new(20, 0, 10, 5));
+    let mut status = String::new();
+
+    let mouse = |kind, column, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let events = [
+        mouse(MouseEventKind::Moved, 2, 1),
+        mouse(MouseEventKind::Moved, 5, 1),
+        mouse(MouseEventKind::Moved, 5, 3),
+        mouse(MouseEventKind::Down(MouseButton::Left), 5, 3),
+        mouse(MouseEventKind::Moved, 22, 0),
+        mouse(MouseEventKind::Moved, 9, 0),
+        mouse(MouseEventKind::Moved, 15, 0),
+    ];
+
+    for event in events {
+        // hover first, for all widgets. The list the mouse
+        // leaves needs the event as well.
+        let mut hover = Outcome::Continue;
+        broadcast!(hover: left.handle(&event, Hover), right.handle(&event, Hover));
+        if hover == Outcome::Changed {
+            status = [&left, &right]
+                .iter()
+                .find_map(|v| v.hovered.map(|item| format!("{} item {}", v.name, item)))
+                .unwrap_or_default();
+        }
+
+        // then the regular event-handling.
+        let mut r = Outcome::Continue;
+        broadcast!(r: left.handle(&event, Regular), right.handle(&event, Regular));
+
+        println!(
+            "{:?} -> hover {:?} regular {:?} status {:?}",
+            event, hover, r, status
+        );
+    }
+    println!("left selected {:?}", left.selected);
+}
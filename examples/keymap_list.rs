@@ -0,0 +1,102 @@
+//!
+//! A list whose navigation keys are defined in a KeyMap.
+//!
+//! The keys are read from a config-like table, and the list
+//! handles actions instead of raw key events.
+//!
+
+use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::crossterm::{fmt_key, KeyCombo, KeyMap, Keyed};
+use rat_event::{HandleEvent, Outcome, Regular};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListAction {
+    Up,
+    Down,
+    First,
+    Last,
+    PageUp,
+    PageDown,
+}
+
+struct ListState {
+    len: usize,
+    page: usize,
+    selected: usize,
+}
+
+impl HandleEvent<ListAction, Regular, Outcome> for ListState {
+    fn handle(&mut self, action: &ListAction, _qualifier: Regular) -> Outcome {
+        let old = self.selected;
+        let last = self.len.saturating_sub(1);
+        self.selected = match action {
+            ListAction::Up => self.selected.saturating_sub(1),
+            ListAction::Down => (self.selected + 1).min(last),
+            ListAction::First => 0,
+            ListAction::Last => last,
+            ListAction::PageUp => self.selected.saturating_sub(self.page),
+            ListAction::PageDown => (self.selected + self.page).min(last),
+        };
+        if old != self.selected {
+            Outcome::Changed
+        } else {
+            Outcome::Unchanged
+        }
+    }
+}
+
+const BINDINGS: &[(&str, ListAction)] = &[
+    ("up", ListAction::Up),
+    ("k", ListAction::Up),
+    ("down", ListAction::Down),
+    ("j", ListAction::Down),
+    ("home", ListAction::First),
+    ("g", ListAction::First),
+    ("end", ListAction::Last),
+    ("shift+g", ListAction::Last),
+    ("pgup", ListAction::PageUp),
+    ("ctrl+b", ListAction::PageUp),
+    ("pgdn", ListAction::PageDown),
+    ("ctrl+f", ListAction::PageDown),
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut keymap = KeyMap::new();
+    for (key, action) in BINDINGS {
+        let key = key.parse::<KeyCombo>()?;
+        if let Some(old) = keymap.bind(key, *action) {
+            eprintln!("{} was bound to {:?}", key, old);
+        }
+    }
+
+    for action in [ListAction::Up, ListAction::Down, ListAction::Last] {
+        let keys = keymap
+            .keys_for(&action)
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        println!("{:?}: {}", action, keys.join(", "));
+    }
+
+    let mut list = ListState {
+        len: 100,
+        page: 20,
+        selected: 0,
+    };
+
+    let key = |code, modifiers| KeyEvent::new(code, modifiers);
+    let keys = [
+        key(KeyCode::Char('j'), KeyModifiers::NONE),
+        key(KeyCode::Down, KeyModifiers::NONE),
+        key(KeyCode::Char('f'), KeyModifiers::CONTROL),
+        key(KeyCode::Char('x'), KeyModifiers::NONE),
+        key(KeyCode::Char('G'), KeyModifiers::SHIFT),
+        key(KeyCode::Char('g'), KeyModifiers::NONE),
+    ];
+    for key in keys {
+        let r = Keyed(&mut list).handle(&Event::Key(key), &keymap);
+        println!("{} -> {:?} selected {}", fmt_key(&key), r, list.selected);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//!
+//! Two overlapping popups above a regular widget.
+//!
+//! A dropdown lies above a list, and a context menu lies above
+//! both of them. The popups add their areas to a ZAreas during
+//! rendering. All event-handlers get the ZAreas as qualifier and
+//! only take the mouse events that are routed to them.
+//!
+
+use rat_event::crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::crossterm::ZAreas;
+use rat_event::{ConsumedEvent, HandleEvent, Outcome};
+use ratatui::layout::{Position, Rect};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PopupId {
+    Dropdown,
+    ContextMenu,
+}
+
+struct ListState {
+    area: Rect,
+    clicked: Option<Position>,
+}
+
+impl<'a> HandleEvent<Event, &'a ZAreas<PopupId>, Outcome> for ListState {
+    fn handle(&mut self, event: &Event, zareas: &'a ZAreas<PopupId>) -> Outcome {
+        if zareas.is_obscured(self.area, 0, event) {
+            return Outcome::Continue;
+        }
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if self.area.contains(Position::new(*column, *row)) => {
+                self.clicked = Some(Position::new(*column, *row));
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+struct PopupState {
+    id: PopupId,
+    area: Rect,
+    z: u16,
+    clicks: usize,
+}
+
+impl PopupState {
+    fn render(&self, zareas: &mut ZAreas<PopupId>) {
+        // ... render the popup ...
+        zareas.add(self.id, self.area, self.z);
+    }
+}
+
+impl<'a> HandleEvent<Event, &'a ZAreas<PopupId>, Outcome> for PopupState {
+    fn handle(&mut self, event: &Event, zareas: &'a ZAreas<PopupId>) -> Outcome {
+        if zareas.route_mouse(event) != Some(&self.id) {
+            return Outcome::Continue;
+        }
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                ..
+            }) => {
+                self.clicks += 1;
+                Outcome::Changed
+            }
+            _ => Outcome::Unchanged,
+        }
+    }
+}
+
+fn main() {
+    let mut list = ListState {
+        area: Rect::new(0, 0, 40, 20),
+        clicked: None,
+    };
+    let mut dropdown = PopupState {
+        id: PopupId::Dropdown,
+        area: Rect::new(10, 2, 15, 10),
+        z: 1,
+        clicks: 0,
+    };
+    let mut menu = PopupState {
+        id: PopupId::ContextMenu,
+        area: Rect::new(20, 5, 12, 6),
+        z: 2,
+        clicks: 0,
+    };
+
+    // render
+    let mut zareas = ZAreas::new();
+    dropdown.render(&mut zareas);
+    menu.render(&mut zareas);
+
+    for (id, area, z) in zareas.iter() {
+        println!("{:?} at {:?} z {}", id, area, z);
+    }
+
+    // events
+    let click = |column, row| {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    for (column, row) in [(12, 3), (22, 6), (30, 8), (5, 15), (22, 15)] {
+        let event = click(column, row);
+        let r = menu
+            .handle(&event, &zareas)
+            .or_else(|| dropdown.handle(&event, &zareas))
+            .or_else(|| list.handle(&event, &zareas));
+        println!(
+            "click {},{} -> {:?} for {:?}",
+            column,
+            row,
+            r,
+            zareas.route_mouse(&event)
+        );
+    }
+
+    println!("dropdown clicks {}", dropdown.clicks);
+    println!("menu clicks {}", menu.clicks);
+    println!("list clicked {:?}", list.clicked);
+}
@@ -0,0 +1,98 @@
+//!
+//! A text field with a Regular and a ReadOnly event-handler.
+//!
+//! The ReadOnly event-handler uses the Regular one for moving
+//! the cursor, and drops all the events that would change the text.
+//!
+
+use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::crossterm::{fmt_key, is_edit_event};
+use rat_event::handler::read_only;
+use rat_event::{ct_event, HandleEvent, Outcome, ReadOnly, Regular};
+
+struct TextState {
+    text: Vec<char>,
+    cursor: usize,
+}
+
+impl TextState {
+    fn move_to(&mut self, cursor: usize) -> Outcome {
+        let cursor = cursor.min(self.text.len());
+        if cursor != self.cursor {
+            self.cursor = cursor;
+            Outcome::Changed
+        } else {
+            Outcome::Unchanged
+        }
+    }
+}
+
+impl HandleEvent<Event, Regular, Outcome> for TextState {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            ct_event!(keycode press Left) => self.move_to(self.cursor.saturating_sub(1)),
+            ct_event!(keycode press Right) => self.move_to(self.cursor + 1),
+            ct_event!(keycode press Home) => self.move_to(0),
+            ct_event!(keycode press End) => self.move_to(self.text.len()),
+            ct_event!(keycode press Backspace) => {
+                if self.cursor > 0 {
+                    self.cursor -= 1;
+                    self.text.remove(self.cursor);
+                    Outcome::Changed
+                } else {
+                    Outcome::Unchanged
+                }
+            }
+            ct_event!(key press c) | ct_event!(key press SHIFT-c) => {
+                self.text.insert(self.cursor, *c);
+                self.cursor += 1;
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+impl HandleEvent<Event, ReadOnly, Outcome> for TextState {
+    fn handle(&mut self, event: &Event, qualifier: ReadOnly) -> Outcome {
+        read_only(self, event, qualifier, is_edit_event)
+    }
+}
+
+fn main() {
+    let mut text = TextState {
+        text: "hello".chars().collect(),
+        cursor: 5,
+    };
+
+    let key = |code, modifiers| KeyEvent::new(code, modifiers);
+    let keys = [
+        key(KeyCode::Home, KeyModifiers::NONE),
+        key(KeyCode::Right, KeyModifiers::NONE),
+        key(KeyCode::Char('x'), KeyModifiers::NONE),
+        key(KeyCode::Backspace, KeyModifiers::NONE),
+        key(KeyCode::Char('v'), KeyModifiers::CONTROL),
+        key(KeyCode::F(5), KeyModifiers::NONE),
+    ];
+
+    for key in keys {
+        let r = text.handle(&Event::Key(key), ReadOnly);
+        println!(
+            "read-only {} -> {:?} text {:?} cursor {}",
+            fmt_key(&key),
+            r,
+            text.text.iter().collect::<String>(),
+            text.cursor
+        );
+    }
+    for key in keys {
+        let r = text.handle(&Event::Key(key), Regular);
+        println!(
+            "regular {} -> {:?} text {:?} cursor {}",
+            fmt_key(&key),
+            r,
+            text.text.iter().collect::<String>(),
+            text.cursor
+        );
+    }
+}
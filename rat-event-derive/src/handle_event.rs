@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Error, Field, Member, Path, Result};
+
+struct Forward<'a> {
+    field: &'a Field,
+    member: Member,
+    qualifiers: Option<Vec<Path>>,
+}
+
+pub(crate) fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "#[derive(HandleEvent)] only works for structs",
+        ));
+    };
+
+    let mut forward: Option<Forward> = None;
+    for (idx, field) in data.fields.iter().enumerate() {
+        for attr in &field.attrs {
+            if !attr.path().is_ident("handle_event") {
+                continue;
+            }
+
+            let mut is_forward = false;
+            let mut qualifiers = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("forward") {
+                    is_forward = true;
+                    Ok(())
+                } else if meta.path.is_ident("qualifiers") {
+                    let mut list = Vec::new();
+                    meta.parse_nested_meta(|q| {
+                        list.push(q.path);
+                        Ok(())
+                    })?;
+                    if list.is_empty() {
+                        return Err(meta.error("expected at least one qualifier"));
+                    }
+                    qualifiers = Some(list);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `forward` or `qualifiers(...)`"))
+                }
+            })?;
+
+            if !is_forward {
+                return Err(Error::new_spanned(
+                    attr,
+                    "#[handle_event(...)] needs `forward`",
+                ));
+            }
+            if forward.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "only one field can be marked with #[handle_event(forward)]",
+                ));
+            }
+
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(idx.into()),
+            };
+            forward = Some(Forward {
+                field,
+                member,
+                qualifiers,
+            });
+        }
+    }
+
+    let Some(forward) = forward else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "one field must be marked with #[handle_event(forward)]",
+        ));
+    };
+
+    let name = &input.ident;
+    let field_ty = &forward.field.ty;
+    let member = &forward.member;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let event = format_ident!("__E");
+    let ret = format_ident!("__R");
+
+    let qualifiers = match forward.qualifiers {
+        Some(qualifiers) => qualifiers.into_iter().map(|q| (q, false)).collect(),
+        None => vec![(parse_quote!(__Q), true)],
+    };
+
+    let mut impls = TokenStream::new();
+    for (qualifier, generic) in qualifiers {
+        let mut generics = input.generics.clone();
+        generics.params.push(parse_quote!(#event));
+        if generic {
+            generics.params.push(parse_quote!(#qualifier));
+        }
+        generics.params.push(parse_quote!(#ret));
+        let where_clause = generics.make_where_clause();
+        where_clause
+            .predicates
+            .push(parse_quote!(#field_ty: ::rat_event::HandleEvent<#event, #qualifier, #ret>));
+        where_clause
+            .predicates
+            .push(parse_quote!(#ret: ::rat_event::ConsumedEvent));
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        impls.extend(quote! {
+            impl #impl_generics ::rat_event::HandleEvent<#event, #qualifier, #ret>
+                for #name #ty_generics #where_clause
+            {
+                fn handle(&mut self, event: &#event, qualifier: #qualifier) -> #ret {
+                    ::rat_event::HandleEvent::handle(&mut self.#member, event, qualifier)
+                }
+            }
+        });
+    }
+
+    Ok(impls)
+}
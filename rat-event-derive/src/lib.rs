@@ -0,0 +1,102 @@
+//!
+//! Derive macros for [rat-event](https://docs.rs/rat-event).
+//!
+//! Use them via the `derive` feature of rat-event.
+//!
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod handle_event;
+mod outcome;
+
+/// Derives HandleEvent by forwarding to one of the fields.
+///
+/// ```rust ignore
+/// #[derive(HandleEvent)]
+/// struct MyList {
+///     #[handle_event(forward)]
+///     list: ListState,
+///     extra: u32,
+/// }
+/// ```
+///
+/// generates
+///
+/// ```rust ignore
+/// impl<E, Q, R> HandleEvent<E, Q, R> for MyList
+/// where
+///     ListState: HandleEvent<E, Q, R>,
+///     R: ConsumedEvent,
+/// {
+///     fn handle(&mut self, event: &E, qualifier: Q) -> R {
+///         self.list.handle(event, qualifier)
+///     }
+/// }
+/// ```
+///
+/// With `#[handle_event(forward, qualifiers(Regular, MouseOnly))]`
+/// only the given qualifiers are forwarded. This generates one impl
+/// for each qualifier.
+#[proc_macro_derive(HandleEvent, attributes(handle_event))]
+pub fn derive_handle_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    handle_event::derive(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives ConsumedEvent for a widget defined outcome enum.
+///
+/// The variant that is not consumed must be named `Continue`
+/// or be marked with `#[outcome(Continue)]`. It can't have any data.
+///
+/// ```rust ignore
+/// #[derive(ConsumedEvent)]
+/// enum MenuOutcome {
+///     #[outcome(Continue)]
+///     NotUsed,
+///     Unchanged,
+///     Changed,
+///     Activated(usize),
+/// }
+/// ```
+#[proc_macro_derive(ConsumedEvent, attributes(outcome))]
+pub fn derive_consumed_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    outcome::derive_consumed_event(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives the conversions from and to Outcome for a widget defined
+/// outcome enum.
+///
+/// Named OutcomeConvert, as a derive named Outcome would collide with
+/// the enum Outcome.
+///
+/// Each variant maps to one of the variants of Outcome. Variants named
+/// `Continue`, `Unchanged` or `Changed` map to the same variant, all others
+/// need a `#[outcome(...)]` attribute.
+///
+/// For the conversion from Outcome there must be a variant without data
+/// for each of the variants of Outcome. If there are more than one, the
+/// first is used.
+///
+/// ```rust ignore
+/// #[derive(ConsumedEvent, OutcomeConvert)]
+/// enum MenuOutcome {
+///     Continue,
+///     Unchanged,
+///     Changed,
+///     #[outcome(Changed)]
+///     Activated(usize),
+/// }
+/// ```
+#[proc_macro_derive(OutcomeConvert, attributes(outcome))]
+pub fn derive_outcome(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    outcome::derive_outcome(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -0,0 +1,197 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DataEnum, DeriveInput, Error, Fields, Ident, Result, Variant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Base {
+    Continue,
+    Unchanged,
+    Changed,
+}
+
+impl Base {
+    fn from_ident(ident: &Ident) -> Option<Base> {
+        if ident == "Continue" {
+            Some(Base::Continue)
+        } else if ident == "Unchanged" {
+            Some(Base::Unchanged)
+        } else if ident == "Changed" {
+            Some(Base::Changed)
+        } else {
+            None
+        }
+    }
+
+    fn to_tokens(self) -> TokenStream {
+        match self {
+            Base::Continue => quote!(::rat_event::Outcome::Continue),
+            Base::Unchanged => quote!(::rat_event::Outcome::Unchanged),
+            Base::Changed => quote!(::rat_event::Outcome::Changed),
+        }
+    }
+}
+
+struct Mapped<'a> {
+    variant: &'a Variant,
+    base: Base,
+}
+
+fn data_enum<'a>(input: &'a DeriveInput, derive: &str) -> Result<&'a DataEnum> {
+    match &input.data {
+        Data::Enum(data) => Ok(data),
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            format!("#[derive({})] only works for enums", derive),
+        )),
+    }
+}
+
+/// Mapping from #[outcome(...)] or the name of the variant.
+fn mapping(variant: &Variant) -> Result<Option<Base>> {
+    let mut base = None;
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("outcome") {
+            continue;
+        }
+        if base.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate #[outcome(...)]"));
+        }
+        attr.parse_nested_meta(|meta| {
+            if base.is_some() {
+                return Err(meta.error("expected only one of `Continue`, `Unchanged` or `Changed`"));
+            }
+            match meta.path.get_ident().and_then(Base::from_ident) {
+                Some(v) => {
+                    base = Some(v);
+                    Ok(())
+                }
+                None => Err(meta.error("expected `Continue`, `Unchanged` or `Changed`")),
+            }
+        })?;
+        if base.is_none() {
+            return Err(Error::new_spanned(
+                attr,
+                "expected `Continue`, `Unchanged` or `Changed`",
+            ));
+        }
+    }
+    Ok(base.or_else(|| Base::from_ident(&variant.ident)))
+}
+
+fn pattern(name: &Ident, variant: &Variant) -> TokenStream {
+    let ident = &variant.ident;
+    match &variant.fields {
+        Fields::Named(_) => quote!(#name::#ident { .. }),
+        Fields::Unnamed(_) => quote!(#name::#ident(..)),
+        Fields::Unit => quote!(#name::#ident),
+    }
+}
+
+fn continue_variant<'a>(input: &DeriveInput, data: &'a DataEnum) -> Result<&'a Variant> {
+    let mut found = None;
+    for variant in &data.variants {
+        if mapping(variant)? != Some(Base::Continue) {
+            continue;
+        }
+        if found.is_some() {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "only one variant can map to `Continue`",
+            ));
+        }
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "the `Continue` variant can't have any data",
+            ));
+        }
+        found = Some(variant);
+    }
+    found.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "one variant must be named `Continue` or be marked with #[outcome(Continue)]",
+        )
+    })
+}
+
+pub(crate) fn derive_consumed_event(input: DeriveInput) -> Result<TokenStream> {
+    let data = data_enum(&input, "ConsumedEvent")?;
+    let name = &input.ident;
+    let not_consumed = pattern(name, continue_variant(&input, data)?);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::rat_event::ConsumedEvent for #name #ty_generics #where_clause {
+            fn is_consumed(&self) -> bool {
+                !matches!(self, #not_consumed)
+            }
+        }
+    })
+}
+
+pub(crate) fn derive_outcome(input: DeriveInput) -> Result<TokenStream> {
+    let data = data_enum(&input, "Outcome")?;
+    continue_variant(&input, data)?;
+
+    let mut mapped = Vec::new();
+    for variant in &data.variants {
+        match mapping(variant)? {
+            Some(base) => mapped.push(Mapped { variant, base }),
+            None => {
+                return Err(Error::new_spanned(
+                    &variant.ident,
+                    "missing #[outcome(...)] for this variant",
+                ))
+            }
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let to_outcome = mapped.iter().map(|m| {
+        let pattern = pattern(name, m.variant);
+        let base = m.base.to_tokens();
+        quote!(#pattern => #base,)
+    });
+
+    let mut from_outcome = Vec::new();
+    for base in [Base::Continue, Base::Unchanged, Base::Changed] {
+        let Some(m) = mapped
+            .iter()
+            .find(|m| m.base == base && matches!(m.variant.fields, Fields::Unit))
+        else {
+            return Err(Error::new_spanned(
+                &input.ident,
+                format!("there must be a variant without data for `{:?}`", base),
+            ));
+        };
+        let ident = &m.variant.ident;
+        let base = base.to_tokens();
+        from_outcome.push(quote!(#base => #name::#ident,));
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for ::rat_event::Outcome
+            #where_clause
+        {
+            fn from(value: #name #ty_generics) -> Self {
+                match value {
+                    #(#to_outcome)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<::rat_event::Outcome> for #name #ty_generics
+            #where_clause
+        {
+            fn from(value: ::rat_event::Outcome) -> Self {
+                match value {
+                    #(#from_outcome)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,59 @@
+use rat_event::{HandleEvent, MouseOnly, Outcome, Regular};
+
+#[derive(Debug, Default)]
+struct ListState {
+    count: u32,
+}
+
+impl HandleEvent<char, Regular, Outcome> for ListState {
+    fn handle(&mut self, _event: &char, _qualifier: Regular) -> Outcome {
+        self.count += 1;
+        Outcome::Changed
+    }
+}
+
+impl HandleEvent<char, MouseOnly, Outcome> for ListState {
+    fn handle(&mut self, _event: &char, _qualifier: MouseOnly) -> Outcome {
+        Outcome::Unchanged
+    }
+}
+
+#[derive(Debug, Default, HandleEvent)]
+struct MyList {
+    #[handle_event(forward)]
+    list: ListState,
+    _extra: u32,
+}
+
+#[derive(Debug, Default, HandleEvent)]
+struct MyTuple(u32, #[handle_event(forward)] ListState);
+
+#[derive(Debug, Default, HandleEvent)]
+struct MyGeneric<'a, T: Default> {
+    #[handle_event(forward, qualifiers(Regular))]
+    list: T,
+    _name: &'a str,
+}
+
+#[test]
+fn test_forward() {
+    let mut s = MyList::default();
+    assert_eq!(s.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(s.handle(&'a', MouseOnly), Outcome::Unchanged);
+    assert_eq!(s.list.count, 1);
+
+    let mut s = MyTuple::default();
+    assert_eq!(s.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(s.0, 0);
+    assert_eq!(s.1.count, 1);
+
+    let mut s = MyGeneric::<ListState>::default();
+    assert_eq!(s.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(s.list.count, 1);
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
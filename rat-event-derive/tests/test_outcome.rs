@@ -0,0 +1,61 @@
+use rat_event::util::assert_outcome_compatible;
+use rat_event::{flow, ConsumedEvent, Outcome, OutcomeConvert};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ConsumedEvent, OutcomeConvert)]
+enum MenuOutcome {
+    Continue,
+    Unchanged,
+    Changed,
+    #[outcome(Changed)]
+    Activated(usize),
+    #[outcome(Changed)]
+    Selected {
+        idx: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ConsumedEvent, OutcomeConvert)]
+enum Renamed {
+    #[outcome(Continue)]
+    NotUsed,
+    #[outcome(Unchanged)]
+    Used,
+    #[outcome(Changed)]
+    Render,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ConsumedEvent)]
+enum OnlyConsumed<T> {
+    Continue,
+    Value(T),
+}
+
+fn menu(r: MenuOutcome) -> Outcome {
+    flow!(r);
+    Outcome::Continue
+}
+
+#[test]
+fn test_derive() {
+    assert!(!MenuOutcome::Continue.is_consumed());
+    assert!(MenuOutcome::Unchanged.is_consumed());
+    assert!(MenuOutcome::Activated(1).is_consumed());
+    assert!(MenuOutcome::Selected { idx: 1 }.is_consumed());
+
+    assert_eq!(Outcome::from(MenuOutcome::Activated(1)), Outcome::Changed);
+    assert_eq!(
+        Outcome::from(MenuOutcome::Selected { idx: 1 }),
+        Outcome::Changed
+    );
+    assert_eq!(MenuOutcome::from(Outcome::Changed), MenuOutcome::Changed);
+    assert_eq!(menu(MenuOutcome::Activated(1)), Outcome::Changed);
+    assert_outcome_compatible::<MenuOutcome>();
+
+    assert!(!Renamed::NotUsed.is_consumed());
+    assert_eq!(Outcome::from(Renamed::Used), Outcome::Unchanged);
+    assert_eq!(Renamed::from(Outcome::Changed), Renamed::Render);
+    assert_outcome_compatible::<Renamed>();
+
+    assert!(!OnlyConsumed::<u32>::Continue.is_consumed());
+    assert!(OnlyConsumed::Value(1).is_consumed());
+}
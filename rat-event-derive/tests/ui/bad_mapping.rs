@@ -0,0 +1,11 @@
+use rat_event::OutcomeConvert;
+
+#[derive(OutcomeConvert)]
+enum BadMapping {
+    Continue,
+    Unchanged,
+    #[outcome(Render)]
+    Changed,
+}
+
+fn main() {}
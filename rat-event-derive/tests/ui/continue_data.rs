@@ -0,0 +1,9 @@
+use rat_event::ConsumedEvent;
+
+#[derive(ConsumedEvent)]
+enum ContinueData {
+    Continue(u32),
+    Changed,
+}
+
+fn main() {}
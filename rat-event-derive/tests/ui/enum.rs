@@ -0,0 +1,8 @@
+use rat_event::HandleEvent;
+
+#[derive(HandleEvent)]
+enum NotAStruct {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rat_event::OutcomeConvert;
+
+#[derive(OutcomeConvert)]
+enum MissingMapping {
+    Continue,
+    Unchanged,
+    Changed,
+    Activated(usize),
+}
+
+fn main() {}
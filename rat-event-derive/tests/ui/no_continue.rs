@@ -0,0 +1,9 @@
+use rat_event::ConsumedEvent;
+
+#[derive(ConsumedEvent)]
+enum NoContinue {
+    NotUsed,
+    Changed,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use rat_event::HandleEvent;
+
+#[derive(HandleEvent)]
+struct NoForward {
+    list: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rat_event::OutcomeConvert;
+
+#[derive(OutcomeConvert)]
+enum NoUnitChanged {
+    Continue,
+    Unchanged,
+    #[outcome(Changed)]
+    Activated(usize),
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use rat_event::{HandleEvent, MouseOnly, Outcome, Regular};
+
+struct ListState;
+
+impl HandleEvent<char, Regular, Outcome> for ListState {
+    fn handle(&mut self, _event: &char, _qualifier: Regular) -> Outcome {
+        Outcome::Changed
+    }
+}
+
+#[derive(HandleEvent)]
+struct OnlyRegular {
+    #[handle_event(forward, qualifiers(Regular))]
+    list: ListState,
+}
+
+fn main() {
+    let mut s = OnlyRegular { list: ListState };
+    let _: Outcome = s.handle(&'a', MouseOnly);
+}
@@ -0,0 +1,11 @@
+use rat_event::HandleEvent;
+
+#[derive(HandleEvent)]
+struct TwoForward {
+    #[handle_event(forward)]
+    list: u32,
+    #[handle_event(forward)]
+    table: u32,
+}
+
+fn main() {}
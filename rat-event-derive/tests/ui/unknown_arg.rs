@@ -0,0 +1,9 @@
+use rat_event::HandleEvent;
+
+#[derive(HandleEvent)]
+struct UnknownArg {
+    #[handle_event(forwards)]
+    list: u32,
+}
+
+fn main() {}
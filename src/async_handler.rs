@@ -0,0 +1,62 @@
+//!
+//! Event-handling that needs to await something.
+//!
+//! This is available with the feature `async`.
+//!
+
+use crate::{ConsumedEvent, HandleEvent};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Boxed future as returned by [HandleEventAsync].
+pub type BoxFuture<'a, R> = Pin<Box<dyn Future<Output = R> + 'a>>;
+
+/// Async variant of [HandleEvent].
+///
+/// This returns a boxed future, which keeps the trait object safe.
+/// The future is not required to be `Send`.
+///
+/// Every [HandleEvent] is a HandleEventAsync too. The method is
+/// named `handle_async` to avoid any ambiguity with [HandleEvent::handle].
+///
+/// ```rust ignore
+/// impl HandleEventAsync<Event, Regular, Outcome> for EditorState {
+///     fn handle_async<'a>(
+///         &'a mut self,
+///         event: &'a Event,
+///         qualifier: Regular,
+///     ) -> BoxFuture<'a, Outcome> {
+///         Box::pin(async move {
+///             match event {
+///                 ct_event!(key press CONTROL-' ') => {
+///                     self.completions = self.lsp.complete(self.cursor).await;
+///                     Outcome::Changed
+///                 }
+///                 _ => self.text.handle(event, qualifier),
+///             }
+///         })
+///     }
+/// }
+/// ```
+pub trait HandleEventAsync<Event, Qualifier, Return>
+where
+    Return: ConsumedEvent,
+{
+    /// Handle an event.
+    fn handle_async<'a>(
+        &'a mut self,
+        event: &'a Event,
+        qualifier: Qualifier,
+    ) -> BoxFuture<'a, Return>;
+}
+
+impl<T, E, Q, R> HandleEventAsync<E, Q, R> for T
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent + 'static,
+{
+    fn handle_async<'a>(&'a mut self, event: &'a E, qualifier: Q) -> BoxFuture<'a, R> {
+        let r = self.handle(event, qualifier);
+        Box::pin(std::future::ready(r))
+    }
+}
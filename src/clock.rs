@@ -0,0 +1,197 @@
+//!
+//! Clocks for everything that depends on time.
+//!
+//! A state keeps a [SharedClock] and passes its time on. By default,
+//! this is the [SystemClock]. A test can replace it with a [MockClock]
+//! and move time forward as it likes. The `EventRecorder` and
+//! `EventReplay` of the feature `recorder` take a SharedClock directly.
+//!
+//! The [MouseFlags](crate::util::MouseFlags) take the time for the
+//! double-click interval with the functions ending in `_at`. Pass
+//! them `clock.system_time()`. The time-based helpers, e.g.
+//! [EscDebouncer](crate::crossterm::EscDebouncer),
+//! [ResizeDebounce](crate::crossterm::ResizeDebounce),
+//! [KeySequence](crate::crossterm::KeySequence) or
+//! [RepeatThrottle](crate::crossterm::RepeatThrottle) get the current
+//! time with each call. Pass them `clock.now()`.
+//!
+//! ```rust
+//! use rat_event::clock::{Clock, MockClock};
+//! use rat_event::util::{double_click_timeout, MouseFlags};
+//! use rat_event::event::{Modifiers, MouseButton, MouseEvent, MouseKind};
+//! use ratatui::layout::Rect;
+//! use std::time::Duration;
+//!
+//! let clock = MockClock::new();
+//! let flags = MouseFlags::default();
+//! let area = Rect::new(0, 0, 10, 10);
+//! let dclick = |m| flags.doubleclick2_event_at(area, m, Modifiers::NONE, clock.system_time());
+//!
+//! let mouse = |kind| MouseEvent { kind, column: 1, row: 1, modifiers: Modifiers::NONE };
+//! let down = mouse(MouseKind::Down(MouseButton::Left));
+//! let up = mouse(MouseKind::Up(MouseButton::Left));
+//!
+//! assert!(!dclick(&down));
+//! assert!(!dclick(&up));
+//! // too slow.
+//! clock.advance(Duration::from_millis(double_click_timeout() as u64 + 1));
+//! assert!(!dclick(&down));
+//! assert!(!dclick(&up));
+//! ```
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Source of the current time.
+pub trait Clock: Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> Instant;
+
+    /// The current wall-clock time.
+    fn system_time(&self) -> SystemTime;
+}
+
+/// The system clock. Uses `Instant::now()`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that only moves when told to.
+///
+/// Clones share the same time, keep one and give the others to
+/// the states under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    time: Arc<MockTime>,
+}
+
+/// The time of a MockClock and its clones.
+#[derive(Debug)]
+struct MockTime {
+    now: Mutex<(Instant, SystemTime)>,
+}
+
+impl Clock for MockTime {
+    fn now(&self) -> Instant {
+        self.now.lock().expect("clock").0
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.now.lock().expect("clock").1
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClock {
+    /// New clock. Starts at the current time.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// New clock, starts at the given time.
+    /// The wall-clock starts at the current system time.
+    pub fn starting_at(now: Instant) -> Self {
+        Self {
+            time: Arc::new(MockTime {
+                now: Mutex::new((now, SystemTime::now())),
+            }),
+        }
+    }
+
+    /// Move time forward.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.time.now.lock().expect("clock");
+        now.0 += duration;
+        now.1 += duration;
+    }
+
+    /// A [SharedClock] that uses this clock.
+    ///
+    /// All SharedClocks of a MockClock and its clones are equal.
+    pub fn shared(&self) -> SharedClock {
+        SharedClock(Some(self.time.clone()))
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.time.now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.time.system_time()
+    }
+}
+
+/// A clock that can be stored in a state.
+///
+/// The default is the [SystemClock]. Two SharedClocks are equal
+/// if they use the same clock.
+#[derive(Debug, Default, Clone)]
+pub struct SharedClock(Option<Arc<dyn Clock>>);
+
+impl SharedClock {
+    /// Use this clock.
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        Self(Some(Arc::new(clock)))
+    }
+
+    /// The system clock.
+    pub fn system() -> Self {
+        Self(None)
+    }
+
+    /// Uses the system clock.
+    pub fn is_system(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl Clock for SharedClock {
+    fn now(&self) -> Instant {
+        match &self.0 {
+            Some(clock) => clock.now(),
+            None => Instant::now(),
+        }
+    }
+
+    fn system_time(&self) -> SystemTime {
+        match &self.0 {
+            Some(clock) => clock.system_time(),
+            None => SystemTime::now(),
+        }
+    }
+}
+
+impl PartialEq for SharedClock {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (None, None) => true,
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SharedClock {}
+
+impl From<MockClock> for SharedClock {
+    fn from(value: MockClock) -> Self {
+        value.shared()
+    }
+}
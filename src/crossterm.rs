@@ -1,10 +1,70 @@
 //! Support for the [ct_event!](macro.ct_event.html) macro.
+//!
+//! Needs one of the features `crossterm_28` or `crossterm_29`.
+//! `crossterm_28` is enabled by default.
+//!
+//! The crossterm events this crate is compiled against are re-exported
+//! as `rat_event::crossterm::event`. Use them to avoid mismatches if
+//! the application uses a different crossterm version.
 
 #![allow(dead_code)]
 
+#[cfg(feature = "crossterm_28")]
+pub use ::crossterm::event;
+#[cfg(all(feature = "crossterm_29", not(feature = "crossterm_28")))]
+pub use ::crossterm_0_29::event;
+
+mod alt;
+mod coalesce;
+mod count;
+mod dialog_stack;
+mod disabled;
+mod double_click;
+mod drag_drop;
+mod esc;
+mod hotkey;
+mod key;
+mod keymap;
+mod popup_core;
+#[cfg(feature = "recorder")]
+mod recorder;
+mod resize;
+mod sequence;
+mod source;
+mod throttle;
+mod zareas;
+
+pub use alt::{alt_accelerator, AltHold};
+pub use coalesce::{coalesce_events, read_available};
+pub use count::CountPrefix;
+pub use dialog_stack::DialogStack;
+pub use disabled::Disabled;
+pub use double_click::{DoubleClickOutcome, DoubleClickState};
+pub use drag_drop::DragDropState;
+pub use esc::{EscDebouncer, EscEsc, EscResolved};
+pub use hotkey::HotKeyRegistry;
+pub use key::{fmt_key, fmt_key_with_style, parse_key, KeyCombo, KeyFmtStyle, KeyParseError};
+pub use keymap::{
+    BindError, BindingEntry, BindingHelp, Conflict, ConflictKind, KeyLayers, KeyMap, KeyMapChange,
+    KeyMapError, Keyed,
+};
+pub use popup_core::{PopupCore, PopupOutcome};
+#[cfg(feature = "recorder")]
+pub use recorder::{
+    EventRecorder, EventReplay, RecordedEvent, RecordedKeyCode, RecordedKeyKind, RecordedMediaKey,
+    RecordedModifierKey, RecordedMouseButton, RecordedMouseKind,
+};
+pub use resize::{ResizeDebounce, ResizeResolved};
+pub use sequence::{KeySequence, SeqResult};
+pub use source::CrosstermSource;
+pub use throttle::RepeatThrottle;
+pub use zareas::ZAreas;
+
+use event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEventKind};
+
 /// A copy of the crossterm-KeyModifiers. Plus a few combinations of modifiers.
 pub mod modifiers {
-    use crossterm::event::KeyModifiers;
+    use super::event::KeyModifiers;
 
     pub const NONE: KeyModifiers = KeyModifiers::NONE;
     pub const CONTROL: KeyModifiers = KeyModifiers::CONTROL;
@@ -16,6 +76,230 @@ pub mod modifiers {
     pub const CONTROL_ALT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0110);
     pub const CONTROL_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0011);
     pub const ALT_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0101);
+    pub const CONTROL_ALT_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0111);
+}
+
+/// Combinations of the crossterm-KeyEventState that include KEYPAD.
+pub mod states {
+    use super::event::KeyEventState;
+
+    pub const KEYPAD: KeyEventState = KeyEventState::KEYPAD;
+    pub const KEYPAD_CAPS_LOCK: KeyEventState = KEYPAD.union(KeyEventState::CAPS_LOCK);
+    pub const KEYPAD_NUM_LOCK: KeyEventState = KEYPAD.union(KeyEventState::NUM_LOCK);
+    pub const KEYPAD_CAPS_NUM_LOCK: KeyEventState = KEYPAD_CAPS_LOCK.union(KeyEventState::NUM_LOCK);
+}
+
+/// Canonical form of a character key.
+///
+/// Depending on the terminal and the keyboard enhancement flags,
+/// Shift+a arrives as `Char('A')` with SHIFT, `Char('A')` without
+/// SHIFT, or `Char('a')` with SHIFT. This maps all of them to
+/// `Char('A')` without SHIFT.
+///
+/// * For characters SHIFT is removed, and a lowercase character
+///   with SHIFT is converted to uppercase.
+/// * Shift+Tab arrives as BackTab, BackTab with SHIFT or Tab with
+///   SHIFT. All of them are mapped to BackTab without SHIFT.
+/// * Any other key is returned as is, SHIFT-Left stays SHIFT-Left.
+///
+/// Only the case of a character is changed. A terminal that sends
+/// `Char('/')` with SHIFT gives `Char('/')`, there is no way
+/// to know the keyboard layout.
+///
+/// `ct_event!(key press 'A')` matches the normalized form.
+pub fn normalize_key(key: KeyEvent) -> KeyEvent {
+    match key.code {
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            let mut uc = c.to_uppercase();
+            let c = match (uc.next(), uc.next()) {
+                (Some(u), None) => u,
+                _ => c,
+            };
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers: key.modifiers - KeyModifiers::SHIFT,
+                ..key
+            }
+        }
+        KeyCode::Tab | KeyCode::BackTab if is_shift_tab(&key) => KeyEvent {
+            code: KeyCode::BackTab,
+            modifiers: key.modifiers - KeyModifiers::SHIFT,
+            ..key
+        },
+        _ => key,
+    }
+}
+
+fn is_shift_tab(key: &KeyEvent) -> bool {
+    match key.code {
+        KeyCode::BackTab => true,
+        KeyCode::Tab => key.modifiers.contains(KeyModifiers::SHIFT),
+        _ => false,
+    }
+}
+
+fn is_compat(key: &KeyEvent, code: KeyCode, ctrl: char) -> bool {
+    if key.kind == KeyEventKind::Release {
+        return false;
+    }
+    (key.code == code && key.modifiers == KeyModifiers::NONE)
+        || (key.code == KeyCode::Char(ctrl) && key.modifiers == KeyModifiers::CONTROL)
+}
+
+/// Enter or Ctrl+M.
+///
+/// With `KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES` the
+/// terminal reports Ctrl+M as itself, without it Ctrl+M is Enter.
+/// This accepts both, so a widget behaves the same either way.
+///
+/// Matches press and repeat, like `ct_event!(keycode press Enter)`.
+pub fn is_enter(key: &KeyEvent) -> bool {
+    is_compat(key, KeyCode::Enter, 'm')
+}
+
+/// Tab or Ctrl+I. See [is_enter].
+pub fn is_tab(key: &KeyEvent) -> bool {
+    is_compat(key, KeyCode::Tab, 'i')
+}
+
+/// Esc or Ctrl+[. See [is_enter].
+pub fn is_esc(key: &KeyEvent) -> bool {
+    is_compat(key, KeyCode::Esc, '[')
+}
+
+/// Shift+Tab.
+///
+/// This is BackTab with or without SHIFT, or Tab with SHIFT,
+/// depending on the terminal and the keyboard enhancement flags.
+///
+/// Matches press and repeat, like `ct_event!(keycode press backtab)`.
+pub fn is_backtab(key: &KeyEvent) -> bool {
+    key.kind != KeyEventKind::Release
+        && (key.modifiers == KeyModifiers::NONE || key.modifiers == KeyModifiers::SHIFT)
+        && is_shift_tab(key)
+}
+
+/// Events that usually edit the content of a widget.
+///
+/// These are printable characters, Enter, Backspace and Delete,
+/// cut, paste, undo and redo with Ctrl+X, Ctrl+V, Ctrl+Z and Ctrl+Y,
+/// and Paste events. Tab is not included, it usually moves the focus.
+///
+/// Use this with [read_only](crate::handler::read_only) for text-like
+/// widgets.
+pub fn is_edit_event(event: &Event) -> bool {
+    match event {
+        Event::Key(key) if key.kind != KeyEventKind::Release => match key.code {
+            KeyCode::Char(c) => {
+                let modifiers = key.modifiers.difference(KeyModifiers::SHIFT);
+                modifiers.is_empty()
+                    || (modifiers == KeyModifiers::CONTROL
+                        && matches!(c.to_ascii_lowercase(), 'x' | 'v' | 'z' | 'y'))
+            }
+            KeyCode::Enter | KeyCode::Backspace | KeyCode::Delete => true,
+            _ => false,
+        },
+        Event::Paste(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns the KeyEvent for a key press, None for anything else.
+///
+/// On Windows crossterm reports both the press and the release of
+/// a key. Code that matches on `Event::Key(k)` without looking at
+/// the kind sees every key twice. This lets Press and Repeat pass,
+/// like `ct_event!(key press ..)` does.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+/// use rat_event::crossterm::key_press_only;
+///
+/// let press = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+/// let release = Event::Key(KeyEvent::new_with_kind(
+///     KeyCode::Enter,
+///     KeyModifiers::NONE,
+///     KeyEventKind::Release,
+/// ));
+///
+/// assert!(key_press_only(&press).is_some());
+/// assert!(key_press_only(&release).is_none());
+/// assert!(key_press_only(&Event::FocusLost).is_none());
+/// ```
+pub fn key_press_only(event: &Event) -> Option<&KeyEvent> {
+    match event {
+        Event::Key(key) if key.kind != KeyEventKind::Release => Some(key),
+        _ => None,
+    }
+}
+
+/// Direction of a scroll event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl ScrollDirection {
+    /// Column and row delta for one step.
+    pub fn delta(self) -> (i16, i16) {
+        match self {
+            ScrollDirection::Up => (0, -1),
+            ScrollDirection::Down => (0, 1),
+            ScrollDirection::Left => (-1, 0),
+            ScrollDirection::Right => (1, 0),
+        }
+    }
+}
+
+/// Direction of a scroll event, None for anything else.
+///
+/// Terminals that don't send ScrollLeft/ScrollRight use
+/// Shift+ScrollUp/ScrollDown for horizontal scrolling. Those are
+/// translated to Left/Right. ScrollLeft/ScrollRight are always
+/// Left/Right, whatever the modifiers, so an event is never
+/// counted twice.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+/// use rat_event::crossterm::{scroll_direction, ScrollDirection};
+///
+/// let scroll = |kind, modifiers| Event::Mouse(MouseEvent {
+///     kind,
+///     column: 0,
+///     row: 0,
+///     modifiers,
+/// });
+///
+/// assert_eq!(
+///     scroll_direction(&scroll(MouseEventKind::ScrollDown, KeyModifiers::NONE)),
+///     Some(ScrollDirection::Down)
+/// );
+/// assert_eq!(
+///     scroll_direction(&scroll(MouseEventKind::ScrollDown, KeyModifiers::SHIFT)),
+///     Some(ScrollDirection::Right)
+/// );
+/// assert_eq!(
+///     scroll_direction(&scroll(MouseEventKind::ScrollRight, KeyModifiers::SHIFT)),
+///     Some(ScrollDirection::Right)
+/// );
+/// ```
+pub fn scroll_direction(event: &Event) -> Option<ScrollDirection> {
+    let Event::Mouse(m) = event else {
+        return None;
+    };
+    let shift = m.modifiers.contains(KeyModifiers::SHIFT);
+    match m.kind {
+        MouseEventKind::ScrollUp if shift => Some(ScrollDirection::Left),
+        MouseEventKind::ScrollDown if shift => Some(ScrollDirection::Right),
+        MouseEventKind::ScrollUp => Some(ScrollDirection::Up),
+        MouseEventKind::ScrollDown => Some(ScrollDirection::Down),
+        MouseEventKind::ScrollLeft => Some(ScrollDirection::Left),
+        MouseEventKind::ScrollRight => Some(ScrollDirection::Right),
+        _ => None,
+    }
 }
 
 /// This macro produces pattern matches for crossterm events.
@@ -50,321 +334,931 @@ pub mod modifiers {
 ///
 /// __Caveat__
 /// press covers both KeyEventKind::Press and KeyEventKind::Repeat
-/// which is probably what you expect. This macro doesn't cover
-/// the case where those two need to be distinguished.
+/// which is probably what you expect. Use repeat to match only
+/// KeyEventKind::Repeat, and any-kind to match press, repeat and
+/// release alike.
+///
+/// __Caveat__
+/// Windows reports the press and the release of every key.
+/// All patterns except any-kind check the kind, so a binding
+/// fires only once. If you match on `Event::Key` directly, use
+/// [key_press_only].
+///
+/// __Caveat__
+/// Release and Repeat events are only sent if the terminal supports
+/// the kitty keyboard protocol, and it has been activated with
+/// `PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)`.
+/// Otherwise, every key arrives as KeyEventKind::Press, and the
+/// release patterns never match.
+///
+/// __Caveat__
+/// Horizontal scrolling arrives as ScrollLeft/ScrollRight, or as
+/// Shift+ScrollUp/ScrollDown with terminals that don't know better.
+/// Matching both `scroll right` and `scroll SHIFT down` is fine,
+/// each wheel step is a single event and matches only one arm.
+/// [scroll_direction] does this translation.
 ///
 ///
 /// Syntax:
 /// ```bnf
-/// "key" ("press"|"release") (modifier "-")? "'" char "'"
-/// "keycode" ("press"|"release") (modifier "-")? keycode
-/// "mouse" ("down"|"up"|"drag") (modifier "-")? button "for" col_id "," row_id
+/// "key" ("press"|"release"|"repeat"|"any-kind") (modifier "-")? "'" char "'"
+/// "key" ("press"|"release") "shifted" "'" char "'"
+/// "keycode" ("press"|"release"|"repeat"|"any-kind") (modifier "-")? keycode
+/// "keycode" "press" "compat" ("Enter"|"Tab"|"Esc")
+/// "keycode" "press" "backtab"
+/// "key" "keypad" (modifier "-")? "'" char "'"
+/// "keycode" "keypad" (modifier "-")? keycode
+/// "keycode" "media" mediakeycode
+/// "mouse" ("down"|"up"|"drag") (modifier "-")? button ("for" col_id "," row_id)?
 /// "mouse" "any" (modifier)? ("for" mouseevt)?
-/// "mouse" "moved" ("for" col_id "," row_id)?
-/// "scroll" ("up"|"down") "for" col_id "," row_id
-/// "resized" "for" cols_id "," rows_id
+/// "mouse" "moved" (modifier? "for" col_id "," row_id)?
+/// "scroll" (modifier)? ("up"|"down"|"left"|"right") ("for" col_id "," row_id)?
+/// "resized" ("for" cols_id "," rows_id)?
 /// "focus_gained"
 /// "focus_lost"
-/// "paste" identifier
+/// "paste" (identifier)?
 /// ```
 ///
 /// where
 ///
 /// ```bnf
 /// modifier := <<one of the KeyModifiers's>> | "CONTROL_SHIFT" | "ALT_SHIFT"
+///     | "CONTROL_ALT" | "CONTROL_ALT_SHIFT"
 /// char := <<some character>>
-/// keycode := <<one of the defined KeyCode's>>
+/// keycode := <<one of the defined KeyCode's>> | "F(" pattern ")"
+/// mediakeycode := <<one of the defined MediaKeyCode's>>
 /// button := <<one of the defined MouseButton's>>
 /// ```
 ///
 /// __Caveat__
+/// Keypad keys are only marked with KeyEventState::KEYPAD if the
+/// kitty keyboard protocol is active with
+/// `KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES`.
+/// The same holds for media keys. Otherwise, the keypad
+/// sends the same events as the main keyboard. The other patterns
+/// don't look at the state, so put the keypad arm first and let
+/// the regular arm catch the rest.
+///
+/// __Caveat__
+/// Function keys are matched with `ct_event!(keycode press F(5))`.
+/// The number is a pattern, so `ct_event!(keycode press F(n))` binds
+/// the function key and `ct_event!(keycode press F(1..=12))` works too.
+/// Some terminals report SHIFT-F1 to SHIFT-F12 as F13 to F24 without
+/// any modifier. If this matters match both, as in
+/// `ct_event!(keycode press SHIFT-F(5)) | ct_event!(keycode press F(17))`.
+///
+/// __Caveat__
 /// `ct_event!(key press SHIFT-'f')` doesn't work. It must be
 /// `ct_event!(key press SHIFT-'F')` with capital F. This holds for
 /// any combination of SHIFT too.
 ///
+/// The mouse position is bound to the given identifiers. If you match
+/// on a `&Event` they are bound as `&u16`. To use one of the [MouseFlags](crate::util::MouseFlags)
+/// functions as a guard, bind the whole MouseEvent with `mouse any for m`.
+///
+/// ```rust
+/// use rat_event::crossterm::event::Event;
+/// use rat_event::ct_event;
+/// use rat_event::util::MouseFlags;
+/// use ratatui::layout::Rect;
+///
+/// fn click(event: &Event, flags: &MouseFlags, area: Rect) -> Option<(u16, u16)> {
+///     match event {
+///         ct_event!(mouse any for m) if flags.doubleclick(area, m) => None,
+///         ct_event!(mouse down Left for x, y) if area.contains((*x, *y).into()) => {
+///             Some((*x, *y))
+///         }
+///         _ => None,
+///     }
+/// }
+/// ```
+///
+/// The character is a pattern. `ct_event!(key press c)` binds any
+/// character to `c`, and `ct_event!(key press _)` matches any character
+/// without binding it. Space arrives as `KeyCode::Char(' ')` and is
+/// matched too. Enter, Tab, Backspace and Esc have their own KeyCode
+/// and need the keycode form.
+///
+/// Whether a shifted character arrives with SHIFT set depends on the
+/// terminal. `ct_event!(key press shifted '?')` matches both,
+/// with SHIFT and without any modifier. For a text input
+/// `ct_event!(key press shifted c)` is all you need.
+///
+/// __Caveat__
+/// With `KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES` Ctrl+M,
+/// Ctrl+I and Ctrl+[ are reported as themselves, otherwise they
+/// arrive as Enter, Tab and Esc. `ct_event!(keycode press compat Enter)`
+/// matches both, as do [is_enter], [is_tab] and [is_esc].
+///
+/// __Caveat__
+/// Shift+Tab arrives as BackTab, BackTab with SHIFT, or Tab with SHIFT.
+/// `ct_event!(keycode press backtab)` and [is_backtab] match all of them.
+///
+/// Letters are more varied, Shift+a may even arrive as `'a'` with
+/// SHIFT. Run the key through [normalize_key] first, then
+/// `ct_event!(key press 'A')` is guaranteed to match.
+///
 #[macro_export]
 macro_rules! ct_event {
     (key press $keychar:pat) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char($keychar),
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
             modifiers: $crate::crossterm::modifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
     (key press ANY-$keychar:pat) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char($keychar),
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (key press shifted $keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::crossterm::modifiers::NONE|$crate::crossterm::modifiers::SHIFT,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
     (key press $mod:ident-$keychar:pat) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char($keychar),
-            modifiers: $crate::crossterm::modifiers::$mod,
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
     (key release $keychar:pat) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char($keychar),
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
             modifiers: $crate::crossterm::modifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Release,
+            kind: $crate::crossterm::event::KeyEventKind::Release,
             ..
         })
     };
     (key release ANY-$keychar:pat) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char($keychar),
-            kind: crossterm::event::KeyEventKind::Release,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            kind: $crate::crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
+    (key release shifted $keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::crossterm::modifiers::NONE|$crate::crossterm::modifiers::SHIFT,
+            kind: $crate::crossterm::event::KeyEventKind::Release,
             ..
         })
     };
     (key release $mod:ident-$keychar:pat) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char($keychar),
-            modifiers: $crate::crossterm::modifiers::$mod,
-            kind: crossterm::event::KeyEventKind::Release,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
+    (key repeat $keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (key repeat ANY-$keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (key repeat $mod:ident-$keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (key any-kind $keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            ..
+        })
+    };
+    (key any-kind ANY-$keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            ..
+        })
+    };
+    (key any-kind $mod:ident-$keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_modifier!($mod),
             ..
         })
     };
 
-    (keycode press F($code:literal)) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::F($code),
+    (key keypad $keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
             modifiers: $crate::crossterm::modifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            state: $crate::crossterm::states::KEYPAD|$crate::crossterm::states::KEYPAD_CAPS_LOCK|$crate::crossterm::states::KEYPAD_NUM_LOCK|$crate::crossterm::states::KEYPAD_CAPS_NUM_LOCK,
+        })
+    };
+    (key keypad $mod:ident-$keychar:pat) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            state: $crate::crossterm::states::KEYPAD|$crate::crossterm::states::KEYPAD_CAPS_LOCK|$crate::crossterm::states::KEYPAD_NUM_LOCK|$crate::crossterm::states::KEYPAD_CAPS_NUM_LOCK,
+        })
+    };
+    (keycode press backtab) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::BackTab,
+            modifiers: $crate::crossterm::modifiers::NONE|$crate::crossterm::modifiers::SHIFT,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        }) | $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Tab,
+            modifiers: $crate::crossterm::modifiers::SHIFT,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
-    (keycode press $mod:ident-F($code:literal)) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::F($code),
-            modifiers: $crate::crossterm::modifiers::$mod,
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+    (keycode press compat Enter) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Enter,
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        }) | $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char('m'),
+            modifiers: $crate::crossterm::modifiers::CONTROL,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode press compat Tab) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Tab,
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        }) | $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char('i'),
+            modifiers: $crate::crossterm::modifiers::CONTROL,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode press compat Esc) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Esc,
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        }) | $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Char('['),
+            modifiers: $crate::crossterm::modifiers::CONTROL,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode press F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode press $mod:ident-F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
     (keycode press $code:ident) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::$code,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
             modifiers: $crate::crossterm::modifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
     (keycode press $mod:ident-$code:ident) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::$code,
-            modifiers: $crate::crossterm::modifiers::$mod,
-            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
             ..
         })
     };
-    (keycode release F($code:literal)) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::F($code),
+    (keycode release F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
             modifiers: $crate::crossterm::modifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Release,
+            kind: $crate::crossterm::event::KeyEventKind::Release,
             ..
         })
     };
-    (keycode release $mod:ident-F($code:literal)) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::F($code),
-            modifiers: $crate::crossterm::modifiers::$mod,
-            kind: crossterm::event::KeyEventKind::Release,
+    (keycode release $mod:ident-F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Release,
             ..
         })
     };
     (keycode release $code:ident) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::$code,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
             modifiers: $crate::crossterm::modifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Release,
+            kind: $crate::crossterm::event::KeyEventKind::Release,
             ..
         })
     };
     (keycode release $mod:ident-$code:ident) => {
-        crossterm::event::Event::Key(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::$code,
-            modifiers: $crate::crossterm::modifiers::$mod,
-            kind: crossterm::event::KeyEventKind::Release,
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
+    (keycode repeat F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode repeat $mod:ident-F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode repeat $code:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode repeat $mod:ident-$code:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode any-kind F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            ..
+        })
+    };
+    (keycode any-kind $mod:ident-F($code:pat)) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::F($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            ..
+        })
+    };
+    (keycode any-kind $code:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            ..
+        })
+    };
+    (keycode any-kind $mod:ident-$code:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::__ct_modifier!($mod),
             ..
         })
     };
 
+    (keycode keypad $code:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            state: $crate::crossterm::states::KEYPAD|$crate::crossterm::states::KEYPAD_CAPS_LOCK|$crate::crossterm::states::KEYPAD_NUM_LOCK|$crate::crossterm::states::KEYPAD_CAPS_NUM_LOCK,
+        })
+    };
+    (keycode keypad $mod:ident-$code:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::__ct_keycode!($code),
+            modifiers: $crate::__ct_modifier!($mod),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            state: $crate::crossterm::states::KEYPAD|$crate::crossterm::states::KEYPAD_CAPS_LOCK|$crate::crossterm::states::KEYPAD_NUM_LOCK|$crate::crossterm::states::KEYPAD_CAPS_NUM_LOCK,
+        })
+    };
+    (keycode media $media:ident) => {
+        $crate::crossterm::event::Event::Key($crate::crossterm::event::KeyEvent {
+            code: $crate::crossterm::event::KeyCode::Media($crate::__ct_media!($media)),
+            kind: $crate::crossterm::event::KeyEventKind::Press|$crate::crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
     (mouse down $button:ident for $col:ident, $row:ident ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::$button),
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Down($crate::__ct_button!($button)),
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (mouse down $mod:ident-$button:ident for $col:ident, $row:ident ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::$button),
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Down($crate::__ct_button!($button)),
             column: $col,
             row: $row,
-            modifiers: $crate::crossterm::modifiers::$mod,
+            modifiers: $crate::__ct_modifier!($mod),
         })
     };
     (mouse up $button:ident for $col:ident, $row:ident ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Up(crossterm::event::MouseButton::$button),
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Up($crate::__ct_button!($button)),
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (mouse up $mod:ident-$button:ident for $col:ident, $row:ident ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Up(crossterm::event::MouseButton::$button),
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Up($crate::__ct_button!($button)),
             column: $col,
             row: $row,
-            modifiers: $crate::crossterm::modifiers::$mod,
+            modifiers: $crate::__ct_modifier!($mod),
         })
     };
     (mouse drag $button:ident for $col:ident, $row:ident ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Drag(crossterm::event::MouseButton::$button),
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Drag($crate::__ct_button!($button)),
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (mouse drag $mod:ident-$button:ident for $col:ident, $row:ident ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Drag(crossterm::event::MouseButton::$button),
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Drag($crate::__ct_button!($button)),
             column: $col,
             row: $row,
-            modifiers: $crate::crossterm::modifiers::$mod,
+            modifiers: $crate::__ct_modifier!($mod),
         })
     };
 
+    (mouse down $button:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Down($crate::__ct_button!($button)),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            ..
+        })
+    };
+    (mouse down $mod:ident-$button:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Down($crate::__ct_button!($button)),
+            modifiers: $crate::__ct_modifier!($mod),
+            ..
+        })
+    };
+    (mouse up $button:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Up($crate::__ct_button!($button)),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            ..
+        })
+    };
+    (mouse up $mod:ident-$button:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Up($crate::__ct_button!($button)),
+            modifiers: $crate::__ct_modifier!($mod),
+            ..
+        })
+    };
+    (mouse drag $button:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Drag($crate::__ct_button!($button)),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            ..
+        })
+    };
+    (mouse drag $mod:ident-$button:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Drag($crate::__ct_button!($button)),
+            modifiers: $crate::__ct_modifier!($mod),
+            ..
+        })
+    };
 
     (mouse any for $mouse:ident) => {
-        crossterm::event::Event::Mouse($mouse)
+        $crate::crossterm::event::Event::Mouse($mouse)
     };
     (mouse any $mod:ident for $mouse:ident ) => {
-        crossterm::event::Event::Mouse($mouse @ crossterm::event::MouseEvent {
-            modifiers: $crate::crossterm::modifiers::$mod,
+        $crate::crossterm::event::Event::Mouse($mouse @ $crate::crossterm::event::MouseEvent {
+            modifiers: $crate::__ct_modifier!($mod),
             ..
         })
     };
 
     (mouse moved ) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Moved,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Moved,
             modifiers: $crate::crossterm::modifiers::NONE,
             ..
         })
     };
     (mouse moved for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::Moved,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Moved,
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
+    (mouse moved $mod:ident for $col:ident, $row:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::Moved,
+            column: $col,
+            row: $row,
+            modifiers: $crate::__ct_modifier!($mod),
+        })
+    };
 
     (scroll $mod:ident down for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollDown,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollDown,
             column: $col,
             row: $row,
-            modifiers: $crate::crossterm::modifiers::$mod,
+            modifiers: $crate::__ct_modifier!($mod),
         })
     };
     (scroll down for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollDown,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollDown,
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (scroll down) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollDown,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollDown,
             modifiers: $crate::crossterm::modifiers::NONE,
             ..
         })
     };
     (scroll $mod:ident up for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollUp,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollUp,
             column: $col,
             row: $row,
-            modifiers: $crate::crossterm::modifiers::$mod,
+            modifiers: $crate::__ct_modifier!($mod),
         })
     };
     (scroll up for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollUp,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollUp,
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (scroll up) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollUp,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollUp,
             modifiers: $crate::crossterm::modifiers::NONE,
             ..
         })
     };
 
-    //??
+    (scroll $mod:ident left for $col:ident, $row:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollLeft,
+            column: $col,
+            row: $row,
+            modifiers: $crate::__ct_modifier!($mod),
+        })
+    };
     (scroll left for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollLeft,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollLeft,
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (scroll left) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollLeft,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollLeft,
             modifiers: $crate::crossterm::modifiers::NONE,
             ..
         })
     };
-    //??
+    (scroll $mod:ident right for $col:ident, $row:ident) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollRight,
+            column: $col,
+            row: $row,
+            modifiers: $crate::__ct_modifier!($mod),
+        })
+    };
     (scroll right for $col:ident, $row:ident) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollRight,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollRight,
             column: $col,
             row: $row,
             modifiers: $crate::crossterm::modifiers::NONE,
         })
     };
     (scroll right) => {
-        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
-            kind: crossterm::event::MouseEventKind::ScrollRight,
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::ScrollRight,
             modifiers: $crate::crossterm::modifiers::NONE,
             ..
         })
     };
 
     (resized) => {
-        crossterm::event::Event::Resize(_, _)
+        $crate::crossterm::event::Event::Resize(_, _)
     };
     (resized for $cols:ident, $rows:ident ) => {
-        crossterm::event::Event::Resize($cols, $rows)
+        $crate::crossterm::event::Event::Resize($cols, $rows)
     };
     (focus_gained) => {
-        crossterm::event::Event::FocusGained
+        $crate::crossterm::event::Event::FocusGained
     };
     (focus_lost) => {
-        crossterm::event::Event::FocusLost
+        $crate::crossterm::event::Event::FocusLost
+    };
+    (paste) => {
+        $crate::crossterm::event::Event::Paste(_)
     };
     (paste $value:ident) => {
-        crossterm::event::Event::Paste($value)
+        $crate::crossterm::event::Event::Paste($value)
+    };
+    (key $($t:tt)*) => {
+        compile_error!(concat!(
+            "ct_event!: can't read `key ",
+            stringify!($($t)*),
+            "`, expected `key press|release|repeat|any-kind [MODIFIER-]'c'`, ",
+            "`key press|release shifted 'c'` or `key keypad [MODIFIER-]'c'`"
+        ))
+    };
+    (keycode $($t:tt)*) => {
+        compile_error!(concat!(
+            "ct_event!: can't read `keycode ",
+            stringify!($($t)*),
+            "`, expected `keycode press|release|repeat|any-kind [MODIFIER-]Key`, ",
+            "`keycode keypad [MODIFIER-]Key`, `keycode media Key`, ",
+            "`keycode press compat Enter|Tab|Esc` or `keycode press backtab`"
+        ))
+    };
+    (mouse $($t:tt)*) => {
+        compile_error!(concat!(
+            "ct_event!: can't read `mouse ",
+            stringify!($($t)*),
+            "`, expected `mouse down|up|drag [MODIFIER-]Button [for col, row]`, ",
+            "`mouse any [MODIFIER] for m` or `mouse moved [[MODIFIER] for col, row]`"
+        ))
+    };
+    (scroll $($t:tt)*) => {
+        compile_error!(concat!(
+            "ct_event!: can't read `scroll ",
+            stringify!($($t)*),
+            "`, expected `scroll [MODIFIER] up|down|left|right [for col, row]`"
+        ))
+    };
+    ($($t:tt)*) => {
+        compile_error!(concat!(
+            "ct_event!: can't read `",
+            stringify!($($t)*),
+            "`, expected one of key, keycode, mouse, scroll, resized, ",
+            "focus_gained, focus_lost or paste"
+        ))
+    };
+}
+
+// The helpers below check the names used in ct_event! and give
+// a readable error for a typo.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_modifier {
+    (NONE) => {
+        $crate::crossterm::modifiers::NONE
+    };
+    (CONTROL) => {
+        $crate::crossterm::modifiers::CONTROL
+    };
+    (SHIFT) => {
+        $crate::crossterm::modifiers::SHIFT
+    };
+    (ALT) => {
+        $crate::crossterm::modifiers::ALT
+    };
+    (META) => {
+        $crate::crossterm::modifiers::META
+    };
+    (SUPER) => {
+        $crate::crossterm::modifiers::SUPER
+    };
+    (HYPER) => {
+        $crate::crossterm::modifiers::HYPER
+    };
+    (CONTROL_ALT) => {
+        $crate::crossterm::modifiers::CONTROL_ALT
+    };
+    (CONTROL_SHIFT) => {
+        $crate::crossterm::modifiers::CONTROL_SHIFT
+    };
+    (ALT_SHIFT) => {
+        $crate::crossterm::modifiers::ALT_SHIFT
+    };
+    (CONTROL_ALT_SHIFT) => {
+        $crate::crossterm::modifiers::CONTROL_ALT_SHIFT
+    };
+    ($other:ident) => {
+        compile_error!(concat!(
+            "ct_event!: unknown modifier `",
+            stringify!($other),
+            "`, expected one of NONE, CONTROL, SHIFT, ALT, META, SUPER, HYPER, CONTROL_ALT, CONTROL_SHIFT, ALT_SHIFT or CONTROL_ALT_SHIFT"
+        ))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_keycode {
+    (Backspace) => {
+        $crate::crossterm::event::KeyCode::Backspace
+    };
+    (Enter) => {
+        $crate::crossterm::event::KeyCode::Enter
+    };
+    (Left) => {
+        $crate::crossterm::event::KeyCode::Left
+    };
+    (Right) => {
+        $crate::crossterm::event::KeyCode::Right
+    };
+    (Up) => {
+        $crate::crossterm::event::KeyCode::Up
+    };
+    (Down) => {
+        $crate::crossterm::event::KeyCode::Down
+    };
+    (Home) => {
+        $crate::crossterm::event::KeyCode::Home
+    };
+    (End) => {
+        $crate::crossterm::event::KeyCode::End
+    };
+    (PageUp) => {
+        $crate::crossterm::event::KeyCode::PageUp
+    };
+    (PageDown) => {
+        $crate::crossterm::event::KeyCode::PageDown
+    };
+    (Tab) => {
+        $crate::crossterm::event::KeyCode::Tab
+    };
+    (BackTab) => {
+        $crate::crossterm::event::KeyCode::BackTab
+    };
+    (Delete) => {
+        $crate::crossterm::event::KeyCode::Delete
+    };
+    (Insert) => {
+        $crate::crossterm::event::KeyCode::Insert
+    };
+    (Null) => {
+        $crate::crossterm::event::KeyCode::Null
+    };
+    (Esc) => {
+        $crate::crossterm::event::KeyCode::Esc
+    };
+    (CapsLock) => {
+        $crate::crossterm::event::KeyCode::CapsLock
+    };
+    (ScrollLock) => {
+        $crate::crossterm::event::KeyCode::ScrollLock
+    };
+    (NumLock) => {
+        $crate::crossterm::event::KeyCode::NumLock
+    };
+    (PrintScreen) => {
+        $crate::crossterm::event::KeyCode::PrintScreen
+    };
+    (Pause) => {
+        $crate::crossterm::event::KeyCode::Pause
+    };
+    (Menu) => {
+        $crate::crossterm::event::KeyCode::Menu
+    };
+    (KeypadBegin) => {
+        $crate::crossterm::event::KeyCode::KeypadBegin
+    };
+    ($other:ident) => {
+        compile_error!(concat!(
+            "ct_event!: unknown key `",
+            stringify!($other),
+            "`, expected one of Backspace, Enter, Left, Right, Up, Down, Home, End, PageUp, PageDown, Tab, BackTab, Delete, Insert, Null, Esc, CapsLock, ScrollLock, NumLock, PrintScreen, Pause, Menu, KeypadBegin or F(n). Use `key` instead of `keycode` for characters"
+        ))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_button {
+    (Left) => {
+        $crate::crossterm::event::MouseButton::Left
+    };
+    (Right) => {
+        $crate::crossterm::event::MouseButton::Right
+    };
+    (Middle) => {
+        $crate::crossterm::event::MouseButton::Middle
+    };
+    ($other:ident) => {
+        compile_error!(concat!(
+            "ct_event!: unknown mouse button `",
+            stringify!($other),
+            "`, expected one of Left, Right or Middle"
+        ))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_media {
+    (Play) => {
+        $crate::crossterm::event::MediaKeyCode::Play
+    };
+    (Pause) => {
+        $crate::crossterm::event::MediaKeyCode::Pause
+    };
+    (PlayPause) => {
+        $crate::crossterm::event::MediaKeyCode::PlayPause
+    };
+    (Reverse) => {
+        $crate::crossterm::event::MediaKeyCode::Reverse
+    };
+    (Stop) => {
+        $crate::crossterm::event::MediaKeyCode::Stop
+    };
+    (FastForward) => {
+        $crate::crossterm::event::MediaKeyCode::FastForward
+    };
+    (Rewind) => {
+        $crate::crossterm::event::MediaKeyCode::Rewind
+    };
+    (TrackNext) => {
+        $crate::crossterm::event::MediaKeyCode::TrackNext
+    };
+    (TrackPrevious) => {
+        $crate::crossterm::event::MediaKeyCode::TrackPrevious
+    };
+    (Record) => {
+        $crate::crossterm::event::MediaKeyCode::Record
+    };
+    (LowerVolume) => {
+        $crate::crossterm::event::MediaKeyCode::LowerVolume
+    };
+    (RaiseVolume) => {
+        $crate::crossterm::event::MediaKeyCode::RaiseVolume
+    };
+    (MuteVolume) => {
+        $crate::crossterm::event::MediaKeyCode::MuteVolume
+    };
+    ($other:ident) => {
+        compile_error!(concat!(
+            "ct_event!: unknown media key `",
+            stringify!($other),
+            "`, expected one of Play, Pause, PlayPause, Reverse, Stop, FastForward, Rewind, TrackNext, TrackPrevious, Record, LowerVolume, RaiseVolume or MuteVolume"
+        ))
     };
 }
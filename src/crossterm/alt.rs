@@ -0,0 +1,149 @@
+use crate::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, ModifierKeyCode, MouseEventKind,
+};
+
+/// Alt+letter accelerator for menus.
+///
+/// Returns the lowercase character for a key press with ALT,
+/// or with ALT and SHIFT. Anything else, Alt+Left for example,
+/// returns None.
+pub fn alt_accelerator(event: &Event) -> Option<char> {
+    match event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) if *modifiers - KeyModifiers::SHIFT == KeyModifiers::ALT => c.to_lowercase().next(),
+        _ => None,
+    }
+}
+
+/// Detects Alt pressed and released without any other key,
+/// which conventionally toggles the menubar.
+///
+/// The terminal only reports the Alt key itself if the kitty keyboard
+/// protocol is active with `KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES`.
+/// The release needs `KeyboardEnhancementFlags::REPORT_EVENT_TYPES`
+/// too. Until a release event has been seen, the press of Alt
+/// is taken as the toggle.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{
+///     Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, ModifierKeyCode,
+/// };
+/// use rat_event::crossterm::{alt_accelerator, AltHold};
+///
+/// #[derive(Default)]
+/// struct MenuBar {
+///     active: bool,
+///     open: Option<usize>,
+///     alt: AltHold,
+/// }
+///
+/// impl MenuBar {
+///     const MENUS: [char; 3] = ['f', 'e', 'h'];
+///
+///     fn handle(&mut self, event: &Event) {
+///         if self.alt.check(event) {
+///             self.active = !self.active;
+///         } else if let Some(c) = alt_accelerator(event) {
+///             if let Some(idx) = Self::MENUS.iter().position(|v| *v == c) {
+///                 self.active = true;
+///                 self.open = Some(idx);
+///             }
+///         }
+///     }
+/// }
+///
+/// let alt = |kind| Event::Key(KeyEvent::new_with_kind(
+///     KeyCode::Modifier(ModifierKeyCode::LeftAlt),
+///     KeyModifiers::ALT,
+///     kind,
+/// ));
+///
+/// let mut menu = MenuBar::default();
+/// menu.handle(&alt(KeyEventKind::Press));
+/// menu.handle(&alt(KeyEventKind::Release));
+/// assert!(menu.active);
+///
+/// menu.handle(&Event::Key(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::ALT)));
+/// assert_eq!(menu.open, Some(1));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AltHold {
+    armed: bool,
+    releases: bool,
+}
+
+impl AltHold {
+    /// New state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if Alt has been pressed and released
+    /// without any other key in between.
+    pub fn check(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) if is_alt(key.code) => match key.kind {
+                KeyEventKind::Press => {
+                    if self.releases {
+                        self.armed = true;
+                        false
+                    } else {
+                        // no releases, this is all we get.
+                        self.armed = false;
+                        true
+                    }
+                }
+                KeyEventKind::Repeat => false,
+                KeyEventKind::Release => {
+                    self.releases = true;
+                    let armed = self.armed;
+                    self.armed = false;
+                    armed
+                }
+            },
+            Event::Key(key) => {
+                if key.kind == KeyEventKind::Release {
+                    self.releases = true;
+                } else {
+                    self.armed = false;
+                }
+                false
+            }
+            Event::Mouse(m) => {
+                if matches!(
+                    m.kind,
+                    MouseEventKind::Down(_) | MouseEventKind::Up(_) | MouseEventKind::Drag(_)
+                ) {
+                    self.armed = false;
+                }
+                false
+            }
+            Event::FocusLost => {
+                self.armed = false;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Is Alt currently held down without any other key?
+    pub fn is_armed(&self) -> bool {
+        self.armed
+    }
+
+    /// Forget the current state.
+    pub fn reset(&mut self) {
+        self.armed = false;
+    }
+}
+
+fn is_alt(code: KeyCode) -> bool {
+    matches!(
+        code,
+        KeyCode::Modifier(ModifierKeyCode::LeftAlt | ModifierKeyCode::RightAlt)
+    )
+}
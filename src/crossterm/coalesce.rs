@@ -0,0 +1,185 @@
+use crate::crossterm::event::{self, Event, KeyModifiers, MouseEvent, MouseEventKind};
+use std::io;
+use std::time::Duration;
+
+/// Merges floods of mouse events.
+///
+/// If the event queue backs up, it contains a lot of scroll and
+/// move events. Handling each of them with a render in between
+/// only makes things worse.
+///
+/// * A run of ScrollUp/ScrollDown is reduced to the net count.
+///   The events that cancel out are dropped. The same for
+///   ScrollLeft/ScrollRight.
+/// * Of a run of Moved or Drag events only the last is kept.
+///
+/// A run is a sequence of adjacent events with the same modifiers,
+/// so nothing is reordered across key or button events. A run of
+/// scroll events also needs the same position. Without Moved
+/// events in between, e.g. with button-event tracking, scrolling
+/// over one widget and then another still reaches both of them.
+///
+/// ```rust
+/// use rat_event::crossterm::coalesce_events;
+/// use rat_event::crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+///
+/// let mouse = |kind, row| Event::Mouse(MouseEvent {
+///     kind,
+///     column: 0,
+///     row,
+///     modifiers: KeyModifiers::NONE,
+/// });
+///
+/// let mut events = vec![
+///     mouse(MouseEventKind::ScrollDown, 0),
+///     mouse(MouseEventKind::ScrollDown, 0),
+///     mouse(MouseEventKind::ScrollUp, 0),
+///     mouse(MouseEventKind::Moved, 1),
+///     mouse(MouseEventKind::Moved, 2),
+/// ];
+/// coalesce_events(&mut events);
+/// assert_eq!(
+///     events,
+///     vec![
+///         mouse(MouseEventKind::ScrollDown, 0),
+///         mouse(MouseEventKind::Moved, 2),
+///     ]
+/// );
+/// ```
+pub fn coalesce_events(events: &mut Vec<Event>) {
+    let mut out = Vec::with_capacity(events.len());
+    let mut run: Option<ScrollRun> = None;
+
+    for event in events.drain(..) {
+        if let Event::Mouse(m) = &event {
+            if let Some((vertical, step)) = scroll_step(m) {
+                match &mut run {
+                    Some(r)
+                        if r.vertical == vertical
+                            && r.modifiers == m.modifiers
+                            && r.position == (m.column, m.row) =>
+                    {
+                        r.add(step, *m);
+                    }
+                    _ => {
+                        if let Some(r) = run.take() {
+                            r.flush(&mut out);
+                        }
+                        let mut r = ScrollRun::new(vertical, m.modifiers, (m.column, m.row));
+                        r.add(step, *m);
+                        run = Some(r);
+                    }
+                }
+                continue;
+            }
+        }
+
+        if let Some(r) = run.take() {
+            r.flush(&mut out);
+        }
+
+        if let (Some(Event::Mouse(last)), Event::Mouse(m)) = (out.last_mut(), &event) {
+            if is_motion(last) && last.kind == m.kind && last.modifiers == m.modifiers {
+                *last = *m;
+                continue;
+            }
+        }
+        out.push(event);
+    }
+
+    if let Some(r) = run.take() {
+        r.flush(&mut out);
+    }
+
+    *events = out;
+}
+
+/// Reads all the events that are available.
+///
+/// Waits up to timeout for the first event, and then reads all
+/// events that are queued without blocking. Returns an empty
+/// Vec if nothing arrived in time.
+///
+/// ```rust no_run
+/// use rat_event::crossterm::{coalesce_events, read_available};
+/// use std::time::Duration;
+///
+/// let mut events = read_available(Duration::from_millis(50))?;
+/// coalesce_events(&mut events);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn read_available(timeout: Duration) -> io::Result<Vec<Event>> {
+    let mut events = Vec::new();
+    if event::poll(timeout)? {
+        events.push(event::read()?);
+        drain(&mut events)?;
+    }
+    Ok(events)
+}
+
+/// Reads all queued events without blocking.
+pub(crate) fn drain(events: &mut Vec<Event>) -> io::Result<()> {
+    while event::poll(Duration::ZERO)? {
+        events.push(event::read()?);
+    }
+    Ok(())
+}
+
+fn is_motion(m: &MouseEvent) -> bool {
+    matches!(m.kind, MouseEventKind::Moved | MouseEventKind::Drag(_))
+}
+
+/// Axis and direction of a scroll event.
+fn scroll_step(m: &MouseEvent) -> Option<(bool, i32)> {
+    match m.kind {
+        MouseEventKind::ScrollDown => Some((true, 1)),
+        MouseEventKind::ScrollUp => Some((true, -1)),
+        MouseEventKind::ScrollRight => Some((false, 1)),
+        MouseEventKind::ScrollLeft => Some((false, -1)),
+        _ => None,
+    }
+}
+
+struct ScrollRun {
+    vertical: bool,
+    modifiers: KeyModifiers,
+    position: (u16, u16),
+    net: i32,
+    forward: Option<MouseEvent>,
+    backward: Option<MouseEvent>,
+}
+
+impl ScrollRun {
+    fn new(vertical: bool, modifiers: KeyModifiers, position: (u16, u16)) -> Self {
+        Self {
+            vertical,
+            modifiers,
+            position,
+            net: 0,
+            forward: None,
+            backward: None,
+        }
+    }
+
+    fn add(&mut self, step: i32, m: MouseEvent) {
+        self.net += step;
+        if step > 0 {
+            self.forward = Some(m);
+        } else {
+            self.backward = Some(m);
+        }
+    }
+
+    fn flush(self, out: &mut Vec<Event>) {
+        let m = if self.net > 0 {
+            self.forward
+        } else {
+            self.backward
+        };
+        if let Some(m) = m {
+            for _ in 0..self.net.unsigned_abs() {
+                out.push(Event::Mouse(m));
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crate::{HandleEvent, Outcome, Regular};
+
+/// Vim-style count before a command, as in `12j`.
+///
+/// Digits accumulate, Esc cancels the count. Any other key ends
+/// the count, and the handler for that key uses
+/// [CountPrefix::take_count].
+///
+/// A `0` as the first digit is not part of a count, it passes
+/// through. In vim it means "start of line".
+///
+/// This swallows digits, so only call it from navigation-mode
+/// handlers, never where text can be entered.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::CountPrefix;
+/// use rat_event::{ct_event, flow, HandleEvent, Outcome, Regular};
+///
+/// struct List {
+///     count: CountPrefix,
+///     selected: usize,
+/// }
+///
+/// impl HandleEvent<Event, Regular, Outcome> for List {
+///     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+///         flow!(self.count.handle(event, Regular));
+///         match event {
+///             ct_event!(key press 'j') => {
+///                 self.selected += self.count.take_count().unwrap_or(1);
+///                 Outcome::Changed
+///             }
+///             ct_event!(key press '0') => {
+///                 self.selected = 0;
+///                 Outcome::Changed
+///             }
+///             _ => {
+///                 self.count.reset();
+///                 Outcome::Continue
+///             }
+///         }
+///     }
+/// }
+///
+/// let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+///
+/// let mut list = List {
+///     count: CountPrefix::new(),
+///     selected: 0,
+/// };
+/// list.handle(&key('1'), Regular);
+/// list.handle(&key('0'), Regular);
+/// list.handle(&key('j'), Regular);
+/// assert_eq!(list.selected, 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountPrefix {
+    max: usize,
+    count: Option<usize>,
+}
+
+impl Default for CountPrefix {
+    fn default() -> Self {
+        Self {
+            max: 9999,
+            count: None,
+        }
+    }
+}
+
+impl CountPrefix {
+    /// New state, the count is capped at 9999.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum count. Anything bigger is capped.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Maximum count.
+    pub fn get_max(&self) -> usize {
+        self.max
+    }
+
+    /// Current count, without taking it.
+    pub fn count(&self) -> Option<usize> {
+        self.count
+    }
+
+    /// Is a count being typed?
+    pub fn is_active(&self) -> bool {
+        self.count.is_some()
+    }
+
+    /// Returns the count and resets it.
+    pub fn take_count(&mut self) -> Option<usize> {
+        self.count.take()
+    }
+
+    /// Forget the count.
+    pub fn reset(&mut self) {
+        self.count = None;
+    }
+
+    /// Feed the next key.
+    ///
+    /// Returns true if the key has been used, which is the case for
+    /// the digits of the count, and for Esc while a count is typed.
+    /// Releases are never used.
+    pub fn feed(&mut self, key: &KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        // some layouts need shift for the digits.
+        if key.modifiers - KeyModifiers::SHIFT != KeyModifiers::NONE {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char(c @ '0'..='9') => {
+                let digit = c as usize - '0' as usize;
+                match self.count {
+                    None if digit == 0 => false,
+                    None => {
+                        self.count = Some(digit.min(self.max));
+                        true
+                    }
+                    Some(count) => {
+                        let count = count.saturating_mul(10).saturating_add(digit);
+                        self.count = Some(count.min(self.max));
+                        true
+                    }
+                }
+            }
+            KeyCode::Esc if self.count.is_some() => {
+                self.count = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Feeds key events to [CountPrefix::feed].
+///
+/// Returns Changed for a used key, to show the count somewhere.
+impl HandleEvent<Event, Regular, Outcome> for CountPrefix {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            Event::Key(key) if self.feed(key) => Outcome::Changed,
+            _ => Outcome::Continue,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crate::{ConsumedEvent, Dialog, HandleEvent, Outcome};
+
+/// Stack of nested modal dialogs.
+///
+/// Only the topmost dialog gets the events, and everything below
+/// is frozen. While the stack is not empty _all_ events are consumed,
+/// a result that is not consumed is turned into `Outcome::Unchanged`.
+/// If the top dialog doesn't consume an Esc, the dialog is popped and
+/// the result is `Outcome::Changed`.
+///
+/// An empty stack returns `R::default()`, see
+/// [not consumed](ConsumedEvent#not-consumed).
+///
+/// Render the dialogs in the order of [DialogStack::iter], so the top
+/// dialog is drawn last.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::DialogStack;
+/// use rat_event::{Dialog, HandleEvent, Outcome};
+///
+/// struct MsgBox(&'static str);
+///
+/// impl HandleEvent<Event, Dialog, Outcome> for MsgBox {
+///     fn handle(&mut self, _event: &Event, _qualifier: Dialog) -> Outcome {
+///         Outcome::Continue
+///     }
+/// }
+///
+/// let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+/// let x = Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+///
+/// let mut dialogs = DialogStack::new();
+/// dialogs.push(MsgBox("settings"));
+/// dialogs.push(MsgBox("error"));
+///
+/// assert_eq!(dialogs.handle(&x, Dialog), Outcome::Unchanged);
+/// assert_eq!(dialogs.handle(&esc, Dialog), Outcome::Changed);
+/// assert_eq!(dialogs.top().map(|v| v.0), Some("settings"));
+/// assert_eq!(dialogs.handle(&esc, Dialog), Outcome::Changed);
+/// assert_eq!(dialogs.handle(&x, Dialog), Outcome::Continue);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DialogStack<T> {
+    dialogs: Vec<T>,
+}
+
+impl<T> Default for DialogStack<T> {
+    fn default() -> Self {
+        Self {
+            dialogs: Vec::new(),
+        }
+    }
+}
+
+impl<T> DialogStack<T> {
+    /// Empty stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open a dialog on top.
+    pub fn push(&mut self, dialog: T) {
+        self.dialogs.push(dialog);
+    }
+
+    /// Close the top dialog.
+    pub fn pop(&mut self) -> Option<T> {
+        self.dialogs.pop()
+    }
+
+    /// The top dialog.
+    pub fn top(&self) -> Option<&T> {
+        self.dialogs.last()
+    }
+
+    /// The top dialog.
+    pub fn top_mut(&mut self) -> Option<&mut T> {
+        self.dialogs.last_mut()
+    }
+
+    /// Close all dialogs.
+    pub fn clear(&mut self) {
+        self.dialogs.clear();
+    }
+
+    /// All dialogs bottom-up, in render order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.dialogs.iter()
+    }
+
+    /// All dialogs bottom-up, in render order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.dialogs.iter_mut()
+    }
+
+    /// Number of open dialogs.
+    pub fn len(&self) -> usize {
+        self.dialogs.len()
+    }
+
+    /// No open dialogs.
+    pub fn is_empty(&self) -> bool {
+        self.dialogs.is_empty()
+    }
+}
+
+impl<T, R> HandleEvent<Event, Dialog, R> for DialogStack<T>
+where
+    T: HandleEvent<Event, Dialog, R>,
+    R: ConsumedEvent + Default + From<Outcome>,
+{
+    fn handle(&mut self, event: &Event, qualifier: Dialog) -> R {
+        let Some(top) = self.dialogs.last_mut() else {
+            return R::default();
+        };
+        let r = top.handle(event, qualifier);
+        if r.is_consumed() {
+            return r;
+        }
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                modifiers: KeyModifiers::NONE,
+                kind: KeyEventKind::Press,
+                ..
+            }) => {
+                self.dialogs.pop();
+                Outcome::Changed.into()
+            }
+            _ => Outcome::Unchanged.into(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::crossterm::event::{Event, KeyEventKind, MouseEvent};
+use crate::{ConsumedEvent, HandleEvent, Outcome};
+use ratatui::layout::{Position, Rect};
+
+/// Event-handling for a widget that can be disabled.
+///
+/// While enabled, all events go to the inner event-handler, with the
+/// same qualifier.
+///
+/// While disabled the inner event-handler is never called, so nothing
+/// can change. Instead, all events that would usually operate the
+/// widget are consumed and return `Outcome::Unchanged`, so they don't
+/// fall through to the widgets behind it. These are
+///
+/// * key presses, repeats and pastes, if the widget is focused.
+/// * mouse events inside the area.
+///
+/// Anything else returns `R::default()`, see
+/// [not consumed](ConsumedEvent#not-consumed).
+///
+/// Rendering the widget greyed out is up to the widget itself.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::Disabled;
+/// use rat_event::{ct_event, HandleEvent, Outcome, Regular};
+/// use ratatui::layout::Rect;
+///
+/// struct Button {
+///     area: Rect,
+///     focused: bool,
+///     disabled: bool,
+///     pressed: bool,
+/// }
+///
+/// impl HandleEvent<Event, Regular, Outcome> for Button {
+///     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+///         match event {
+///             ct_event!(keycode press Enter) if self.focused => {
+///                 self.pressed = true;
+///                 Outcome::Changed
+///             }
+///             _ => Outcome::Continue,
+///         }
+///     }
+/// }
+///
+/// let mut button = Button {
+///     area: Rect::new(0, 0, 10, 1),
+///     focused: true,
+///     disabled: true,
+///     pressed: false,
+/// };
+///
+/// let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+/// let (area, focused, disabled) = (button.area, button.focused, button.disabled);
+/// let r: Outcome = Disabled::new(&mut button, disabled)
+///     .focused(focused)
+///     .area(area)
+///     .handle(&enter, Regular);
+/// assert_eq!(r, Outcome::Unchanged);
+/// assert!(!button.pressed);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Disabled<H> {
+    /// Widget is disabled.
+    pub disabled: bool,
+    /// Widget has the focus.
+    pub focused: bool,
+    /// Area of the widget.
+    pub area: Rect,
+    /// Event-handler of the widget.
+    pub inner: H,
+}
+
+impl<H> Disabled<H> {
+    /// New adapter. Not focused, and with an empty area.
+    pub fn new(inner: H, disabled: bool) -> Self {
+        Self {
+            disabled,
+            focused: false,
+            area: Rect::default(),
+            inner,
+        }
+    }
+
+    /// Widget has the focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Area of the widget.
+    pub fn area(mut self, area: Rect) -> Self {
+        self.area = area;
+        self
+    }
+
+    /// Widget is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Would the widget usually use this event?
+    ///
+    /// These are the events that are consumed while disabled.
+    pub fn operates(&self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.focused && key.kind != KeyEventKind::Release,
+            Event::Paste(_) => self.focused,
+            Event::Mouse(MouseEvent { column, row, .. }) => {
+                self.area.contains(Position::new(*column, *row))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<H, Q, R> HandleEvent<Event, Q, R> for Disabled<H>
+where
+    H: HandleEvent<Event, Q, R>,
+    R: ConsumedEvent + Default + From<Outcome>,
+{
+    fn handle(&mut self, event: &Event, qualifier: Q) -> R {
+        if !self.disabled {
+            self.inner.handle(event, qualifier)
+        } else if self.operates(event) {
+            Outcome::Unchanged.into()
+        } else {
+            R::default()
+        }
+    }
+}
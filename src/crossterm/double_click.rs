@@ -0,0 +1,118 @@
+use crate::clock::{Clock, SharedClock};
+use crate::crossterm::event::{Event, KeyModifiers};
+use crate::util::MouseFlags;
+use crate::{impl_outcome, DoubleClick, HandleEvent};
+use ratatui::layout::Rect;
+
+/// Result of [DoubleClickState].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DoubleClickOutcome {
+    /// Not a double-click.
+    #[default]
+    Continue,
+    /// Event has been used, but nothing changed.
+    Unchanged,
+    /// Event has been used and something changed.
+    Changed,
+    /// Double-click at this position.
+    ClickedAt(u16, u16),
+}
+
+impl_outcome!(DoubleClickOutcome {
+    Continue => Continue,
+    Unchanged => Unchanged,
+    Changed => Changed,
+    ClickedAt(_, _) => Changed,
+});
+
+/// Double-click recognition for a widget.
+///
+/// Add this to the widget state and set the area during rendering.
+/// The [DoubleClick] event-handler must be called *before* the regular
+/// event-handler. It doesn't consume the first click, which leaves it
+/// for the regular event-handler.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind, KeyModifiers};
+/// use rat_event::crossterm::{DoubleClickOutcome, DoubleClickState};
+/// use rat_event::{ct_event, DoubleClick, HandleEvent, Outcome, Regular};
+/// use ratatui::layout::Rect;
+///
+/// #[derive(Default)]
+/// struct ListState {
+///     selected: Option<u16>,
+///     opened: Option<u16>,
+///     double_click: DoubleClickState,
+/// }
+///
+/// impl HandleEvent<Event, Regular, Outcome> for ListState {
+///     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+///         // double-click first, then the regular handling.
+///         if let DoubleClickOutcome::ClickedAt(_, row) = self.double_click.handle(event, DoubleClick) {
+///             self.opened = Some(row);
+///             return Outcome::Changed;
+///         }
+///         match event {
+///             ct_event!(mouse down Left for _x, row) => {
+///                 self.selected = Some(*row);
+///                 Outcome::Changed
+///             }
+///             _ => Outcome::Continue,
+///         }
+///     }
+/// }
+///
+/// let mut state = ListState::default();
+/// state.double_click.area = Rect::new(0, 0, 10, 10);
+///
+/// let mouse = |kind| Event::Mouse(MouseEvent {
+///     kind,
+///     column: 1,
+///     row: 2,
+///     modifiers: KeyModifiers::NONE,
+/// });
+/// let down = mouse(MouseEventKind::Down(MouseButton::Left));
+/// let up = mouse(MouseEventKind::Up(MouseButton::Left));
+///
+/// assert_eq!(state.handle(&down, Regular), Outcome::Changed);
+/// assert_eq!(state.handle(&up, Regular), Outcome::Continue);
+/// assert_eq!(state.handle(&down, Regular), Outcome::Changed);
+/// assert_eq!(state.handle(&up, Regular), Outcome::Changed);
+/// assert_eq!(state.selected, Some(2));
+/// assert_eq!(state.opened, Some(2));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DoubleClickState {
+    /// Area for double-clicks. Set during rendering.
+    pub area: Rect,
+    /// Mouse flags.
+    pub mouse: MouseFlags,
+    /// Clock for the double-click interval.
+    /// Defaults to the system clock.
+    pub clock: SharedClock,
+}
+
+impl DoubleClickState {
+    /// New state with an empty area.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl HandleEvent<Event, DoubleClick, DoubleClickOutcome> for DoubleClickState {
+    fn handle(&mut self, event: &Event, _qualifier: DoubleClick) -> DoubleClickOutcome {
+        match event {
+            Event::Mouse(m)
+                if self.mouse.doubleclick2_at(
+                    self.area,
+                    m,
+                    KeyModifiers::NONE,
+                    self.clock.system_time(),
+                ) =>
+            {
+                DoubleClickOutcome::ClickedAt(m.column, m.row)
+            }
+            _ => DoubleClickOutcome::Continue,
+        }
+    }
+}
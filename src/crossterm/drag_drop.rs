@@ -0,0 +1,218 @@
+use crate::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use crate::{DragDrop, HandleEvent, Outcome};
+use std::cell::{Ref, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+#[derive(Debug)]
+enum DragPhase<T> {
+    Idle,
+    Dragging { payload: T, source: usize },
+    Dropped { source: usize, target: usize },
+    Cancelled { source: usize },
+}
+
+/// Shared state for drag-and-drop between widgets.
+///
+/// This is cheap to clone, all clones share the same state. Give a
+/// clone to every widget that takes part.
+///
+/// * The source widget calls [DragDropState::start] when its drag
+///   starts, e.g. when [MouseFlags::drag](crate::util::MouseFlags::drag)
+///   reports the first drag event.
+/// * While dragging, the container calls the [DragDrop] event-handlers
+///   of all targets. They can look at the payload with
+///   [DragDropState::dragging] to show a drop indicator.
+/// * On the mouse-up the target that contains the position calls
+///   [DragDropState::accept] and takes the payload.
+/// * Last, the container calls the DragDrop event-handler of the
+///   DragDropState. A mouse-up that no target accepted cancels the
+///   drag, as does Esc or the loss of the terminal focus.
+///
+/// Afterwards [DragDropState::dropped] and [DragDropState::cancelled]
+/// tell the outcome, until the next mouse-down or the next drag.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+/// use rat_event::crossterm::DragDropState;
+/// use rat_event::{DragDrop, HandleEvent, Outcome};
+///
+/// let up = Event::Mouse(MouseEvent {
+///     kind: MouseEventKind::Up(MouseButton::Left),
+///     column: 0,
+///     row: 0,
+///     modifiers: KeyModifiers::NONE,
+/// });
+///
+/// let drag = DragDropState::new();
+/// let target = drag.clone();
+///
+/// drag.start("item", 0);
+/// assert_eq!(target.dragging().as_deref(), Some(&"item"));
+/// assert_eq!(target.accept(1), Some("item"));
+/// assert_eq!(drag.clone().handle(&up, DragDrop), Outcome::Continue);
+/// assert_eq!(drag.dropped(), Some((0, 1)));
+///
+/// // nobody accepts.
+/// drag.start("other", 0);
+/// assert_eq!(drag.clone().handle(&up, DragDrop), Outcome::Changed);
+/// assert!(drag.cancelled());
+/// ```
+pub struct DragDropState<T> {
+    phase: Rc<RefCell<DragPhase<T>>>,
+}
+
+impl<T> Clone for DragDropState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            phase: Rc::clone(&self.phase),
+        }
+    }
+}
+
+impl<T> Default for DragDropState<T> {
+    fn default() -> Self {
+        Self {
+            phase: Rc::new(RefCell::new(DragPhase::Idle)),
+        }
+    }
+}
+
+impl<T: Debug> Debug for DragDropState<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragDropState")
+            .field("phase", &self.phase.borrow())
+            .finish()
+    }
+}
+
+impl<T> DragDropState<T> {
+    /// New shared state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start dragging the payload. The source is an id of the
+    /// source widget.
+    ///
+    /// Replaces any drag in progress.
+    pub fn start(&self, payload: T, source: usize) {
+        *self.phase.borrow_mut() = DragPhase::Dragging { payload, source };
+    }
+
+    /// Is there a drag in progress?
+    pub fn is_dragging(&self) -> bool {
+        matches!(*self.phase.borrow(), DragPhase::Dragging { .. })
+    }
+
+    /// The payload of the drag in progress.
+    pub fn dragging(&self) -> Option<Ref<'_, T>> {
+        Ref::filter_map(self.phase.borrow(), |v| match v {
+            DragPhase::Dragging { payload, .. } => Some(payload),
+            _ => None,
+        })
+        .ok()
+    }
+
+    /// Source of the current or the last drag.
+    pub fn source(&self) -> Option<usize> {
+        match *self.phase.borrow() {
+            DragPhase::Idle => None,
+            DragPhase::Dragging { source, .. }
+            | DragPhase::Dropped { source, .. }
+            | DragPhase::Cancelled { source } => Some(source),
+        }
+    }
+
+    /// Take the payload. The target is an id of the target widget.
+    ///
+    /// Returns None if there is no drag in progress.
+    pub fn accept(&self, target: usize) -> Option<T> {
+        let mut phase = self.phase.borrow_mut();
+        match std::mem::replace(&mut *phase, DragPhase::Idle) {
+            DragPhase::Dragging { payload, source } => {
+                *phase = DragPhase::Dropped { source, target };
+                Some(payload)
+            }
+            v => {
+                *phase = v;
+                None
+            }
+        }
+    }
+
+    /// Cancel the drag in progress. Returns the payload.
+    pub fn cancel(&self) -> Option<T> {
+        let mut phase = self.phase.borrow_mut();
+        match std::mem::replace(&mut *phase, DragPhase::Idle) {
+            DragPhase::Dragging { payload, source } => {
+                *phase = DragPhase::Cancelled { source };
+                Some(payload)
+            }
+            v => {
+                *phase = v;
+                None
+            }
+        }
+    }
+
+    /// Source and target of the last drag, if it has been accepted.
+    pub fn dropped(&self) -> Option<(usize, usize)> {
+        match *self.phase.borrow() {
+            DragPhase::Dropped { source, target } => Some((source, target)),
+            _ => None,
+        }
+    }
+
+    /// The last drag has been cancelled.
+    pub fn cancelled(&self) -> bool {
+        matches!(*self.phase.borrow(), DragPhase::Cancelled { .. })
+    }
+
+    /// Forget everything.
+    pub fn clear(&self) {
+        *self.phase.borrow_mut() = DragPhase::Idle;
+    }
+}
+
+/// Finishes the drag.
+///
+/// Cancels the drag for a mouse-up that no target accepted, for Esc
+/// and for the loss of the terminal focus, and returns Changed.
+/// A mouse-down forgets the outcome of the last drag.
+/// Everything else returns Continue.
+impl<T> HandleEvent<Event, DragDrop, Outcome> for DragDropState<T> {
+    fn handle(&mut self, event: &Event, _qualifier: DragDrop) -> Outcome {
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            })
+            | Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                modifiers: KeyModifiers::NONE,
+                kind: KeyEventKind::Press,
+                ..
+            })
+            | Event::FocusLost => {
+                if self.cancel().is_some() {
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(_),
+                ..
+            }) => {
+                if !self.is_dragging() {
+                    self.clear();
+                }
+                Outcome::Continue
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crate::{HandleEvent, OutcomeOf, Tick, TimeOut};
+use std::time::{Duration, Instant};
+
+/// Result of [EscDebouncer].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscResolved {
+    /// Nothing to do with Esc. Handle the event as usual.
+    Continue,
+    /// An Esc that might be the start of an Alt-sequence.
+    /// Don't handle it now, call [EscDebouncer::resolve] after
+    /// [EscDebouncer::deadline].
+    Pending,
+    /// The pending Esc was a real Esc.
+    ///
+    /// If this is the result of [EscDebouncer::feed], the current
+    /// event has not been looked at. Handle the Esc and feed the
+    /// event again.
+    Escape,
+    /// The pending Esc and this key are Alt+key.
+    AltKey(KeyEvent),
+}
+
+/// Tells a lone Esc from the start of an Alt-sequence.
+///
+/// Without the kitty keyboard protocol, Alt+x is sent as Esc
+/// followed by x. If the two bytes arrive separately, this shows
+/// up as an Esc and an x. The debouncer holds back an Esc until
+/// the next key arrives or the window has passed.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::{EscDebouncer, EscResolved};
+/// use std::time::{Duration, Instant};
+///
+/// let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+/// let x = Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+///
+/// let mut debounce = EscDebouncer::new();
+/// let now = Instant::now();
+///
+/// assert_eq!(debounce.feed(&esc, now), EscResolved::Pending);
+/// // wait until the deadline for the next event ...
+/// assert!(debounce.deadline().is_some());
+/// // ... x arrives in time
+/// assert_eq!(
+///     debounce.feed(&x, now + Duration::from_millis(5)),
+///     EscResolved::AltKey(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT))
+/// );
+///
+/// assert_eq!(debounce.feed(&esc, now), EscResolved::Pending);
+/// // ... the poll timed out
+/// assert_eq!(
+///     debounce.resolve(now + Duration::from_millis(100)),
+///     EscResolved::Escape
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct EscDebouncer {
+    window: Duration,
+    pending: Option<Instant>,
+}
+
+impl Default for EscDebouncer {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_millis(50),
+            pending: None,
+        }
+    }
+}
+
+impl EscDebouncer {
+    /// New debouncer with a window of 50ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the window for the follow-up key.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Window for the follow-up key.
+    pub fn get_window(&self) -> Duration {
+        self.window
+    }
+
+    /// When the pending Esc is resolved. Use this as poll timeout.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.pending.map(|v| v + self.window)
+    }
+
+    /// Is there a pending Esc?
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Feed the next event.
+    pub fn feed(&mut self, event: &Event, now: Instant) -> EscResolved {
+        let key = match event {
+            Event::Key(key) if key.kind != KeyEventKind::Release => Some(key),
+            Event::Key(_) => return EscResolved::Continue,
+            _ => None,
+        };
+
+        if let Some(pending) = self.pending {
+            self.pending = None;
+            return match key {
+                Some(key) if now.saturating_duration_since(pending) < self.window => {
+                    EscResolved::AltKey(KeyEvent {
+                        modifiers: key.modifiers | KeyModifiers::ALT,
+                        ..*key
+                    })
+                }
+                _ => EscResolved::Escape,
+            };
+        }
+
+        match key {
+            Some(key) if key.code == KeyCode::Esc && key.modifiers == KeyModifiers::NONE => {
+                self.pending = Some(now);
+                EscResolved::Pending
+            }
+            _ => EscResolved::Continue,
+        }
+    }
+
+    /// Resolve a pending Esc after the deadline.
+    ///
+    /// Returns Escape if the deadline has passed, Pending if
+    /// not, and Continue if there is no pending Esc.
+    pub fn resolve(&mut self, now: Instant) -> EscResolved {
+        match self.deadline() {
+            Some(deadline) if now >= deadline => {
+                self.pending = None;
+                EscResolved::Escape
+            }
+            Some(_) => EscResolved::Pending,
+            None => EscResolved::Continue,
+        }
+    }
+
+    /// Forget a pending Esc.
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// Resolves a pending Esc. Returns `Value(Escape)` if the
+/// deadline has passed, and Continue otherwise.
+impl HandleEvent<TimeOut, Tick, OutcomeOf<EscResolved>> for EscDebouncer {
+    fn handle(&mut self, event: &TimeOut, _qualifier: Tick) -> OutcomeOf<EscResolved> {
+        match self.resolve(event.now) {
+            EscResolved::Continue | EscResolved::Pending => OutcomeOf::Continue,
+            r => OutcomeOf::Value(r),
+        }
+    }
+}
+
+/// Detects Esc pressed twice in quick succession.
+///
+/// Any other key in between starts over. Releases and repeats
+/// are ignored.
+///
+/// Together with an [EscDebouncer] use [EscEsc::check_resolved]
+/// for its results, so the Esc prefix of an Alt-sequence doesn't
+/// count.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::EscEsc;
+/// use std::time::{Duration, Instant};
+///
+/// let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+///
+/// let mut quit = EscEsc::new();
+/// let now = Instant::now();
+/// assert!(!quit.check(&esc, now));
+/// assert!(quit.check(&esc, now + Duration::from_millis(200)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EscEsc {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl Default for EscEsc {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            last: None,
+        }
+    }
+}
+
+impl EscEsc {
+    /// New state with an interval of 500ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum interval between the two Esc.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Maximum interval between the two Esc.
+    pub fn get_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns true for the second of two Esc presses.
+    pub fn check(&mut self, event: &Event, now: Instant) -> bool {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                if key.code == KeyCode::Esc && key.modifiers == KeyModifiers::NONE {
+                    self.esc(now)
+                } else {
+                    self.last = None;
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true for the second of two Esc resolved by
+    /// an [EscDebouncer].
+    ///
+    /// Escape counts as Esc and AltKey as any other key.
+    /// Pending is ignored. For Continue pass the event
+    /// to [EscEsc::check].
+    pub fn check_resolved(&mut self, resolved: &EscResolved, now: Instant) -> bool {
+        match resolved {
+            EscResolved::Escape => self.esc(now),
+            EscResolved::AltKey(_) => {
+                self.last = None;
+                false
+            }
+            EscResolved::Continue | EscResolved::Pending => false,
+        }
+    }
+
+    /// Forget the first Esc.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    fn esc(&mut self, now: Instant) -> bool {
+        match self.last {
+            Some(last) if now.saturating_duration_since(last) <= self.interval => {
+                self.last = None;
+                true
+            }
+            _ => {
+                self.last = Some(now);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::crossterm::event::{Event, KeyEvent, KeyEventKind};
+use crate::crossterm::{BindError, KeyCombo};
+use crate::{HandleEvent, HotKey, OutcomeOf};
+use std::collections::{HashMap, HashSet};
+
+/// Application-wide hotkeys.
+///
+/// Components register their hotkeys with an action-id, and the
+/// container calls the registry with the [HotKey] qualifier before
+/// any other event-handling. A hotkey returns `OutcomeOf::Value`
+/// with the action-id.
+///
+/// The focused widget can claim some of the hotkeys for itself.
+/// A claimed hotkey is not handled by the registry and goes on
+/// to the regular event-handling. Set the claims whenever the
+/// focus changes.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::{HotKeyRegistry, KeyCombo};
+/// use rat_event::{HandleEvent, HotKey, OutcomeOf};
+///
+/// let ctrl_q = KeyCombo::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+///
+/// let mut hotkeys = HotKeyRegistry::new();
+/// hotkeys.register(ctrl_q, "quit")?;
+/// assert!(hotkeys.register(ctrl_q, "query").is_err());
+///
+/// let event = Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+/// assert_eq!(hotkeys.handle(&event, HotKey), OutcomeOf::Value("quit"));
+///
+/// // a text field has the focus and uses ctrl+q itself.
+/// hotkeys.set_claims([ctrl_q]);
+/// assert_eq!(hotkeys.handle(&event, HotKey), OutcomeOf::Continue);
+/// # Ok::<(), rat_event::crossterm::BindError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HotKeyRegistry<A> {
+    keys: HashMap<KeyCombo, A>,
+    claims: HashSet<KeyCombo>,
+}
+
+impl<A> Default for HotKeyRegistry<A> {
+    fn default() -> Self {
+        Self {
+            keys: HashMap::new(),
+            claims: HashSet::new(),
+        }
+    }
+}
+
+impl<A> HotKeyRegistry<A> {
+    /// Empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hotkey.
+    ///
+    /// Fails with [BindError::Taken] if the key is already
+    /// registered, the existing hotkey stays as it is.
+    pub fn register(&mut self, key: impl Into<KeyCombo>, action: A) -> Result<(), BindError> {
+        let key = key.into();
+        if self.keys.contains_key(&key) {
+            return Err(BindError::Taken(key));
+        }
+        self.keys.insert(key, action);
+        Ok(())
+    }
+
+    /// Remove a hotkey. Returns the old action.
+    pub fn unregister(&mut self, key: impl Into<KeyCombo>) -> Option<A> {
+        self.keys.remove(&key.into())
+    }
+
+    /// Action registered for a key, claimed or not.
+    pub fn get(&self, key: &KeyCombo) -> Option<&A> {
+        self.keys.get(key)
+    }
+
+    /// All registered hotkeys in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&KeyCombo, &A)> {
+        self.keys.iter()
+    }
+
+    /// Replace the claims with the keys of the focused widget.
+    pub fn set_claims(&mut self, keys: impl IntoIterator<Item = KeyCombo>) {
+        self.claims.clear();
+        self.claims.extend(keys);
+    }
+
+    /// Claim one more key.
+    pub fn claim(&mut self, key: impl Into<KeyCombo>) {
+        self.claims.insert(key.into());
+    }
+
+    /// Remove all claims.
+    pub fn clear_claims(&mut self) {
+        self.claims.clear();
+    }
+
+    /// Is the key claimed?
+    pub fn is_claimed(&self, key: &KeyCombo) -> bool {
+        self.claims.contains(key)
+    }
+
+    /// Action for a key press.
+    ///
+    /// Releases and claimed keys never map to an action.
+    pub fn lookup(&self, key: &KeyEvent) -> Option<&A> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let key = KeyCombo::from(key);
+        if self.claims.contains(&key) {
+            return None;
+        }
+        self.keys.get(&key)
+    }
+
+    /// Number of hotkeys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// No hotkeys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<A: Clone> HandleEvent<Event, HotKey, OutcomeOf<A>> for HotKeyRegistry<A> {
+    fn handle(&mut self, event: &Event, _qualifier: HotKey) -> OutcomeOf<A> {
+        match event {
+            Event::Key(key) => match self.lookup(key) {
+                Some(action) => OutcomeOf::Value(action.clone()),
+                None => OutcomeOf::Continue,
+            },
+            _ => OutcomeOf::Continue,
+        }
+    }
+}
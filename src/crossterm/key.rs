@@ -0,0 +1,377 @@
+use crate::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::crossterm::normalize_key;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A key with its modifiers, usable as key in a HashMap or BTreeMap.
+///
+/// KeyEvent has the kind and state too, so two presses of the
+/// same key don't necessarily compare equal. KeyCombo drops
+/// those and stores the key in the form of [normalize_key].
+///
+/// ```rust
+/// use rat_event::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+/// use rat_event::crossterm::KeyCombo;
+/// use std::collections::HashMap;
+///
+/// let mut keymap = HashMap::new();
+/// keymap.insert("ctrl+shift+s".parse::<KeyCombo>()?, "save as");
+///
+/// let key = KeyEvent::new_with_kind(
+///     KeyCode::Char('s'),
+///     KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+///     KeyEventKind::Repeat,
+/// );
+/// assert_eq!(keymap.get(&KeyCombo::from(&key)), Some(&"save as"));
+/// # Ok::<(), rat_event::crossterm::KeyParseError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyCombo {
+    /// New combo. The key is normalized.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self::from(KeyEvent::new(code, modifiers))
+    }
+
+    /// Key code.
+    pub fn code(&self) -> KeyCode {
+        self.code
+    }
+
+    /// Modifiers.
+    pub fn modifiers(&self) -> KeyModifiers {
+        self.modifiers
+    }
+
+    /// Formats the combo. See [fmt_key_with_style].
+    pub fn fmt_with_style(&self, style: KeyFmtStyle) -> String {
+        fmt_key_with_style(&KeyEvent::from(*self), style)
+    }
+}
+
+impl From<KeyEvent> for KeyCombo {
+    fn from(key: KeyEvent) -> Self {
+        let key = normalize_key(key);
+        Self {
+            code: key.code,
+            modifiers: key.modifiers,
+        }
+    }
+}
+
+impl From<&KeyEvent> for KeyCombo {
+    fn from(key: &KeyEvent) -> Self {
+        Self::from(*key)
+    }
+}
+
+impl From<KeyCombo> for KeyEvent {
+    fn from(combo: KeyCombo) -> Self {
+        KeyEvent::new(combo.code, combo.modifiers)
+    }
+}
+
+impl PartialOrd for KeyCombo {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for KeyCombo {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // KeyCode has no Ord, but the derived PartialOrd is total.
+        self.code
+            .partial_cmp(&other.code)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| self.modifiers.bits().cmp(&other.modifiers.bits()))
+    }
+}
+
+impl Display for KeyCombo {
+    /// Formats with [KeyFmtStyle::Plain].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&fmt_key(&KeyEvent::from(*self)))
+    }
+}
+
+impl FromStr for KeyCombo {
+    type Err = KeyParseError;
+
+    /// Parses with [parse_key] and normalizes the result.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_key(s).map(KeyCombo::from)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyCombo {
+    /// Serializes the [Display] form, "Ctrl+Shift+F5".
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeyCombo {
+    /// Deserializes from a string, see [parse_key].
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ComboVisitor;
+
+        impl serde::de::Visitor<'_> for ComboVisitor {
+            type Value = KeyCombo;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a key like \"ctrl+shift+f5\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<KeyCombo, E> {
+                v.parse()
+                    .map_err(|e| E::custom(format!("key binding '{}': {}", v, e)))
+            }
+        }
+
+        deserializer.deserialize_str(ComboVisitor)
+    }
+}
+
+/// Conventions for [fmt_key_with_style].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyFmtStyle {
+    /// "Ctrl+Shift+S", "Alt+Enter", "F5"
+    #[default]
+    Plain,
+    /// Like Plain, but uses symbols for the cursor keys and
+    /// some others. "Alt+↵", "Ctrl+←"
+    Symbols,
+    /// Emacs like. "C-s", "M-enter", "C-S-f5"
+    Emacs,
+    /// Terse, as used in some status-lines. "^S", "M-x", "^F5"
+    Terse,
+}
+
+/// Formats the key with [KeyFmtStyle::Plain].
+pub fn fmt_key(key: &KeyEvent) -> String {
+    fmt_key_with_style(key, KeyFmtStyle::Plain)
+}
+
+/// Formats the key for display in menus, help texts etc.
+///
+/// The modifiers are always in the order Ctrl, Alt, Shift, Super,
+/// Hyper, Meta. The kind and state of the KeyEvent are ignored.
+pub fn fmt_key_with_style(key: &KeyEvent, style: KeyFmtStyle) -> String {
+    let mut buf = String::new();
+
+    for (modifier, plain, emacs, terse) in [
+        (KeyModifiers::CONTROL, "Ctrl+", "C-", "^"),
+        (KeyModifiers::ALT, "Alt+", "M-", "M-"),
+        (KeyModifiers::SHIFT, "Shift+", "S-", "S-"),
+        (KeyModifiers::SUPER, "Super+", "Super-", "Super-"),
+        (KeyModifiers::HYPER, "Hyper+", "Hyper-", "Hyper-"),
+        (KeyModifiers::META, "Meta+", "Meta-", "Meta-"),
+    ] {
+        if key.modifiers.contains(modifier) {
+            buf.push_str(match style {
+                KeyFmtStyle::Plain | KeyFmtStyle::Symbols => plain,
+                KeyFmtStyle::Emacs => emacs,
+                KeyFmtStyle::Terse => terse,
+            });
+        }
+    }
+
+    match key.code {
+        KeyCode::Char(' ') => buf.push_str(match style {
+            KeyFmtStyle::Emacs => "space",
+            KeyFmtStyle::Symbols => "␣",
+            _ => "Space",
+        }),
+        KeyCode::Char(c) => {
+            if style == KeyFmtStyle::Terse && key.modifiers.contains(KeyModifiers::CONTROL) {
+                buf.extend(c.to_uppercase());
+            } else {
+                buf.push(c);
+            }
+        }
+        KeyCode::F(n) => {
+            if style == KeyFmtStyle::Emacs {
+                buf.push('f');
+            } else {
+                buf.push('F');
+            }
+            buf.push_str(&n.to_string());
+        }
+        code => {
+            let (name, symbol) = key_name(code);
+            match style {
+                KeyFmtStyle::Plain | KeyFmtStyle::Terse => buf.push_str(&name),
+                KeyFmtStyle::Symbols => buf.push_str(symbol.unwrap_or(&name)),
+                KeyFmtStyle::Emacs => buf.push_str(&name.to_lowercase()),
+            }
+        }
+    }
+
+    buf
+}
+
+/// Name and symbol for all the non-character keys.
+const NAMED_KEYS: &[(KeyCode, &str, Option<&str>)] = &[
+    (KeyCode::Backspace, "Backspace", Some("⌫")),
+    (KeyCode::Enter, "Enter", Some("↵")),
+    (KeyCode::Left, "Left", Some("←")),
+    (KeyCode::Right, "Right", Some("→")),
+    (KeyCode::Up, "Up", Some("↑")),
+    (KeyCode::Down, "Down", Some("↓")),
+    (KeyCode::Home, "Home", Some("⇱")),
+    (KeyCode::End, "End", Some("⇲")),
+    (KeyCode::PageUp, "PageUp", Some("⇞")),
+    (KeyCode::PageDown, "PageDown", Some("⇟")),
+    (KeyCode::Tab, "Tab", Some("⇥")),
+    (KeyCode::BackTab, "BackTab", Some("⇤")),
+    (KeyCode::Delete, "Delete", Some("⌦")),
+    (KeyCode::Insert, "Insert", None),
+    (KeyCode::Null, "Null", None),
+    (KeyCode::Esc, "Esc", None),
+    (KeyCode::CapsLock, "CapsLock", Some("⇪")),
+    (KeyCode::ScrollLock, "ScrollLock", None),
+    (KeyCode::NumLock, "NumLock", None),
+    (KeyCode::PrintScreen, "PrintScreen", None),
+    (KeyCode::Pause, "Pause", None),
+    (KeyCode::Menu, "Menu", None),
+    (KeyCode::KeypadBegin, "KeypadBegin", None),
+];
+
+/// Other names accepted by [parse_key].
+const KEY_ALIASES: &[(&str, KeyCode)] = &[
+    ("return", KeyCode::Enter),
+    ("ret", KeyCode::Enter),
+    ("escape", KeyCode::Esc),
+    ("pgup", KeyCode::PageUp),
+    ("pgdn", KeyCode::PageDown),
+    ("pgdown", KeyCode::PageDown),
+    ("del", KeyCode::Delete),
+    ("ins", KeyCode::Insert),
+    ("space", KeyCode::Char(' ')),
+    ("spc", KeyCode::Char(' ')),
+];
+
+fn key_name(code: KeyCode) -> (String, Option<&'static str>) {
+    match code {
+        KeyCode::Media(media) => (format!("{:?}", media), None),
+        KeyCode::Modifier(modifier) => (format!("{:?}", modifier), None),
+        code => NAMED_KEYS
+            .iter()
+            .find(|(c, _, _)| *c == code)
+            .map(|(_, name, symbol)| (name.to_string(), *symbol))
+            .expect("named key"),
+    }
+}
+
+/// Error from [parse_key].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyParseError {
+    /// Nothing to parse.
+    Empty,
+    /// Modifiers without a key, e.g. "ctrl+".
+    MissingKey(String),
+    /// Unknown modifier.
+    UnknownModifier(String),
+    /// Unknown key name.
+    UnknownKey(String),
+}
+
+impl Display for KeyParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyParseError::Empty => write!(f, "empty key"),
+            KeyParseError::MissingKey(s) => write!(f, "no key after '{}'", s),
+            KeyParseError::UnknownModifier(s) => write!(f, "unknown modifier '{}'", s),
+            KeyParseError::UnknownKey(s) => write!(f, "unknown key '{}'", s),
+        }
+    }
+}
+
+impl Error for KeyParseError {}
+
+/// Parses a key as it would be written in a config file.
+///
+/// * Modifiers are separated with `+` or `-`: "ctrl+shift+f5", "C-x".
+///   They are not case-sensitive. Known modifiers are
+///   ctrl/control/c, alt/meta/m, shift/s, super and hyper.
+/// * Named keys are not case-sensitive: enter, esc, tab, backtab,
+///   space, home, end, pgup, pgdn, up, down, left, right, f1..f24,
+///   del, ins and all the names [fmt_key] uses.
+/// * Anything else must be a single character, which is taken as is.
+///   "ctrl++" and "C--" are the characters '+' and '-'.
+///
+/// This is the inverse of [fmt_key] with [KeyFmtStyle::Plain] or
+/// [KeyFmtStyle::Emacs], except for media keys, modifier keys and
+/// the META modifier. Meta is read as Alt, as most terminals do.
+///
+/// For keymaps parse a [KeyCombo] instead, which is normalized.
+pub fn parse_key(s: &str) -> Result<KeyEvent, KeyParseError> {
+    let s = s.trim();
+    let is_sep = |c: char| c == '+' || c == '-';
+
+    let mut chars = s.char_indices().rev();
+    let (key, modifiers) = match (chars.next(), chars.next()) {
+        (None, _) => return Err(KeyParseError::Empty),
+        (Some(_), None) => (s, ""),
+        (Some((i, c)), Some((j, d))) if is_sep(c) => {
+            if is_sep(d) {
+                // separator + the key itself.
+                (&s[i..], &s[..j])
+            } else {
+                return Err(KeyParseError::MissingKey(s.into()));
+            }
+        }
+        (Some(_), Some(_)) => match s.rfind(is_sep) {
+            Some(i) => (&s[i + 1..], &s[..i]),
+            None => (s, ""),
+        },
+    };
+
+    let mut mods = KeyModifiers::NONE;
+    if !modifiers.is_empty() {
+        for m in modifiers.split(is_sep) {
+            mods |= match m.to_lowercase().as_str() {
+                "ctrl" | "control" | "c" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "m" => KeyModifiers::ALT,
+                "shift" | "s" => KeyModifiers::SHIFT,
+                "super" => KeyModifiers::SUPER,
+                "hyper" => KeyModifiers::HYPER,
+                _ => return Err(KeyParseError::UnknownModifier(m.into())),
+            };
+        }
+    }
+
+    let code = parse_code(key).ok_or_else(|| KeyParseError::UnknownKey(key.into()))?;
+    Ok(KeyEvent::new(code, mods))
+}
+
+fn parse_code(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let lower = key.to_lowercase();
+    if let Some(n) = lower.strip_prefix('f') {
+        if let Ok(n) = n.parse::<u8>() {
+            if (1..=24).contains(&n) {
+                return Some(KeyCode::F(n));
+            }
+        }
+    }
+    if let Some((_, code)) = KEY_ALIASES.iter().find(|(name, _)| *name == lower) {
+        return Some(*code);
+    }
+    NAMED_KEYS
+        .iter()
+        .find(|(_, name, _)| name.to_lowercase() == lower)
+        .map(|(code, _, _)| *code)
+}
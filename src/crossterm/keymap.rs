@@ -0,0 +1,756 @@
+use crate::crossterm::event::{Event, KeyEvent, KeyEventKind};
+use crate::crossterm::{KeyCombo, KeyParseError, KeySequence};
+use crate::{ConsumedEvent, HandleEvent, Regular};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Maps keys to user-defined actions.
+///
+/// The keys are stored as [KeyCombo], so Shift+a and 'A' are the
+/// same binding, and the kind and state of a KeyEvent don't matter.
+///
+/// Use [Keyed] to feed the actions to a widget.
+#[derive(Debug, Clone)]
+pub struct KeyMap<A> {
+    map: HashMap<KeyCombo, A>,
+    help: HashMap<KeyCombo, BindingHelp>,
+}
+
+impl<A> Default for KeyMap<A> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+            help: HashMap::new(),
+        }
+    }
+}
+
+impl<A> KeyMap<A> {
+    /// Empty keymap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keymap from key bindings as written in a config file.
+    ///
+    /// The bindings are parsed with [parse_key](crate::crossterm::parse_key).
+    /// Two bindings for the same key are an error too, "ctrl+S" and
+    /// "ctrl+shift+s" for example.
+    ///
+    /// ```rust
+    /// use rat_event::crossterm::{KeyMap, KeyMapError};
+    ///
+    /// let keymap = KeyMap::from_pairs([
+    ///     ("ctrl+s".to_string(), "save"),
+    ///     ("ctrl+q".to_string(), "quit"),
+    /// ])?;
+    /// assert_eq!(keymap.len(), 2);
+    ///
+    /// let err = KeyMap::from_pairs([("ctrl+sv".to_string(), "save")]).unwrap_err();
+    /// assert_eq!(err.binding(), "ctrl+sv");
+    /// # Ok::<(), KeyMapError>(())
+    /// ```
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (String, A)>) -> Result<Self, KeyMapError> {
+        let mut map = KeyMap::new();
+        for (binding, action) in pairs {
+            let key = match binding.parse::<KeyCombo>() {
+                Ok(v) => v,
+                Err(e) => return Err(KeyMapError::Parse(binding, e)),
+            };
+            if map.bind(key, action).is_some() {
+                return Err(KeyMapError::Duplicate(binding));
+            }
+        }
+        Ok(map)
+    }
+
+    /// Bind a key to an action.
+    ///
+    /// If the key was already bound, the old action is replaced
+    /// and returned.
+    pub fn bind(&mut self, key: impl Into<KeyCombo>, action: A) -> Option<A> {
+        let key = key.into();
+        self.help.remove(&key);
+        self.map.insert(key, action)
+    }
+
+    /// Bind a key to an action, with a description for the help.
+    ///
+    /// ```rust
+    /// use rat_event::crossterm::event::{KeyCode, KeyModifiers};
+    /// use rat_event::crossterm::{BindingHelp, KeyCombo, KeyMap};
+    ///
+    /// let mut keymap = KeyMap::new();
+    /// keymap.bind_with(
+    ///     KeyCombo::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+    ///     "save",
+    ///     BindingHelp::new("Save the file").category("File"),
+    /// );
+    /// keymap.bind_with(KeyCombo::new(KeyCode::F(1), KeyModifiers::NONE), "help", "Help");
+    ///
+    /// let entries = keymap.entries().collect::<Vec<_>>();
+    /// assert_eq!(entries[0].key, "F1");
+    /// assert_eq!(entries[1].key, "Ctrl+s");
+    /// assert_eq!(entries[1].category, Some("File"));
+    /// ```
+    pub fn bind_with(
+        &mut self,
+        key: impl Into<KeyCombo>,
+        action: A,
+        help: impl Into<BindingHelp>,
+    ) -> Option<A> {
+        let key = key.into();
+        let old = self.bind(key, action);
+        self.help.insert(key, help.into());
+        old
+    }
+
+    /// Help for a binding.
+    pub fn help(&self, key: &KeyCombo) -> Option<&BindingHelp> {
+        self.help.get(key)
+    }
+
+    /// All bindings for a help text. Sorted by category and key,
+    /// the bindings without category first.
+    pub fn entries(&self) -> impl Iterator<Item = BindingEntry<'_, A>> {
+        let mut entries = self
+            .map
+            .iter()
+            .map(|(key, action)| BindingEntry::new(vec![*key], action, self.help.get(key)))
+            .collect::<Vec<_>>();
+        BindingEntry::sort(&mut entries);
+        entries.into_iter()
+    }
+
+    /// Move the binding from one key to another.
+    ///
+    /// Fails if the old key is not bound, or if the new key is
+    /// bound already. Nothing is changed then.
+    pub fn rebind(
+        &mut self,
+        old: impl Into<KeyCombo>,
+        new: impl Into<KeyCombo>,
+    ) -> Result<(), BindError> {
+        let old = old.into();
+        let new = new.into();
+        if !self.map.contains_key(&old) {
+            return Err(BindError::NotBound(old));
+        }
+        if old == new {
+            return Ok(());
+        }
+        if self.map.contains_key(&new) {
+            return Err(BindError::Taken(new));
+        }
+        let action = self.map.remove(&old).expect("bound");
+        self.map.insert(new, action);
+        if let Some(help) = self.help.remove(&old) {
+            self.help.insert(new, help);
+        }
+        Ok(())
+    }
+
+    /// Remove the binding for a key. Returns the old action.
+    pub fn unbind(&mut self, key: impl Into<KeyCombo>) -> Option<A> {
+        let key = key.into();
+        self.help.remove(&key);
+        self.map.remove(&key)
+    }
+
+    /// Action for a key press.
+    ///
+    /// Releases never map to an action, so a binding fires only
+    /// once on Windows too. Repeats do.
+    pub fn lookup(&self, key: &KeyEvent) -> Option<&A> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        self.map.get(&KeyCombo::from(key))
+    }
+
+    /// Action for an event. None for anything but a key press.
+    pub fn lookup_event(&self, event: &Event) -> Option<&A> {
+        match event {
+            Event::Key(key) => self.lookup(key),
+            _ => None,
+        }
+    }
+
+    /// Action bound to a key.
+    pub fn get(&self, key: &KeyCombo) -> Option<&A> {
+        self.map.get(key)
+    }
+
+    /// All keys bound to this action, sorted.
+    ///
+    /// Useful for help texts.
+    pub fn keys_for(&self, action: &A) -> Vec<KeyCombo>
+    where
+        A: PartialEq,
+    {
+        let mut keys = self
+            .map
+            .iter()
+            .filter(|(_, v)| *v == action)
+            .map(|(k, _)| *k)
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    /// All bindings, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&KeyCombo, &A)> {
+        self.map.iter()
+    }
+
+    /// Finds the bindings that get in each other's way, if the
+    /// keymap is consulted before the sequences.
+    ///
+    /// * A key that is bound here and as a single key sequence.
+    /// * A key that is bound here and starts a sequence. The
+    ///   sequence can never fire.
+    /// * Everything [KeySequence::conflicts] finds.
+    pub fn conflicts<B>(&self, sequence: &KeySequence<B>) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+        for (keys, _) in sequence.all() {
+            if self.map.contains_key(&keys[0]) {
+                conflicts.push(Conflict {
+                    kind: if keys.len() == 1 {
+                        ConflictKind::Duplicate
+                    } else {
+                        ConflictKind::Shadowed
+                    },
+                    binding: vec![keys[0]],
+                    other: keys,
+                });
+            }
+        }
+        conflicts.sort_by(|a, b| (&a.binding, &a.other).cmp(&(&b.binding, &b.other)));
+        conflicts.extend(sequence.conflicts());
+        conflicts
+    }
+
+    /// Changes from this keymap to the other one, sorted by key.
+    ///
+    /// Use the defaults as self to show the user's changes.
+    pub fn diff<'a>(&'a self, other: &'a KeyMap<A>) -> Vec<KeyMapChange<'a, A>>
+    where
+        A: PartialEq,
+    {
+        let mut changes = Vec::new();
+        for (key, action) in &self.map {
+            match other.map.get(key) {
+                None => changes.push(KeyMapChange::Removed(*key, action)),
+                Some(new) if new != action => {
+                    changes.push(KeyMapChange::Changed(*key, action, new));
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, action) in &other.map {
+            if !self.map.contains_key(key) {
+                changes.push(KeyMapChange::Added(*key, action));
+            }
+        }
+        changes.sort_by_key(|v| v.key());
+        changes
+    }
+
+    /// Number of bindings.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// No bindings.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<A, K> FromIterator<(K, A)> for KeyMap<A>
+where
+    K: Into<KeyCombo>,
+{
+    /// Later bindings for the same key replace earlier ones.
+    fn from_iter<T: IntoIterator<Item = (K, A)>>(iter: T) -> Self {
+        let mut map = KeyMap::new();
+        for (key, action) in iter {
+            map.bind(key, action);
+        }
+        map
+    }
+}
+
+/// Error from [KeyMap::from_pairs].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyMapError {
+    /// The binding can't be parsed.
+    Parse(String, KeyParseError),
+    /// The binding is the same key as another one.
+    Duplicate(String),
+}
+
+impl KeyMapError {
+    /// The offending binding.
+    pub fn binding(&self) -> &str {
+        match self {
+            KeyMapError::Parse(binding, _) => binding,
+            KeyMapError::Duplicate(binding) => binding,
+        }
+    }
+}
+
+impl Display for KeyMapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyMapError::Parse(binding, e) => write!(f, "key binding '{}': {}", binding, e),
+            KeyMapError::Duplicate(binding) => {
+                write!(f, "key binding '{}': the key is bound twice", binding)
+            }
+        }
+    }
+}
+
+impl Error for KeyMapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KeyMapError::Parse(_, e) => Some(e),
+            KeyMapError::Duplicate(_) => None,
+        }
+    }
+}
+
+/// Description of a binding for the help.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BindingHelp {
+    /// Description.
+    pub description: String,
+    /// Category to group the bindings.
+    pub category: Option<String>,
+}
+
+impl BindingHelp {
+    /// Help with a description.
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            category: None,
+        }
+    }
+
+    /// Set the category.
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+impl From<&str> for BindingHelp {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for BindingHelp {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// One line of a help text.
+///
+/// Created by [KeyMap::entries], [KeySequence::entries] and
+/// [KeyLayers::entries].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingEntry<'a, A> {
+    /// Formatted keys, a sequence separated with spaces.
+    /// "Ctrl+k Ctrl+c"
+    pub key: String,
+    /// The keys.
+    pub keys: Vec<KeyCombo>,
+    /// The action.
+    pub action: &'a A,
+    /// Description, if any.
+    pub description: Option<&'a str>,
+    /// Category, if any.
+    pub category: Option<&'a str>,
+}
+
+impl<'a, A> BindingEntry<'a, A> {
+    pub(crate) fn new(keys: Vec<KeyCombo>, action: &'a A, help: Option<&'a BindingHelp>) -> Self {
+        Self {
+            key: fmt_keys(&keys),
+            keys,
+            action,
+            description: help.map(|v| v.description.as_str()),
+            category: help.and_then(|v| v.category.as_deref()),
+        }
+    }
+
+    pub(crate) fn sort(entries: &mut [Self]) {
+        entries.sort_by(|a, b| (a.category, &a.keys).cmp(&(b.category, &b.keys)));
+    }
+}
+
+/// Error from [KeyMap::rebind] and
+/// [HotKeyRegistry::register](crate::crossterm::HotKeyRegistry::register).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindError {
+    /// The old key is not bound.
+    NotBound(KeyCombo),
+    /// The new key is already bound.
+    Taken(KeyCombo),
+}
+
+impl Display for BindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BindError::NotBound(key) => write!(f, "'{}' is not bound", key),
+            BindError::Taken(key) => write!(f, "'{}' is already bound", key),
+        }
+    }
+}
+
+impl Error for BindError {}
+
+/// Kind of [Conflict].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConflictKind {
+    /// The same keys are bound twice.
+    Duplicate,
+    /// The binding consumes the first key of the other one,
+    /// which can never fire.
+    Shadowed,
+    /// The binding is the start of the other one. It only fires
+    /// after the timeout, or when the next key doesn't continue
+    /// the other one.
+    Prefix,
+}
+
+/// Two bindings that get in each other's way.
+///
+/// Display gives a description for the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// Kind of conflict.
+    pub kind: ConflictKind,
+    /// The binding that wins.
+    pub binding: Vec<KeyCombo>,
+    /// The other binding.
+    pub other: Vec<KeyCombo>,
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let binding = fmt_keys(&self.binding);
+        let other = fmt_keys(&self.other);
+        match self.kind {
+            ConflictKind::Duplicate => {
+                write!(f, "'{}' is bound in the keymap and as a sequence", binding)
+            }
+            ConflictKind::Shadowed => {
+                write!(f, "'{}' hides the sequence '{}'", binding, other)
+            }
+            ConflictKind::Prefix => write!(
+                f,
+                "'{}' is the start of '{}' and only fires after a timeout",
+                binding, other
+            ),
+        }
+    }
+}
+
+fn fmt_keys(keys: &[KeyCombo]) -> String {
+    keys.iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// One change found by [KeyMap::diff].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMapChange<'a, A> {
+    /// New binding.
+    Added(KeyCombo, &'a A),
+    /// Binding removed.
+    Removed(KeyCombo, &'a A),
+    /// Key bound to another action. Old and new action.
+    Changed(KeyCombo, &'a A, &'a A),
+}
+
+impl<A> KeyMapChange<'_, A> {
+    /// The key.
+    pub fn key(&self) -> KeyCombo {
+        match self {
+            KeyMapChange::Added(key, _) => *key,
+            KeyMapChange::Removed(key, _) => *key,
+            KeyMapChange::Changed(key, _, _) => *key,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A> serde::Serialize for KeyMap<A>
+where
+    A: serde::Serialize,
+{
+    /// Serializes as a map from binding to action, sorted by key.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut keys = self.map.iter().collect::<Vec<_>>();
+        keys.sort_by_key(|(k, _)| **k);
+
+        let mut map = serializer.serialize_map(Some(keys.len()))?;
+        for (key, action) in keys {
+            map.serialize_entry(key, action)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A> serde::Deserialize<'de> for KeyMap<A>
+where
+    A: serde::Deserialize<'de>,
+{
+    /// Deserializes from a map of binding to action, with [KeyMap::from_pairs].
+    ///
+    /// ```rust
+    /// use rat_event::crossterm::KeyMap;
+    /// use rat_event::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// #[serde(rename_all = "kebab-case")]
+    /// enum Action {
+    ///     Save,
+    ///     SaveAs,
+    /// }
+    ///
+    /// let keymap: KeyMap<Action> = toml::from_str(r#"
+    ///     "ctrl+s" = "save"
+    ///     "ctrl+shift+s" = "save-as"
+    /// "#)?;
+    ///
+    /// let key = KeyEvent::new(KeyCode::Char('S'), KeyModifiers::CONTROL);
+    /// assert_eq!(keymap.lookup(&key), Some(&Action::SaveAs));
+    /// # Ok::<(), toml::de::Error>(())
+    /// ```
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeyMapVisitor<A>(std::marker::PhantomData<A>);
+
+        impl<'de, A> serde::de::Visitor<'de> for KeyMapVisitor<A>
+        where
+            A: serde::Deserialize<'de>,
+        {
+            type Value = KeyMap<A>;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of key bindings to actions")
+            }
+
+            fn visit_map<M: serde::de::MapAccess<'de>>(
+                self,
+                mut access: M,
+            ) -> Result<Self::Value, M::Error> {
+                let mut pairs = Vec::new();
+                while let Some(pair) = access.next_entry::<String, A>()? {
+                    pairs.push(pair);
+                }
+                KeyMap::from_pairs(pairs).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_map(KeyMapVisitor(std::marker::PhantomData))
+    }
+}
+
+/// A stack of [KeyMap]s for modal UIs.
+///
+/// The top layer is consulted first. If it has no binding for a
+/// key, the next one is tried, unless the layer is opaque. An
+/// opaque layer hides everything below, e.g. an insert mode
+/// blocks all normal mode bindings.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::{KeyCombo, KeyLayers, KeyMap};
+///
+/// let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+///
+/// let mut layers = KeyLayers::new();
+/// layers.push_layer(KeyMap::from_iter([(key('i'), "insert"), (key('q'), "quit")]), false);
+/// layers.push_layer(KeyMap::from_iter([(key('h'), "shrink")]), false);
+/// assert_eq!(layers.lookup(&key('h')), Some(&"shrink"));
+/// assert_eq!(layers.lookup(&key('q')), Some(&"quit"));
+///
+/// layers.pop_layer();
+/// layers.push_layer(KeyMap::new(), true);
+/// assert_eq!(layers.lookup(&key('q')), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyLayers<A> {
+    layers: Vec<(KeyMap<A>, bool)>,
+}
+
+impl<A> Default for KeyLayers<A> {
+    fn default() -> Self {
+        Self { layers: Vec::new() }
+    }
+}
+
+impl<A> KeyLayers<A> {
+    /// No layers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a layer on top. An opaque layer stops the lookup.
+    pub fn push_layer(&mut self, keymap: KeyMap<A>, opaque: bool) {
+        self.layers.push((keymap, opaque));
+    }
+
+    /// Remove the top layer.
+    pub fn pop_layer(&mut self) -> Option<KeyMap<A>> {
+        self.layers.pop().map(|(keymap, _)| keymap)
+    }
+
+    /// The top layer.
+    pub fn top(&self) -> Option<&KeyMap<A>> {
+        self.layers.last().map(|(keymap, _)| keymap)
+    }
+
+    /// The top layer.
+    pub fn top_mut(&mut self) -> Option<&mut KeyMap<A>> {
+        self.layers.last_mut().map(|(keymap, _)| keymap)
+    }
+
+    /// Number of layers.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// No layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Action for a key press. Walks the layers top-down until
+    /// a binding is found or an opaque layer is reached.
+    pub fn lookup(&self, key: &KeyEvent) -> Option<&A> {
+        for (keymap, opaque) in self.layers.iter().rev() {
+            if let Some(action) = keymap.lookup(key) {
+                return Some(action);
+            }
+            if *opaque {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Action for an event. None for anything but a key press.
+    pub fn lookup_event(&self, event: &Event) -> Option<&A> {
+        match event {
+            Event::Key(key) => self.lookup(key),
+            _ => None,
+        }
+    }
+
+    /// All bindings that can fire for a help text.
+    ///
+    /// Bindings hidden by a higher layer, or below an opaque
+    /// layer, are left out. Sorted like [KeyMap::entries].
+    pub fn entries(&self) -> impl Iterator<Item = BindingEntry<'_, A>> {
+        let mut entries: Vec<BindingEntry<'_, A>> = Vec::new();
+        for (keymap, opaque) in self.layers.iter().rev() {
+            for entry in keymap.entries() {
+                if !entries.iter().any(|v| v.keys == entry.keys) {
+                    entries.push(entry);
+                }
+            }
+            if *opaque {
+                break;
+            }
+        }
+        BindingEntry::sort(&mut entries);
+        entries.into_iter()
+    }
+}
+
+/// Translates key events to actions with a [KeyMap] or [KeyLayers].
+///
+/// The wrapped event-handler handles the actions instead of
+/// the raw events. Any event that is not bound returns
+/// `R::default()`, see [not consumed](ConsumedEvent#not-consumed).
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::{KeyCombo, KeyMap, Keyed};
+/// use rat_event::{HandleEvent, Outcome, Regular};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Nav {
+///     Up,
+///     Down,
+/// }
+///
+/// struct List {
+///     selected: usize,
+/// }
+///
+/// impl HandleEvent<Nav, Regular, Outcome> for List {
+///     fn handle(&mut self, action: &Nav, _qualifier: Regular) -> Outcome {
+///         match action {
+///             Nav::Up => self.selected = self.selected.saturating_sub(1),
+///             Nav::Down => self.selected += 1,
+///         }
+///         Outcome::Changed
+///     }
+/// }
+///
+/// let keymap = KeyMap::from_iter([
+///     (KeyCombo::new(KeyCode::Up, KeyModifiers::NONE), Nav::Up),
+///     (KeyCombo::new(KeyCode::Char('k'), KeyModifiers::NONE), Nav::Up),
+///     (KeyCombo::new(KeyCode::Down, KeyModifiers::NONE), Nav::Down),
+///     (KeyCombo::new(KeyCode::Char('j'), KeyModifiers::NONE), Nav::Down),
+/// ]);
+///
+/// let mut list = List { selected: 0 };
+/// let j = Event::Key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+/// let x = Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+///
+/// assert_eq!(Keyed(&mut list).handle(&j, &keymap), Outcome::Changed);
+/// assert_eq!(Keyed(&mut list).handle(&x, &keymap), Outcome::Continue);
+/// assert_eq!(list.selected, 1);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Keyed<H>(pub H);
+
+impl<'a, H, A, R> HandleEvent<Event, &'a KeyMap<A>, R> for Keyed<H>
+where
+    H: HandleEvent<A, Regular, R>,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &Event, keymap: &'a KeyMap<A>) -> R {
+        match keymap.lookup_event(event) {
+            Some(action) => self.0.handle(action, Regular),
+            None => R::default(),
+        }
+    }
+}
+
+impl<'a, H, A, R> HandleEvent<Event, &'a KeyLayers<A>, R> for Keyed<H>
+where
+    H: HandleEvent<A, Regular, R>,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &Event, layers: &'a KeyLayers<A>) -> R {
+        match layers.lookup_event(event) {
+            Some(action) => self.0.handle(action, Regular),
+            None => R::default(),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+};
+use crate::util::MouseFlags;
+use crate::{ConsumedEvent, HandleEvent, Outcome, Popup};
+use ratatui::layout::{Position, Rect};
+
+/// Result of [PopupCore].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PopupOutcome {
+    /// Not used.
+    #[default]
+    Continue,
+    /// Event has been used, but nothing changed.
+    Unchanged,
+    /// Event has been used and something changed.
+    Changed,
+    /// The popup has been closed, but the event is not consumed.
+    /// Others still need to see it, e.g. the focus loss.
+    ///
+    /// This still needs a render, and converts to `Outcome::Changed`.
+    /// Use it with `flow!(.., consider ..)` or keep the max() of it.
+    Closed,
+    /// The popup has been closed, and the event is used up.
+    ClosedAndConsumed,
+}
+
+impl ConsumedEvent for PopupOutcome {
+    fn is_consumed(&self) -> bool {
+        !matches!(self, PopupOutcome::Continue | PopupOutcome::Closed)
+    }
+}
+
+impl From<Outcome> for PopupOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => PopupOutcome::Continue,
+            Outcome::Unchanged => PopupOutcome::Unchanged,
+            Outcome::Changed => PopupOutcome::Changed,
+        }
+    }
+}
+
+impl From<PopupOutcome> for Outcome {
+    fn from(value: PopupOutcome) -> Self {
+        match value {
+            PopupOutcome::Continue => Outcome::Continue,
+            PopupOutcome::Unchanged => Outcome::Unchanged,
+            PopupOutcome::Changed | PopupOutcome::Closed | PopupOutcome::ClosedAndConsumed => {
+                Outcome::Changed
+            }
+        }
+    }
+}
+
+/// Closes a popup.
+///
+/// Add this to the popup state, set the area during rendering and
+/// call the [Popup] event-handler before the event-handler for the
+/// content of the popup. It closes the popup for
+///
+/// * Esc. Returns `ClosedAndConsumed`.
+/// * a mouse-down outside the area. Returns `ClosedAndConsumed`,
+///   and swallows the matching mouse-up. Otherwise, the mouse-up
+///   would end up with the widget below the popup, which may
+///   act on it.
+/// * the loss of the terminal focus. Returns `Closed`, as others
+///   may need to know too.
+///
+/// Everything else returns `Continue`, and is left for the content.
+/// While inactive it only swallows the mouse-up.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+/// use rat_event::crossterm::{PopupCore, PopupOutcome};
+/// use rat_event::{HandleEvent, Popup};
+/// use ratatui::layout::Rect;
+///
+/// let mouse = |kind| Event::Mouse(MouseEvent {
+///     kind,
+///     column: 20,
+///     row: 20,
+///     modifiers: KeyModifiers::NONE,
+/// });
+///
+/// let mut popup = PopupCore::new();
+/// popup.area = Rect::new(0, 0, 10, 10);
+/// popup.set_active(true);
+///
+/// let down = mouse(MouseEventKind::Down(MouseButton::Left));
+/// let up = mouse(MouseEventKind::Up(MouseButton::Left));
+/// assert_eq!(popup.handle(&down, Popup), PopupOutcome::ClosedAndConsumed);
+/// assert!(!popup.is_active());
+/// // the click is used up.
+/// assert_eq!(popup.handle(&up, Popup), PopupOutcome::Unchanged);
+/// assert_eq!(popup.handle(&up, Popup), PopupOutcome::Continue);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct PopupCore {
+    /// Area of the popup. Set during rendering.
+    pub area: Rect,
+    /// Popup is shown.
+    pub active: bool,
+    /// Mouse flags for the content of the popup.
+    /// They are cleared when the popup closes.
+    pub mouse: MouseFlags,
+
+    swallow_release: bool,
+}
+
+impl PopupCore {
+    /// New inactive popup.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Popup is shown.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Show or hide the popup.
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        if !active {
+            self.mouse.clear();
+        }
+    }
+
+    /// Swallow the next mouse-up.
+    ///
+    /// This is done automatically after a mouse-down outside the popup.
+    /// Use it if the popup is closed by some mouse-down of the content,
+    /// e.g. when a menu item is selected.
+    pub fn swallow_next_release(&mut self) {
+        self.swallow_release = true;
+    }
+
+    /// The next mouse-up will be swallowed.
+    pub fn swallows_release(&self) -> bool {
+        self.swallow_release
+    }
+}
+
+impl HandleEvent<Event, Popup, PopupOutcome> for PopupCore {
+    fn handle(&mut self, event: &Event, _qualifier: Popup) -> PopupOutcome {
+        if self.swallow_release {
+            match event {
+                Event::Mouse(MouseEvent {
+                    kind: MouseEventKind::Up(_),
+                    ..
+                }) => {
+                    self.swallow_release = false;
+                    return PopupOutcome::Unchanged;
+                }
+                Event::Mouse(MouseEvent {
+                    kind: MouseEventKind::Down(_),
+                    ..
+                }) => {
+                    // the mouse-up got lost somewhere.
+                    self.swallow_release = false;
+                }
+                _ => {}
+            }
+        }
+
+        if !self.active {
+            return PopupOutcome::Continue;
+        }
+
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                modifiers: KeyModifiers::NONE,
+                kind: KeyEventKind::Press,
+                ..
+            }) => {
+                self.set_active(false);
+                PopupOutcome::ClosedAndConsumed
+            }
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(_),
+                column,
+                row,
+                ..
+            }) if !self.area.contains(Position::new(*column, *row)) => {
+                self.set_active(false);
+                self.swallow_next_release();
+                PopupOutcome::ClosedAndConsumed
+            }
+            Event::FocusLost => {
+                self.set_active(false);
+                PopupOutcome::Closed
+            }
+            _ => PopupOutcome::Continue,
+        }
+    }
+}
@@ -0,0 +1,551 @@
+use crate::clock::{Clock, SharedClock};
+use crate::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode,
+    ModifierKeyCode, MouseButton, MouseEvent, MouseEventKind,
+};
+use crate::source::{EventSource, SourceError};
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Serializable copy of a crossterm event.
+///
+/// The crossterm events don't implement serde, this is used
+/// for the files of the [EventRecorder]. Converts to/from Event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordedEvent {
+    FocusGained,
+    FocusLost,
+    Key {
+        code: RecordedKeyCode,
+        /// KeyModifiers as bits.
+        modifiers: u8,
+        kind: RecordedKeyKind,
+        /// KeyEventState as bits.
+        state: u8,
+    },
+    Mouse {
+        kind: RecordedMouseKind,
+        column: u16,
+        row: u16,
+        /// KeyModifiers as bits.
+        modifiers: u8,
+    },
+    Paste(String),
+    Resize(u16, u16),
+}
+
+/// Copy of [KeyCode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordedKeyCode {
+    Backspace,
+    Enter,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Tab,
+    BackTab,
+    Delete,
+    Insert,
+    F(u8),
+    Char(char),
+    Null,
+    Esc,
+    CapsLock,
+    ScrollLock,
+    NumLock,
+    PrintScreen,
+    Pause,
+    Menu,
+    KeypadBegin,
+    Media(RecordedMediaKey),
+    Modifier(RecordedModifierKey),
+}
+
+/// Copy of [MouseEventKind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordedMouseKind {
+    Down(RecordedMouseButton),
+    Up(RecordedMouseButton),
+    Drag(RecordedMouseButton),
+    Moved,
+    ScrollDown,
+    ScrollUp,
+    ScrollLeft,
+    ScrollRight,
+}
+
+/// Copies an enum without data, and the conversions in both directions.
+macro_rules! mirror_enum {
+    ($(#[$attr:meta])* $name:ident = $ct:ident { $($v:ident),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        pub enum $name {
+            $($v),*
+        }
+
+        impl From<$ct> for $name {
+            fn from(value: $ct) -> Self {
+                match value {
+                    $($ct::$v => $name::$v),*
+                }
+            }
+        }
+
+        impl From<$name> for $ct {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$v => $ct::$v),*
+                }
+            }
+        }
+    };
+}
+
+mirror_enum!(
+    /// Copy of [KeyEventKind].
+    RecordedKeyKind = KeyEventKind { Press, Repeat, Release }
+);
+
+mirror_enum!(
+    /// Copy of [MouseButton].
+    RecordedMouseButton = MouseButton { Left, Right, Middle }
+);
+
+mirror_enum!(
+    /// Copy of [MediaKeyCode].
+    RecordedMediaKey = MediaKeyCode {
+        Play,
+        Pause,
+        PlayPause,
+        Reverse,
+        Stop,
+        FastForward,
+        Rewind,
+        TrackNext,
+        TrackPrevious,
+        Record,
+        LowerVolume,
+        RaiseVolume,
+        MuteVolume,
+    }
+);
+
+mirror_enum!(
+    /// Copy of [ModifierKeyCode].
+    RecordedModifierKey = ModifierKeyCode {
+        LeftShift,
+        LeftControl,
+        LeftAlt,
+        LeftSuper,
+        LeftHyper,
+        LeftMeta,
+        RightShift,
+        RightControl,
+        RightAlt,
+        RightSuper,
+        RightHyper,
+        RightMeta,
+        IsoLevel3Shift,
+        IsoLevel5Shift,
+    }
+);
+
+impl From<KeyCode> for RecordedKeyCode {
+    fn from(value: KeyCode) -> Self {
+        match value {
+            KeyCode::Backspace => RecordedKeyCode::Backspace,
+            KeyCode::Enter => RecordedKeyCode::Enter,
+            KeyCode::Left => RecordedKeyCode::Left,
+            KeyCode::Right => RecordedKeyCode::Right,
+            KeyCode::Up => RecordedKeyCode::Up,
+            KeyCode::Down => RecordedKeyCode::Down,
+            KeyCode::Home => RecordedKeyCode::Home,
+            KeyCode::End => RecordedKeyCode::End,
+            KeyCode::PageUp => RecordedKeyCode::PageUp,
+            KeyCode::PageDown => RecordedKeyCode::PageDown,
+            KeyCode::Tab => RecordedKeyCode::Tab,
+            KeyCode::BackTab => RecordedKeyCode::BackTab,
+            KeyCode::Delete => RecordedKeyCode::Delete,
+            KeyCode::Insert => RecordedKeyCode::Insert,
+            KeyCode::F(n) => RecordedKeyCode::F(n),
+            KeyCode::Char(c) => RecordedKeyCode::Char(c),
+            KeyCode::Null => RecordedKeyCode::Null,
+            KeyCode::Esc => RecordedKeyCode::Esc,
+            KeyCode::CapsLock => RecordedKeyCode::CapsLock,
+            KeyCode::ScrollLock => RecordedKeyCode::ScrollLock,
+            KeyCode::NumLock => RecordedKeyCode::NumLock,
+            KeyCode::PrintScreen => RecordedKeyCode::PrintScreen,
+            KeyCode::Pause => RecordedKeyCode::Pause,
+            KeyCode::Menu => RecordedKeyCode::Menu,
+            KeyCode::KeypadBegin => RecordedKeyCode::KeypadBegin,
+            KeyCode::Media(v) => RecordedKeyCode::Media(v.into()),
+            KeyCode::Modifier(v) => RecordedKeyCode::Modifier(v.into()),
+        }
+    }
+}
+
+impl From<RecordedKeyCode> for KeyCode {
+    fn from(value: RecordedKeyCode) -> Self {
+        match value {
+            RecordedKeyCode::Backspace => KeyCode::Backspace,
+            RecordedKeyCode::Enter => KeyCode::Enter,
+            RecordedKeyCode::Left => KeyCode::Left,
+            RecordedKeyCode::Right => KeyCode::Right,
+            RecordedKeyCode::Up => KeyCode::Up,
+            RecordedKeyCode::Down => KeyCode::Down,
+            RecordedKeyCode::Home => KeyCode::Home,
+            RecordedKeyCode::End => KeyCode::End,
+            RecordedKeyCode::PageUp => KeyCode::PageUp,
+            RecordedKeyCode::PageDown => KeyCode::PageDown,
+            RecordedKeyCode::Tab => KeyCode::Tab,
+            RecordedKeyCode::BackTab => KeyCode::BackTab,
+            RecordedKeyCode::Delete => KeyCode::Delete,
+            RecordedKeyCode::Insert => KeyCode::Insert,
+            RecordedKeyCode::F(n) => KeyCode::F(n),
+            RecordedKeyCode::Char(c) => KeyCode::Char(c),
+            RecordedKeyCode::Null => KeyCode::Null,
+            RecordedKeyCode::Esc => KeyCode::Esc,
+            RecordedKeyCode::CapsLock => KeyCode::CapsLock,
+            RecordedKeyCode::ScrollLock => KeyCode::ScrollLock,
+            RecordedKeyCode::NumLock => KeyCode::NumLock,
+            RecordedKeyCode::PrintScreen => KeyCode::PrintScreen,
+            RecordedKeyCode::Pause => KeyCode::Pause,
+            RecordedKeyCode::Menu => KeyCode::Menu,
+            RecordedKeyCode::KeypadBegin => KeyCode::KeypadBegin,
+            RecordedKeyCode::Media(v) => KeyCode::Media(v.into()),
+            RecordedKeyCode::Modifier(v) => KeyCode::Modifier(v.into()),
+        }
+    }
+}
+
+impl From<MouseEventKind> for RecordedMouseKind {
+    fn from(value: MouseEventKind) -> Self {
+        match value {
+            MouseEventKind::Down(b) => RecordedMouseKind::Down(b.into()),
+            MouseEventKind::Up(b) => RecordedMouseKind::Up(b.into()),
+            MouseEventKind::Drag(b) => RecordedMouseKind::Drag(b.into()),
+            MouseEventKind::Moved => RecordedMouseKind::Moved,
+            MouseEventKind::ScrollDown => RecordedMouseKind::ScrollDown,
+            MouseEventKind::ScrollUp => RecordedMouseKind::ScrollUp,
+            MouseEventKind::ScrollLeft => RecordedMouseKind::ScrollLeft,
+            MouseEventKind::ScrollRight => RecordedMouseKind::ScrollRight,
+        }
+    }
+}
+
+impl From<RecordedMouseKind> for MouseEventKind {
+    fn from(value: RecordedMouseKind) -> Self {
+        match value {
+            RecordedMouseKind::Down(b) => MouseEventKind::Down(b.into()),
+            RecordedMouseKind::Up(b) => MouseEventKind::Up(b.into()),
+            RecordedMouseKind::Drag(b) => MouseEventKind::Drag(b.into()),
+            RecordedMouseKind::Moved => MouseEventKind::Moved,
+            RecordedMouseKind::ScrollDown => MouseEventKind::ScrollDown,
+            RecordedMouseKind::ScrollUp => MouseEventKind::ScrollUp,
+            RecordedMouseKind::ScrollLeft => MouseEventKind::ScrollLeft,
+            RecordedMouseKind::ScrollRight => MouseEventKind::ScrollRight,
+        }
+    }
+}
+
+impl From<&Event> for RecordedEvent {
+    fn from(value: &Event) -> Self {
+        match value {
+            Event::FocusGained => RecordedEvent::FocusGained,
+            Event::FocusLost => RecordedEvent::FocusLost,
+            Event::Key(k) => RecordedEvent::Key {
+                code: k.code.into(),
+                modifiers: k.modifiers.bits(),
+                kind: k.kind.into(),
+                state: k.state.bits(),
+            },
+            Event::Mouse(m) => RecordedEvent::Mouse {
+                kind: m.kind.into(),
+                column: m.column,
+                row: m.row,
+                modifiers: m.modifiers.bits(),
+            },
+            Event::Paste(v) => RecordedEvent::Paste(v.clone()),
+            Event::Resize(c, r) => RecordedEvent::Resize(*c, *r),
+        }
+    }
+}
+
+impl From<RecordedEvent> for Event {
+    fn from(value: RecordedEvent) -> Self {
+        match value {
+            RecordedEvent::FocusGained => Event::FocusGained,
+            RecordedEvent::FocusLost => Event::FocusLost,
+            RecordedEvent::Key {
+                code,
+                modifiers,
+                kind,
+                state,
+            } => Event::Key(KeyEvent {
+                code: code.into(),
+                modifiers: KeyModifiers::from_bits_truncate(modifiers),
+                kind: kind.into(),
+                state: KeyEventState::from_bits_truncate(state),
+            }),
+            RecordedEvent::Mouse {
+                kind,
+                column,
+                row,
+                modifiers,
+            } => Event::Mouse(MouseEvent {
+                kind: kind.into(),
+                column,
+                row,
+                modifiers: KeyModifiers::from_bits_truncate(modifiers),
+            }),
+            RecordedEvent::Paste(v) => Event::Paste(v),
+            RecordedEvent::Resize(c, r) => Event::Resize(c, r),
+        }
+    }
+}
+
+/// One line of the file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Line {
+    /// Microseconds since the start of the recording.
+    t: u64,
+    e: RecordedEvent,
+}
+
+/// Records the events of a source.
+///
+/// Wraps an [EventSource] and writes every event it returns, with
+/// the time since the start of the recording. The file has one
+/// JSON object per line, and can be played back with [EventReplay].
+///
+/// The timestamps come from a [SharedClock], by default the system
+/// clock.
+///
+/// ```rust no_run
+/// use rat_event::crossterm::{CrosstermSource, EventRecorder};
+/// use rat_event::source::EventSource;
+/// use std::fs::File;
+/// use std::io::BufWriter;
+///
+/// let file = BufWriter::new(File::create("events.jsonl")?);
+/// let mut source = EventRecorder::new(CrosstermSource::new(), file);
+///
+/// while let Some(event) = source.poll(None)? {
+///     // ...
+/// }
+/// # Ok::<(), rat_event::source::SourceError>(())
+/// ```
+#[derive(Debug)]
+pub struct EventRecorder<S, W: Write> {
+    source: S,
+    out: W,
+    clock: SharedClock,
+    start: Instant,
+}
+
+impl<S, W: Write> EventRecorder<S, W> {
+    /// New recorder. The recording starts now.
+    pub fn new(source: S, out: W) -> Self {
+        let clock = SharedClock::default();
+        Self {
+            source,
+            out,
+            start: clock.now(),
+            clock,
+        }
+    }
+
+    /// Use this clock for the timestamps.
+    /// The recording starts at the current time of the clock.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.set_clock(clock);
+        self
+    }
+
+    /// Use this clock for the timestamps.
+    /// The recording starts at the current time of the clock.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.start = clock.now();
+        self.clock = clock;
+    }
+
+    /// Write an event that didn't come from the source.
+    pub fn record(&mut self, event: &Event) -> io::Result<()> {
+        let line = Line {
+            t: self
+                .clock
+                .now()
+                .saturating_duration_since(self.start)
+                .as_micros() as u64,
+            e: event.into(),
+        };
+        serde_json::to_writer(&mut self.out, &line)?;
+        self.out.write_all(b"\n")?;
+        // keep everything up to a crash.
+        self.out.flush()
+    }
+
+    /// The source.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// The source.
+    pub fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    /// Split into the source and the output.
+    pub fn into_inner(self) -> (S, W) {
+        (self.source, self.out)
+    }
+}
+
+impl<S, W> EventSource<Event> for EventRecorder<S, W>
+where
+    S: EventSource<Event>,
+    W: Write,
+{
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<Event>, SourceError> {
+        let event = self.source.poll(timeout)?;
+        if let Some(event) = &event {
+            self.record(event)?;
+        }
+        Ok(event)
+    }
+}
+
+/// Plays back the file of an [EventRecorder].
+///
+/// By default, it plays the events as fast as they are polled.
+/// With `real_time` each event is delayed as long as during the
+/// recording, counted from the first poll.
+///
+/// At the end of the file it returns `SourceError::Disconnected`.
+///
+/// The time comes from a [SharedClock], by default the system clock.
+/// With any other clock it never sleeps, time only moves with the
+/// clock. An event that is not due yet is kept for the next poll,
+/// and the poll returns `Ok(None)`, with or without a timeout.
+///
+/// ```rust no_run
+/// use rat_event::crossterm::EventReplay;
+/// use rat_event::source::{EventSource, SourceError};
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// let file = BufReader::new(File::open("events.jsonl")?);
+/// let mut source = EventReplay::new(file).real_time(true);
+///
+/// loop {
+///     match source.poll(None) {
+///         Ok(Some(event)) => { /* ... */ }
+///         Ok(None) => {}
+///         Err(SourceError::Disconnected) => break,
+///         Err(e) => return Err(e),
+///     }
+/// }
+/// # Ok::<(), SourceError>(())
+/// ```
+#[derive(Debug)]
+pub struct EventReplay<R: BufRead> {
+    input: R,
+    real_time: bool,
+    clock: SharedClock,
+    start: Option<Instant>,
+    next: Option<(Duration, Event)>,
+}
+
+impl<R: BufRead> EventReplay<R> {
+    /// New replay, as fast as possible.
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            real_time: false,
+            clock: SharedClock::default(),
+            start: None,
+            next: None,
+        }
+    }
+
+    /// Wait as long between the events as during the recording.
+    pub fn real_time(mut self, real_time: bool) -> Self {
+        self.real_time = real_time;
+        self
+    }
+
+    /// Use this clock for the real-time replay.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.set_clock(clock);
+        self
+    }
+
+    /// Use this clock for the real-time replay.
+    /// The time is counted from the next poll.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+        self.start = None;
+    }
+
+    /// Waits between the events.
+    pub fn is_real_time(&self) -> bool {
+        self.real_time
+    }
+
+    /// Reads the next line. Skips empty lines.
+    fn read_line(&mut self) -> Result<(Duration, Event), SourceError> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            if self.input.read_line(&mut buf)? == 0 {
+                return Err(SourceError::Disconnected);
+            }
+            if !buf.trim().is_empty() {
+                break;
+            }
+        }
+        let line: Line = serde_json::from_str(&buf)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok((Duration::from_micros(line.t), line.e.into()))
+    }
+}
+
+impl<R: BufRead> EventSource<Event> for EventReplay<R> {
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<Event>, SourceError> {
+        let (at, event) = match self.next.take() {
+            Some(v) => v,
+            None => self.read_line()?,
+        };
+
+        if self.real_time {
+            let start = *self.start.get_or_insert_with(|| self.clock.now());
+            let now = self.clock.now();
+            let due = start + at;
+            if due > now {
+                let wait = due - now;
+                // sleeping doesn't move any other clock.
+                if !self.clock.is_system() {
+                    self.next = Some((at, event));
+                    return Ok(None);
+                }
+                match timeout {
+                    Some(timeout) if timeout < wait => {
+                        thread::sleep(timeout);
+                        self.next = Some((at, event));
+                        return Ok(None);
+                    }
+                    _ => thread::sleep(wait),
+                }
+            }
+        }
+
+        Ok(Some(event))
+    }
+}
@@ -0,0 +1,149 @@
+use crate::crossterm::event::Event;
+use crate::{HandleEvent, OutcomeOf, Tick, TimeOut};
+use std::time::{Duration, Instant};
+
+/// Result of [ResizeDebounce].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeResolved {
+    /// Not a resize. Handle the event as usual.
+    Continue,
+    /// The resize has been absorbed. Call [ResizeDebounce::resolve]
+    /// after [ResizeDebounce::pending_deadline].
+    Pending,
+    /// The terminal has this size now. Columns and rows.
+    Resized(u16, u16),
+}
+
+/// Absorbs the storm of Resize events while a window is dragged.
+///
+/// The first Resize is reported at once, so the UI reacts immediately.
+/// Any further Resize is held back until nothing more arrived for
+/// the quiet period, and then only the last size is reported.
+///
+/// ```rust
+/// use rat_event::crossterm::event::Event;
+/// use rat_event::crossterm::{ResizeDebounce, ResizeResolved};
+/// use std::time::{Duration, Instant};
+///
+/// let mut resize = ResizeDebounce::new();
+/// let now = Instant::now();
+/// let ms = Duration::from_millis;
+///
+/// assert_eq!(
+///     resize.feed(&Event::Resize(80, 24), now),
+///     ResizeResolved::Resized(80, 24)
+/// );
+/// assert_eq!(
+///     resize.feed(&Event::Resize(81, 24), now + ms(10)),
+///     ResizeResolved::Pending
+/// );
+/// assert_eq!(
+///     resize.feed(&Event::Resize(82, 25), now + ms(20)),
+///     ResizeResolved::Pending
+/// );
+/// // the poll timed out at the deadline.
+/// let deadline = resize.pending_deadline().expect("pending");
+/// assert_eq!(resize.resolve(deadline), ResizeResolved::Resized(82, 25));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResizeDebounce {
+    quiet: Duration,
+    last: Option<Instant>,
+    pending: Option<(u16, u16)>,
+}
+
+impl Default for ResizeDebounce {
+    fn default() -> Self {
+        Self {
+            quiet: Duration::from_millis(100),
+            last: None,
+            pending: None,
+        }
+    }
+}
+
+impl ResizeDebounce {
+    /// New debouncer with a quiet period of 100ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the quiet period.
+    pub fn quiet(mut self, quiet: Duration) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Quiet period.
+    pub fn get_quiet(&self) -> Duration {
+        self.quiet
+    }
+
+    /// When the pending resize is reported, if there is one.
+    /// Use this as poll timeout.
+    pub fn pending_deadline(&self) -> Option<Instant> {
+        match (self.pending, self.last) {
+            (Some(_), Some(last)) => Some(last + self.quiet),
+            _ => None,
+        }
+    }
+
+    /// Is there a pending resize?
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Feed the next event.
+    pub fn feed(&mut self, event: &Event, now: Instant) -> ResizeResolved {
+        let Event::Resize(cols, rows) = *event else {
+            return ResizeResolved::Continue;
+        };
+
+        let quiet = match self.last {
+            Some(last) => now.saturating_duration_since(last) >= self.quiet,
+            None => true,
+        };
+        self.last = Some(now);
+
+        if quiet {
+            // a pending size is outdated anyway.
+            self.pending = None;
+            ResizeResolved::Resized(cols, rows)
+        } else {
+            self.pending = Some((cols, rows));
+            ResizeResolved::Pending
+        }
+    }
+
+    /// Report the pending resize after the deadline.
+    ///
+    /// Returns Resized if the deadline has passed, Pending if
+    /// not, and Continue if there is no pending resize.
+    pub fn resolve(&mut self, now: Instant) -> ResizeResolved {
+        match self.pending_deadline() {
+            Some(deadline) if now >= deadline => {
+                let (cols, rows) = self.pending.take().expect("pending");
+                ResizeResolved::Resized(cols, rows)
+            }
+            Some(_) => ResizeResolved::Pending,
+            None => ResizeResolved::Continue,
+        }
+    }
+
+    /// Forget everything.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.pending = None;
+    }
+}
+
+/// Reports a pending resize. Returns `Value(Resized(..))` if the
+/// deadline has passed, and Continue otherwise.
+impl HandleEvent<TimeOut, Tick, OutcomeOf<ResizeResolved>> for ResizeDebounce {
+    fn handle(&mut self, event: &TimeOut, _qualifier: Tick) -> OutcomeOf<ResizeResolved> {
+        match self.resolve(event.now) {
+            ResizeResolved::Continue | ResizeResolved::Pending => OutcomeOf::Continue,
+            r => OutcomeOf::Value(r),
+        }
+    }
+}
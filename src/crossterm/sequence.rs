@@ -0,0 +1,465 @@
+use crate::crossterm::event::{KeyEvent, KeyEventKind};
+use crate::crossterm::{BindingEntry, BindingHelp, Conflict, ConflictKind, KeyCombo};
+use crate::{HandleEvent, OutcomeOf, Tick, TimeOut};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Result of [KeySequence].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeqResult<A> {
+    /// The keys so far are the start of a sequence. Wait for the
+    /// next key, or call [KeySequence::resolve] after
+    /// [KeySequence::deadline].
+    Pending,
+    /// A sequence has been completed.
+    Matched(A),
+    /// These keys are not part of any sequence. Handle them as
+    /// usual, in this order. Can be empty if there is nothing to do.
+    NoMatch(Vec<KeyEvent>),
+}
+
+/// Recognizes sequences of keys, Ctrl+K Ctrl+C style.
+///
+/// Feed it all key events. While a sequence is incomplete it
+/// swallows the keys. If the next key doesn't continue any
+/// sequence, the swallowed keys are returned so they can be
+/// handled as usual.
+///
+/// A sequence that is also the start of a longer one is matched
+/// when the timeout runs out, or when a key arrives that doesn't
+/// continue the longer one. Any incomplete sequence is cancelled
+/// by the timeout.
+///
+/// A leader key can be set with [KeySequence::set_leader], and
+/// sequences bound with [KeySequence::bind_leader] start with
+/// it. Changing the leader changes all those bindings.
+///
+/// [KeySequence::pending_keys] and [KeySequence::continuations]
+/// are there to show the possible next keys, which-key style.
+///
+/// One key can give more than one result, e.g. the match of
+/// the shorter sequence and the key itself. Only the first is
+/// returned, for the rest [KeySequence::deadline] is now and
+/// [KeySequence::resolve] returns them.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::crossterm::{KeyCombo, KeySequence, SeqResult};
+/// use std::time::{Duration, Instant};
+///
+/// let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+///
+/// let mut seq = KeySequence::new();
+/// seq.bind([KeyCombo::from(ctrl('k')), KeyCombo::from(ctrl('c'))], "comment");
+/// seq.bind([KeyCombo::from(ctrl('k')), KeyCombo::from(ctrl('u'))], "uncomment");
+///
+/// let now = Instant::now();
+/// assert_eq!(seq.feed(&ctrl('k'), now), SeqResult::Pending);
+/// assert_eq!(seq.feed(&ctrl('c'), now), SeqResult::Matched("comment"));
+///
+/// assert_eq!(seq.feed(&ctrl('k'), now), SeqResult::Pending);
+/// assert_eq!(
+///     seq.feed(&ctrl('x'), now),
+///     SeqResult::NoMatch(vec![ctrl('k'), ctrl('x')])
+/// );
+///
+/// // the main loop polls with the deadline as timeout.
+/// assert_eq!(seq.feed(&ctrl('k'), now), SeqResult::Pending);
+/// let deadline = seq.deadline().expect("pending");
+/// assert_eq!(seq.resolve(deadline), SeqResult::NoMatch(vec![ctrl('k')]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeySequence<A> {
+    timeout: Duration,
+    bindings: HashMap<Vec<KeyCombo>, A>,
+    help: HashMap<Vec<KeyCombo>, BindingHelp>,
+    leader: Option<KeyCombo>,
+    /// Bindings without the leader.
+    leader_bindings: HashMap<Vec<KeyCombo>, A>,
+    leader_help: HashMap<Vec<KeyCombo>, BindingHelp>,
+    replay_leader: bool,
+    /// Keys of the incomplete sequence.
+    buffer: Vec<KeyEvent>,
+    /// Time of the last key in the buffer.
+    last: Option<Instant>,
+    /// Keys that still have to be looked at.
+    input: VecDeque<(KeyEvent, Instant)>,
+}
+
+impl<A> Default for KeySequence<A> {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(1),
+            bindings: Default::default(),
+            help: Default::default(),
+            leader: None,
+            leader_bindings: Default::default(),
+            leader_help: Default::default(),
+            replay_leader: true,
+            buffer: Default::default(),
+            last: None,
+            input: Default::default(),
+        }
+    }
+}
+
+impl<A> KeySequence<A> {
+    /// New recognizer with a timeout of 1s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout between two keys of a sequence.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Timeout between two keys of a sequence.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Return the keys of a cancelled leader sequence for the
+    /// regular event-handling? Default is true.
+    ///
+    /// If the leader is a character, e.g. Space, this is what
+    /// you want in a text-input.
+    pub fn replay_leader(mut self, replay: bool) -> Self {
+        self.replay_leader = replay;
+        self
+    }
+
+    /// Return the keys of a cancelled leader sequence?
+    pub fn is_replay_leader(&self) -> bool {
+        self.replay_leader
+    }
+
+    /// Set the leader key.
+    pub fn set_leader(&mut self, leader: KeyCombo) {
+        self.leader = Some(leader);
+    }
+
+    /// Remove the leader key. The leader bindings are kept,
+    /// but can't match.
+    pub fn clear_leader(&mut self) {
+        self.leader = None;
+    }
+
+    /// Leader key.
+    pub fn leader(&self) -> Option<KeyCombo> {
+        self.leader
+    }
+
+    /// Bind a sequence that starts with the leader to an action.
+    /// The leader itself is not part of the keys.
+    ///
+    /// Returns the old action if the sequence was already bound.
+    ///
+    /// Panics if the sequence is empty.
+    pub fn bind_leader(
+        &mut self,
+        keys: impl IntoIterator<Item = KeyCombo>,
+        action: A,
+    ) -> Option<A> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        assert!(!keys.is_empty(), "empty key sequence");
+        self.leader_help.remove(&keys);
+        self.leader_bindings.insert(keys, action)
+    }
+
+    /// Bind a sequence that starts with the leader, with a
+    /// description for the help.
+    ///
+    /// Panics if the sequence is empty.
+    pub fn bind_leader_with(
+        &mut self,
+        keys: impl IntoIterator<Item = KeyCombo>,
+        action: A,
+        help: impl Into<BindingHelp>,
+    ) -> Option<A> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        let old = self.bind_leader(keys.clone(), action);
+        self.leader_help.insert(keys, help.into());
+        old
+    }
+
+    /// Remove a leader binding. Returns the old action.
+    pub fn unbind_leader(&mut self, keys: impl IntoIterator<Item = KeyCombo>) -> Option<A> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        self.leader_help.remove(&keys);
+        self.leader_bindings.remove(&keys)
+    }
+
+    /// All bindings for a help text, with the full sequence.
+    /// Sorted like [KeyMap::entries](crate::crossterm::KeyMap::entries).
+    ///
+    /// Leader bindings are left out if there is no leader.
+    pub fn entries(&self) -> impl Iterator<Item = BindingEntry<'_, A>> {
+        let mut entries = self
+            .bindings
+            .iter()
+            .map(|(keys, action)| BindingEntry::new(keys.clone(), action, self.help.get(keys)))
+            .collect::<Vec<_>>();
+        if let Some(leader) = self.leader {
+            entries.extend(self.leader_bindings.iter().map(|(keys, action)| {
+                let mut full = vec![leader];
+                full.extend_from_slice(keys);
+                BindingEntry::new(full, action, self.leader_help.get(keys))
+            }));
+        }
+        BindingEntry::sort(&mut entries);
+        entries.into_iter()
+    }
+
+    /// Keys of the incomplete sequence.
+    pub fn pending_keys(&self) -> &[KeyEvent] {
+        &self.buffer
+    }
+
+    /// All the sequences that continue the incomplete sequence,
+    /// without the keys typed so far. Sorted.
+    ///
+    /// Without an incomplete sequence these are all bindings.
+    pub fn continuations(&self) -> Vec<(Vec<KeyCombo>, &A)> {
+        let prefix = Self::combos(&self.buffer);
+        let mut cont = self
+            .all()
+            .filter(|(keys, _)| keys.len() > prefix.len() && keys.starts_with(&prefix))
+            .map(|(keys, action)| (keys[prefix.len()..].to_vec(), action))
+            .collect::<Vec<_>>();
+        cont.sort_by(|a, b| a.0.cmp(&b.0));
+        cont
+    }
+
+    /// Bind a sequence to an action. Returns the old action
+    /// if the sequence was already bound.
+    ///
+    /// Panics if the sequence is empty.
+    pub fn bind(&mut self, keys: impl IntoIterator<Item = KeyCombo>, action: A) -> Option<A> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        assert!(!keys.is_empty(), "empty key sequence");
+        self.help.remove(&keys);
+        self.bindings.insert(keys, action)
+    }
+
+    /// Bind a sequence to an action, with a description for
+    /// the help.
+    ///
+    /// Panics if the sequence is empty.
+    pub fn bind_with(
+        &mut self,
+        keys: impl IntoIterator<Item = KeyCombo>,
+        action: A,
+        help: impl Into<BindingHelp>,
+    ) -> Option<A> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        let old = self.bind(keys.clone(), action);
+        self.help.insert(keys, help.into());
+        old
+    }
+
+    /// Remove the binding for a sequence. Returns the old action.
+    pub fn unbind(&mut self, keys: impl IntoIterator<Item = KeyCombo>) -> Option<A> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        self.help.remove(&keys);
+        self.bindings.remove(&keys)
+    }
+
+    /// Is there an incomplete sequence or are there keys that
+    /// still have to be looked at?
+    pub fn is_pending(&self) -> bool {
+        !self.buffer.is_empty() || !self.input.is_empty()
+    }
+
+    /// When [KeySequence::resolve] must be called next.
+    /// Use this as poll timeout.
+    pub fn deadline(&self) -> Option<Instant> {
+        if let Some((_, time)) = self.input.front() {
+            Some(*time)
+        } else if !self.buffer.is_empty() {
+            self.last.map(|v| v + self.timeout)
+        } else {
+            None
+        }
+    }
+
+    /// Cancel the incomplete sequence. Returns the swallowed
+    /// keys and any keys that have not been looked at.
+    pub fn reset(&mut self) -> Vec<KeyEvent> {
+        let mut keys = std::mem::take(&mut self.buffer);
+        keys.extend(self.input.drain(..).map(|(k, _)| k));
+        self.last = None;
+        keys
+    }
+
+    /// Sequences that are the start of a longer one.
+    ///
+    /// This is allowed, but the shorter one only fires after the
+    /// timeout or when the next key doesn't continue the longer one.
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let all = self.all().collect::<Vec<_>>();
+        let mut conflicts = Vec::new();
+        for (keys, _) in &all {
+            for (other, _) in &all {
+                if other.len() > keys.len() && other.starts_with(keys) {
+                    conflicts.push(Conflict {
+                        kind: ConflictKind::Prefix,
+                        binding: keys.clone(),
+                        other: other.clone(),
+                    });
+                }
+            }
+        }
+        conflicts.sort_by(|a, b| (&a.binding, &a.other).cmp(&(&b.binding, &b.other)));
+        conflicts
+    }
+
+    /// All bindings, the leader bindings with the leader.
+    pub(crate) fn all(&self) -> impl Iterator<Item = (Vec<KeyCombo>, &A)> {
+        let leader = self.leader;
+        let plain = self.bindings.iter().map(|(k, a)| (k.clone(), a));
+        let leader = self.leader_bindings.iter().filter_map(move |(k, a)| {
+            leader.map(|leader| {
+                let mut keys = vec![leader];
+                keys.extend_from_slice(k);
+                (keys, a)
+            })
+        });
+        plain.chain(leader)
+    }
+
+    fn get(&self, keys: &[KeyCombo]) -> Option<&A> {
+        if let Some(action) = self.bindings.get(keys) {
+            return Some(action);
+        }
+        match keys.split_first() {
+            Some((first, rest)) if Some(*first) == self.leader => self.leader_bindings.get(rest),
+            _ => None,
+        }
+    }
+
+    fn is_prefix(&self, keys: &[KeyCombo]) -> bool {
+        self.all()
+            .any(|(v, _)| v.len() > keys.len() && v.starts_with(keys))
+    }
+
+    /// Is this a sequence that starts with the leader, and
+    /// should it be dropped?
+    fn drop_leader(&self, keys: &[KeyEvent]) -> bool {
+        match (keys.first(), self.leader) {
+            (Some(first), Some(leader)) => !self.replay_leader && KeyCombo::from(first) == leader,
+            _ => false,
+        }
+    }
+
+    fn combos(keys: &[KeyEvent]) -> Vec<KeyCombo> {
+        keys.iter().map(KeyCombo::from).collect()
+    }
+}
+
+impl<A> KeySequence<A>
+where
+    A: Clone,
+{
+    /// Feed the next key.
+    pub fn feed(&mut self, key: &KeyEvent, now: Instant) -> SeqResult<A> {
+        self.input.push_back((*key, now));
+        self.resolve(now)
+    }
+
+    /// Resolve after the deadline.
+    ///
+    /// Matches or cancels the incomplete sequence if the timeout
+    /// has run out, and looks at the keys that are left over.
+    /// Returns Pending if there is an incomplete sequence, and an
+    /// empty NoMatch if there is nothing to do.
+    pub fn resolve(&mut self, now: Instant) -> SeqResult<A> {
+        if let Some(last) = self.last {
+            if !self.buffer.is_empty() && now.saturating_duration_since(last) >= self.timeout {
+                return self.flush();
+            }
+        }
+
+        while let Some((key, time)) = self.input.pop_front() {
+            if key.kind == KeyEventKind::Release {
+                if self.buffer.is_empty() {
+                    return SeqResult::NoMatch(vec![key]);
+                } else {
+                    continue;
+                }
+            }
+
+            let mut keys = Self::combos(&self.buffer);
+            keys.push(KeyCombo::from(&key));
+
+            if self.is_prefix(&keys) {
+                self.buffer.push(key);
+                self.last = Some(time);
+                continue;
+            }
+            if let Some(action) = self.get(&keys) {
+                let action = action.clone();
+                self.buffer.clear();
+                self.last = None;
+                return SeqResult::Matched(action);
+            }
+            if self.buffer.is_empty() {
+                return SeqResult::NoMatch(vec![key]);
+            }
+            // the key doesn't continue the sequence.
+            let single = [KeyCombo::from(&key)];
+            if self.get(&Self::combos(&self.buffer)).is_some()
+                || self.get(&single).is_some()
+                || self.is_prefix(&single)
+            {
+                // finish the sequence and look at the key again.
+                self.input.push_front((key, time));
+                return self.flush();
+            } else {
+                let mut keys = std::mem::take(&mut self.buffer);
+                self.last = None;
+                if self.drop_leader(&keys) {
+                    keys.clear();
+                }
+                keys.push(key);
+                return SeqResult::NoMatch(keys);
+            }
+        }
+
+        if self.buffer.is_empty() {
+            SeqResult::NoMatch(Vec::new())
+        } else {
+            SeqResult::Pending
+        }
+    }
+
+    /// Matches the buffer as is, or returns the keys.
+    fn flush(&mut self) -> SeqResult<A> {
+        let keys = std::mem::take(&mut self.buffer);
+        self.last = None;
+        match self.get(&Self::combos(&keys)) {
+            Some(action) => SeqResult::Matched(action.clone()),
+            None if self.drop_leader(&keys) => SeqResult::NoMatch(Vec::new()),
+            None => SeqResult::NoMatch(keys),
+        }
+    }
+}
+
+/// Resolves after the deadline, see [KeySequence::resolve].
+///
+/// Returns `Value(Matched(..))` or `Value(NoMatch(..))` if something
+/// is to be done, and Continue otherwise. There may be more left over,
+/// check [KeySequence::deadline] again.
+impl<A> HandleEvent<TimeOut, Tick, OutcomeOf<SeqResult<A>>> for KeySequence<A>
+where
+    A: Clone,
+{
+    fn handle(&mut self, event: &TimeOut, _qualifier: Tick) -> OutcomeOf<SeqResult<A>> {
+        match self.resolve(event.now) {
+            SeqResult::Pending => OutcomeOf::Continue,
+            SeqResult::NoMatch(keys) if keys.is_empty() => OutcomeOf::Continue,
+            r => OutcomeOf::Value(r),
+        }
+    }
+}
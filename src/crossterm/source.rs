@@ -0,0 +1,122 @@
+use crate::crossterm::coalesce::drain;
+use crate::crossterm::coalesce_events;
+use crate::crossterm::event::{self, Event};
+use crate::source::{EventSource, SourceError};
+use std::io;
+use std::time::Duration;
+
+/// Reads the crossterm events in batches.
+///
+/// Waits for the first event and then reads everything that is
+/// queued without blocking. The batch can be handled with
+/// [HandleEvent::handle_all](crate::HandleEvent::handle_all)
+/// and rendered once.
+///
+/// ```rust no_run
+/// use rat_event::crossterm::event::Event;
+/// use rat_event::crossterm::CrosstermSource;
+/// use rat_event::{ct_event, HandleEvent, Outcome, Regular};
+/// use std::time::Duration;
+///
+/// struct App {
+///     quit: bool,
+/// }
+///
+/// impl HandleEvent<Event, Regular, Outcome> for App {
+///     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+///         match event {
+///             ct_event!(key press 'q') => {
+///                 self.quit = true;
+///                 Outcome::Changed
+///             }
+///             _ => Outcome::Continue,
+///         }
+///     }
+/// }
+///
+/// fn render(_app: &App) {}
+///
+/// let mut app = App { quit: false };
+/// let mut source = CrosstermSource::new().coalesce(true);
+///
+/// render(&app);
+/// while !app.quit {
+///     // wake up every second for a tick.
+///     let events = source.next_batch(Some(Duration::from_secs(1)))?;
+///     if events.is_empty() {
+///         // tick ...
+///         continue;
+///     }
+///     if app.handle_all(&events, Regular) == Outcome::Changed {
+///         render(&app);
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// It is an [EventSource] too, that reads single events. This can
+/// be [merged](crate::source::merge) with other sources.
+#[derive(Debug, Default, Clone)]
+pub struct CrosstermSource {
+    coalesce: bool,
+}
+
+impl CrosstermSource {
+    /// New source without coalescing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run [coalesce_events] for each batch.
+    pub fn coalesce(mut self, coalesce: bool) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+
+    /// Runs [coalesce_events] for each batch?
+    pub fn is_coalesce(&self) -> bool {
+        self.coalesce
+    }
+
+    /// Reads the next batch of events.
+    ///
+    /// Waits up to the timeout for the first event, or forever
+    /// if there is no timeout. Returns an empty Vec if the timeout
+    /// runs out.
+    pub fn next_batch(&mut self, timeout: Option<Duration>) -> io::Result<Vec<Event>> {
+        let mut events = Vec::new();
+        match timeout {
+            Some(timeout) => {
+                if event::poll(timeout)? {
+                    events.push(event::read()?);
+                }
+            }
+            None => {
+                events.push(event::read()?);
+            }
+        }
+        if !events.is_empty() {
+            drain(&mut events)?;
+        }
+        if self.coalesce {
+            coalesce_events(&mut events);
+        }
+        Ok(events)
+    }
+}
+
+impl EventSource<Event> for CrosstermSource {
+    /// Reads a single event. There is no coalescing.
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<Event>, SourceError> {
+        match timeout {
+            Some(timeout) => {
+                if event::poll(timeout)? {
+                    Ok(Some(event::read()?))
+                } else {
+                    Ok(None)
+                }
+            }
+            None => Ok(Some(event::read()?)),
+        }
+    }
+}
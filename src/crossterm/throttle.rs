@@ -0,0 +1,104 @@
+use crate::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Throttles auto-repeated keys.
+///
+/// Holding a key down produces a flood of events. This lets the first
+/// one pass and suppresses the same key for the given interval.
+/// The last suppressed event of each key is kept, and the application
+/// can take them with [pending](RepeatThrottle::pending) on the next
+/// tick, so the final state is not lost.
+///
+/// Keys are compared by code and modifiers. Different keys don't
+/// suppress each other. Release events always pass.
+///
+/// ```rust ignore
+/// if let Event::Key(key) = &event {
+///     if !state.throttle.allow(key, Instant::now()) {
+///         return Outcome::Unchanged;
+///     }
+/// }
+/// // ... and on the next tick
+/// while let Some(key) = state.throttle.pending() {
+///     // handle key
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RepeatThrottle {
+    interval: Duration,
+    last: HashMap<(KeyCode, KeyModifiers), Instant>,
+    /// At most one per key, in the order they were first suppressed.
+    pending: Vec<KeyEvent>,
+}
+
+impl RepeatThrottle {
+    /// New throttle with the minimum interval between two
+    /// events for the same key.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: Default::default(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Interval between two events for the same key.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Should this event be handled now?
+    ///
+    /// If this returns false, the event is kept as pending. It
+    /// replaces an earlier pending event of the same key.
+    pub fn allow(&mut self, key: &KeyEvent, now: Instant) -> bool {
+        let id = (key.code, key.modifiers);
+
+        if key.kind == KeyEventKind::Release {
+            self.last.remove(&id);
+            return true;
+        }
+
+        match self.last.get(&id) {
+            Some(last) if now.saturating_duration_since(*last) < self.interval => {
+                match self
+                    .pending
+                    .iter_mut()
+                    .find(|p| (p.code, p.modifiers) == id)
+                {
+                    Some(p) => *p = *key,
+                    None => self.pending.push(*key),
+                }
+                false
+            }
+            _ => {
+                self.last.insert(id, now);
+                self.pending.retain(|p| (p.code, p.modifiers) != id);
+                true
+            }
+        }
+    }
+
+    /// Takes the last suppressed event of one key.
+    ///
+    /// Call it until it returns None to get all of them.
+    pub fn pending(&mut self) -> Option<KeyEvent> {
+        if self.pending.is_empty() {
+            None
+        } else {
+            Some(self.pending.remove(0))
+        }
+    }
+
+    /// Takes the last suppressed event of every key.
+    pub fn drain_pending(&mut self) -> impl Iterator<Item = KeyEvent> + '_ {
+        self.pending.drain(..)
+    }
+
+    /// Forget everything.
+    pub fn clear(&mut self) {
+        self.last.clear();
+        self.pending.clear();
+    }
+}
@@ -0,0 +1,127 @@
+use crate::crossterm::event::{Event, MouseEvent};
+use ratatui::layout::{Position, Rect};
+
+/// Z-ordered areas of popups for routing mouse events.
+///
+/// Each popup adds its area and z-value while rendering. Clear
+/// the registry at the start of each frame. During event-handling
+/// [ZAreas::route_mouse] tells which popup gets a mouse event, and
+/// everything below uses [ZAreas::is_obscured] to ignore mouse
+/// events that belong to a popup above it.
+///
+/// Regular widgets live at z 0. For the same z the area added
+/// later is on top, as it was rendered later.
+///
+/// ```rust
+/// use rat_event::crossterm::event::{Event, MouseEvent, MouseEventKind, MouseButton, KeyModifiers};
+/// use rat_event::crossterm::ZAreas;
+/// use ratatui::layout::Rect;
+///
+/// let click = |column, row| {
+///     Event::Mouse(MouseEvent {
+///         kind: MouseEventKind::Down(MouseButton::Left),
+///         column,
+///         row,
+///         modifiers: KeyModifiers::NONE,
+///     })
+/// };
+///
+/// let mut zareas = ZAreas::new();
+/// zareas.add("dropdown", Rect::new(10, 5, 20, 10), 1);
+/// zareas.add("menu", Rect::new(15, 8, 10, 5), 2);
+///
+/// assert_eq!(zareas.route_mouse(&click(16, 9)), Some(&"menu"));
+/// assert_eq!(zareas.route_mouse(&click(11, 6)), Some(&"dropdown"));
+/// assert_eq!(zareas.route_mouse(&click(0, 0)), None);
+///
+/// let list = Rect::new(0, 0, 40, 20);
+/// assert!(zareas.is_obscured(list, 0, &click(16, 9)));
+/// assert!(!zareas.is_obscured(list, 0, &click(0, 0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZAreas<K> {
+    areas: Vec<(K, Rect, u16)>,
+}
+
+impl<K> Default for ZAreas<K> {
+    fn default() -> Self {
+        Self { areas: Vec::new() }
+    }
+}
+
+impl<K> ZAreas<K> {
+    /// Empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the area of a popup.
+    pub fn add(&mut self, id: K, area: Rect, z: u16) {
+        self.areas.push((id, area, z));
+    }
+
+    /// Remove all areas. Call this before rendering.
+    pub fn clear(&mut self) {
+        self.areas.clear();
+    }
+
+    /// The popup with the highest z at the position.
+    pub fn at(&self, position: Position) -> Option<&K> {
+        // max_by_key returns the last of equal elements.
+        self.areas
+            .iter()
+            .filter(|(_, area, _)| area.contains(position))
+            .max_by_key(|(_, _, z)| *z)
+            .map(|(id, _, _)| id)
+    }
+
+    /// The popup that should get a mouse event.
+    ///
+    /// None for any other event, and for a mouse event outside
+    /// all popups.
+    pub fn route_mouse(&self, event: &Event) -> Option<&K> {
+        self.at(mouse_position(event)?)
+    }
+
+    /// Is the mouse event hidden by a popup above z?
+    ///
+    /// Returns true if the mouse position is inside the given area
+    /// and some popup with a higher z covers it. Event-handlers at
+    /// z should then treat the event as not theirs.
+    ///
+    /// Returns false for any other event.
+    pub fn is_obscured(&self, area: Rect, z: u16, event: &Event) -> bool {
+        let Some(position) = mouse_position(event) else {
+            return false;
+        };
+        area.contains(position)
+            && self
+                .areas
+                .iter()
+                .any(|(_, v, vz)| *vz > z && v.contains(position))
+    }
+
+    /// All areas, bottom-up.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Rect, u16)> {
+        let mut areas = self.areas.iter().collect::<Vec<_>>();
+        areas.sort_by_key(|(_, _, z)| *z);
+        areas.into_iter().map(|(id, area, z)| (id, *area, *z))
+    }
+
+    /// Number of areas.
+    pub fn len(&self) -> usize {
+        self.areas.len()
+    }
+
+    /// No areas.
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+}
+
+fn mouse_position(event: &Event) -> Option<Position> {
+    match event {
+        Event::Mouse(MouseEvent { column, row, .. }) => Some(Position::new(*column, *row)),
+        _ => None,
+    }
+}
@@ -0,0 +1,456 @@
+//!
+//! A small, backend-neutral model for key and mouse events.
+//!
+//! Widgets that implement `HandleEvent<rat_event::event::Event, ..>`
+//! work with any backend that has a conversion into this model.
+//! With the feature `crossterm` there are `From` conversions for the
+//! crossterm events, in both directions.
+//! With the feature `termwiz` the termwiz events convert with a
+//! `rat_event::termwiz::InputConverter`.
+//!
+//! The model only covers what widgets usually need. Anything else
+//! is mapped to [KeyCode::Other] or dropped.
+//!
+//! ```rust
+//! use rat_event::event::{Event, KeyCode, Modifiers};
+//! use rat_event::{HandleEvent, Outcome, Regular};
+//!
+//! struct Counter(u32);
+//!
+//! impl HandleEvent<Event, Regular, Outcome> for Counter {
+//!     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+//!         match event {
+//!             Event::Key(key) if key.is_press() && key.code == KeyCode::Char('+') => {
+//!                 self.0 += 1;
+//!                 Outcome::Changed
+//!             }
+//!             _ => Outcome::Continue,
+//!         }
+//!     }
+//! }
+//!
+//! let mut counter = Counter(0);
+//! let plus = Event::Key(rat_event::event::KeyEvent::new(KeyCode::Char('+'), Modifiers::NONE));
+//! assert_eq!(counter.handle(&plus, Regular), Outcome::Changed);
+//! assert_eq!(counter.0, 1);
+//! ```
+//!
+//! With crossterm the application converts once and calls the same
+//! event-handler:
+//!
+//! ```rust ignore
+//! let event = rat_event::event::Event::from(&crossterm_event);
+//! counter.handle(&event, Regular);
+//! ```
+
+use std::ops::{BitOr, BitOrAssign};
+
+/// Backend-neutral event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// The terminal gained the focus.
+    FocusGained,
+    /// The terminal lost the focus.
+    FocusLost,
+    /// Key event.
+    Key(KeyEvent),
+    /// Mouse event.
+    Mouse(MouseEvent),
+    /// Pasted text.
+    Paste(String),
+    /// New terminal size, columns and rows.
+    Resize(u16, u16),
+}
+
+/// Modifier keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers(0);
+    pub const SHIFT: Modifiers = Modifiers(0b0000_0001);
+    pub const CONTROL: Modifiers = Modifiers(0b0000_0010);
+    pub const ALT: Modifiers = Modifiers(0b0000_0100);
+    pub const SUPER: Modifiers = Modifiers(0b0000_1000);
+    pub const HYPER: Modifiers = Modifiers(0b0001_0000);
+    pub const META: Modifiers = Modifiers(0b0010_0000);
+
+    /// No modifiers.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Contains all the given modifiers.
+    pub fn contains(self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Modifiers(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Key codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyCode {
+    Backspace,
+    Enter,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Tab,
+    BackTab,
+    Delete,
+    Insert,
+    F(u8),
+    Char(char),
+    Esc,
+    /// Any key that is not part of this model.
+    Other,
+}
+
+/// Press, repeat or release.
+///
+/// Repeat and release are only reported with keyboard enhancements,
+/// see [have_keyboard_enhancement](crate::util::have_keyboard_enhancement).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyKind {
+    #[default]
+    Press,
+    Repeat,
+    Release,
+}
+
+/// Key event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub code: KeyCode,
+    pub modifiers: Modifiers,
+    pub kind: KeyKind,
+}
+
+impl KeyEvent {
+    /// New key press.
+    pub fn new(code: KeyCode, modifiers: Modifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            kind: KeyKind::Press,
+        }
+    }
+
+    /// New key event.
+    pub fn new_with_kind(code: KeyCode, modifiers: Modifiers, kind: KeyKind) -> Self {
+        Self {
+            code,
+            modifiers,
+            kind,
+        }
+    }
+
+    /// Key press or repeat.
+    pub fn is_press(&self) -> bool {
+        self.kind != KeyKind::Release
+    }
+}
+
+/// Mouse buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// Kind of mouse event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MouseKind {
+    Down(MouseButton),
+    Up(MouseButton),
+    Drag(MouseButton),
+    Moved,
+    ScrollDown,
+    ScrollUp,
+    ScrollLeft,
+    ScrollRight,
+}
+
+/// Mouse event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseEvent {
+    pub kind: MouseKind,
+    pub column: u16,
+    pub row: u16,
+    pub modifiers: Modifiers,
+}
+
+impl From<&MouseEvent> for MouseEvent {
+    fn from(value: &MouseEvent) -> Self {
+        *value
+    }
+}
+
+#[cfg(feature = "crossterm")]
+mod crossterm {
+    use super::*;
+    use crate::crossterm::event as ct;
+
+    impl From<ct::KeyModifiers> for Modifiers {
+        fn from(value: ct::KeyModifiers) -> Self {
+            let mut m = Modifiers::NONE;
+            for (c, v) in [
+                (ct::KeyModifiers::SHIFT, Modifiers::SHIFT),
+                (ct::KeyModifiers::CONTROL, Modifiers::CONTROL),
+                (ct::KeyModifiers::ALT, Modifiers::ALT),
+                (ct::KeyModifiers::SUPER, Modifiers::SUPER),
+                (ct::KeyModifiers::HYPER, Modifiers::HYPER),
+                (ct::KeyModifiers::META, Modifiers::META),
+            ] {
+                if value.contains(c) {
+                    m |= v;
+                }
+            }
+            m
+        }
+    }
+
+    impl From<ct::KeyCode> for KeyCode {
+        fn from(value: ct::KeyCode) -> Self {
+            match value {
+                ct::KeyCode::Backspace => KeyCode::Backspace,
+                ct::KeyCode::Enter => KeyCode::Enter,
+                ct::KeyCode::Left => KeyCode::Left,
+                ct::KeyCode::Right => KeyCode::Right,
+                ct::KeyCode::Up => KeyCode::Up,
+                ct::KeyCode::Down => KeyCode::Down,
+                ct::KeyCode::Home => KeyCode::Home,
+                ct::KeyCode::End => KeyCode::End,
+                ct::KeyCode::PageUp => KeyCode::PageUp,
+                ct::KeyCode::PageDown => KeyCode::PageDown,
+                ct::KeyCode::Tab => KeyCode::Tab,
+                ct::KeyCode::BackTab => KeyCode::BackTab,
+                ct::KeyCode::Delete => KeyCode::Delete,
+                ct::KeyCode::Insert => KeyCode::Insert,
+                ct::KeyCode::F(n) => KeyCode::F(n),
+                ct::KeyCode::Char(c) => KeyCode::Char(c),
+                ct::KeyCode::Esc => KeyCode::Esc,
+                _ => KeyCode::Other,
+            }
+        }
+    }
+
+    impl From<ct::KeyEventKind> for KeyKind {
+        fn from(value: ct::KeyEventKind) -> Self {
+            match value {
+                ct::KeyEventKind::Press => KeyKind::Press,
+                ct::KeyEventKind::Repeat => KeyKind::Repeat,
+                ct::KeyEventKind::Release => KeyKind::Release,
+            }
+        }
+    }
+
+    impl From<&ct::KeyEvent> for KeyEvent {
+        fn from(value: &ct::KeyEvent) -> Self {
+            Self {
+                code: value.code.into(),
+                modifiers: value.modifiers.into(),
+                kind: value.kind.into(),
+            }
+        }
+    }
+
+    impl From<ct::KeyEvent> for KeyEvent {
+        fn from(value: ct::KeyEvent) -> Self {
+            Self::from(&value)
+        }
+    }
+
+    impl From<ct::MouseButton> for MouseButton {
+        fn from(value: ct::MouseButton) -> Self {
+            match value {
+                ct::MouseButton::Left => MouseButton::Left,
+                ct::MouseButton::Right => MouseButton::Right,
+                ct::MouseButton::Middle => MouseButton::Middle,
+            }
+        }
+    }
+
+    impl From<ct::MouseEventKind> for MouseKind {
+        fn from(value: ct::MouseEventKind) -> Self {
+            match value {
+                ct::MouseEventKind::Down(b) => MouseKind::Down(b.into()),
+                ct::MouseEventKind::Up(b) => MouseKind::Up(b.into()),
+                ct::MouseEventKind::Drag(b) => MouseKind::Drag(b.into()),
+                ct::MouseEventKind::Moved => MouseKind::Moved,
+                ct::MouseEventKind::ScrollDown => MouseKind::ScrollDown,
+                ct::MouseEventKind::ScrollUp => MouseKind::ScrollUp,
+                ct::MouseEventKind::ScrollLeft => MouseKind::ScrollLeft,
+                ct::MouseEventKind::ScrollRight => MouseKind::ScrollRight,
+            }
+        }
+    }
+
+    impl From<&ct::MouseEvent> for MouseEvent {
+        fn from(value: &ct::MouseEvent) -> Self {
+            Self {
+                kind: value.kind.into(),
+                column: value.column,
+                row: value.row,
+                modifiers: value.modifiers.into(),
+            }
+        }
+    }
+
+    impl From<&ct::Event> for Event {
+        fn from(value: &ct::Event) -> Self {
+            match value {
+                ct::Event::FocusGained => Event::FocusGained,
+                ct::Event::FocusLost => Event::FocusLost,
+                ct::Event::Key(v) => Event::Key(v.into()),
+                ct::Event::Mouse(v) => Event::Mouse(v.into()),
+                ct::Event::Paste(v) => Event::Paste(v.clone()),
+                ct::Event::Resize(c, r) => Event::Resize(*c, *r),
+            }
+        }
+    }
+
+    impl From<ct::Event> for Event {
+        fn from(value: ct::Event) -> Self {
+            match value {
+                ct::Event::Paste(v) => Event::Paste(v),
+                v => Event::from(&v),
+            }
+        }
+    }
+
+    impl From<Modifiers> for ct::KeyModifiers {
+        fn from(value: Modifiers) -> Self {
+            let mut m = ct::KeyModifiers::NONE;
+            for (c, v) in [
+                (ct::KeyModifiers::SHIFT, Modifiers::SHIFT),
+                (ct::KeyModifiers::CONTROL, Modifiers::CONTROL),
+                (ct::KeyModifiers::ALT, Modifiers::ALT),
+                (ct::KeyModifiers::SUPER, Modifiers::SUPER),
+                (ct::KeyModifiers::HYPER, Modifiers::HYPER),
+                (ct::KeyModifiers::META, Modifiers::META),
+            ] {
+                if value.contains(v) {
+                    m |= c;
+                }
+            }
+            m
+        }
+    }
+
+    /// [KeyCode::Other] is mapped to `Null`.
+    impl From<KeyCode> for ct::KeyCode {
+        fn from(value: KeyCode) -> Self {
+            match value {
+                KeyCode::Backspace => ct::KeyCode::Backspace,
+                KeyCode::Enter => ct::KeyCode::Enter,
+                KeyCode::Left => ct::KeyCode::Left,
+                KeyCode::Right => ct::KeyCode::Right,
+                KeyCode::Up => ct::KeyCode::Up,
+                KeyCode::Down => ct::KeyCode::Down,
+                KeyCode::Home => ct::KeyCode::Home,
+                KeyCode::End => ct::KeyCode::End,
+                KeyCode::PageUp => ct::KeyCode::PageUp,
+                KeyCode::PageDown => ct::KeyCode::PageDown,
+                KeyCode::Tab => ct::KeyCode::Tab,
+                KeyCode::BackTab => ct::KeyCode::BackTab,
+                KeyCode::Delete => ct::KeyCode::Delete,
+                KeyCode::Insert => ct::KeyCode::Insert,
+                KeyCode::F(n) => ct::KeyCode::F(n),
+                KeyCode::Char(c) => ct::KeyCode::Char(c),
+                KeyCode::Esc => ct::KeyCode::Esc,
+                KeyCode::Other => ct::KeyCode::Null,
+            }
+        }
+    }
+
+    impl From<KeyKind> for ct::KeyEventKind {
+        fn from(value: KeyKind) -> Self {
+            match value {
+                KeyKind::Press => ct::KeyEventKind::Press,
+                KeyKind::Repeat => ct::KeyEventKind::Repeat,
+                KeyKind::Release => ct::KeyEventKind::Release,
+            }
+        }
+    }
+
+    impl From<KeyEvent> for ct::KeyEvent {
+        fn from(value: KeyEvent) -> Self {
+            ct::KeyEvent::new_with_kind(
+                value.code.into(),
+                value.modifiers.into(),
+                value.kind.into(),
+            )
+        }
+    }
+
+    impl From<MouseButton> for ct::MouseButton {
+        fn from(value: MouseButton) -> Self {
+            match value {
+                MouseButton::Left => ct::MouseButton::Left,
+                MouseButton::Right => ct::MouseButton::Right,
+                MouseButton::Middle => ct::MouseButton::Middle,
+            }
+        }
+    }
+
+    impl From<MouseKind> for ct::MouseEventKind {
+        fn from(value: MouseKind) -> Self {
+            match value {
+                MouseKind::Down(b) => ct::MouseEventKind::Down(b.into()),
+                MouseKind::Up(b) => ct::MouseEventKind::Up(b.into()),
+                MouseKind::Drag(b) => ct::MouseEventKind::Drag(b.into()),
+                MouseKind::Moved => ct::MouseEventKind::Moved,
+                MouseKind::ScrollDown => ct::MouseEventKind::ScrollDown,
+                MouseKind::ScrollUp => ct::MouseEventKind::ScrollUp,
+                MouseKind::ScrollLeft => ct::MouseEventKind::ScrollLeft,
+                MouseKind::ScrollRight => ct::MouseEventKind::ScrollRight,
+            }
+        }
+    }
+
+    impl From<MouseEvent> for ct::MouseEvent {
+        fn from(value: MouseEvent) -> Self {
+            ct::MouseEvent {
+                kind: value.kind.into(),
+                column: value.column,
+                row: value.row,
+                modifiers: value.modifiers.into(),
+            }
+        }
+    }
+
+    impl From<Event> for ct::Event {
+        fn from(value: Event) -> Self {
+            match value {
+                Event::FocusGained => ct::Event::FocusGained,
+                Event::FocusLost => ct::Event::FocusLost,
+                Event::Key(v) => ct::Event::Key(v.into()),
+                Event::Mouse(v) => ct::Event::Mouse(v.into()),
+                Event::Paste(v) => ct::Event::Paste(v),
+                Event::Resize(c, r) => ct::Event::Resize(c, r),
+            }
+        }
+    }
+}
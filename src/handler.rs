@@ -0,0 +1,581 @@
+//!
+//! Combinators for event-handlers.
+//!
+//! This allows building event-handlers from other event-handlers
+//! as values, instead of writing the order into every function.
+//!
+
+use crate::{
+    ConsumedEvent, Dialog, DynHandleEvent, HandleEvent, Outcome, PopupZ, ReadOnlyOf, Regular,
+};
+use std::cmp::{max, Reverse};
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/// Extension methods for all event-handlers.
+pub trait HandleEventExt<Event, Qualifier, Return>:
+    HandleEvent<Event, Qualifier, Return> + Sized
+where
+    Return: ConsumedEvent,
+{
+    /// Calls this handler first and the other one only if the event
+    /// has not been consumed.
+    fn chain<B>(self, other: B) -> Chain<Self, B>
+    where
+        B: HandleEvent<Event, Qualifier, Return>,
+    {
+        Chain::new(self, other)
+    }
+
+    /// Maps the result of this handler.
+    ///
+    /// The function must not map a consumed result to one that is
+    /// not consumed. This is checked with a debug assertion.
+    fn map_result<F, R2>(self, f: F) -> MapResult<Self, F, Return>
+    where
+        F: FnMut(Return) -> R2,
+        R2: ConsumedEvent,
+    {
+        MapResult::new(self, f)
+    }
+
+    /// Calls this handler only if the predicate is true for the event.
+    ///
+    /// Otherwise, `R::default()` is returned, see
+    /// [not consumed](ConsumedEvent#not-consumed).
+    fn filter<F>(self, predicate: F) -> Filtered<Self, F>
+    where
+        F: FnMut(&Event) -> bool,
+    {
+        Filtered::new(self, predicate)
+    }
+}
+
+impl<T, E, Q, R> HandleEventExt<E, Q, R> for T
+where
+    T: HandleEvent<E, Q, R>,
+    R: ConsumedEvent,
+{
+}
+
+/// Calls the first handler and the second one only if the event
+/// has not been consumed. Returns the max() of both results.
+///
+/// The first handler gets the qualifier by reference, the second
+/// one by value. `Q: Clone` is only needed if the first handler
+/// implements [DynHandleEvent] through its blanket impl.
+///
+/// ```rust
+/// use rat_event::handler::{HandleEventExt, Nop};
+/// use rat_event::{HandleEvent, Outcome, Regular};
+///
+/// struct Popup;
+/// impl HandleEvent<char, Regular, Outcome> for Popup {
+///     fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+///         if *event == 'p' {
+///             Outcome::Changed
+///         } else {
+///             Outcome::Continue
+///         }
+///     }
+/// }
+///
+/// let mut h = Popup.chain(Nop);
+/// assert_eq!(h.handle(&'p', Regular), Outcome::Changed);
+/// assert_eq!(h.handle(&'x', Regular), Outcome::Continue);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Chain<A, B> {
+    /// First handler.
+    pub first: A,
+    /// Second handler.
+    pub second: B,
+}
+
+impl<A, B> Chain<A, B> {
+    /// New chain.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+/// The first handler gets the qualifier as a reference, the second
+/// one gets it by value. This way a chain doesn't need `Q: Clone`,
+/// see [DynHandleEvent].
+impl<A, B, E, Q, R> HandleEvent<E, Q, R> for Chain<A, B>
+where
+    A: DynHandleEvent<E, Q, R>,
+    B: HandleEvent<E, Q, R>,
+    R: ConsumedEvent + Ord,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        let r = self.first.dyn_handle(event, &qualifier);
+        if r.is_consumed() {
+            r
+        } else {
+            max(r, self.second.handle(event, qualifier))
+        }
+    }
+}
+
+/// Maps the result of an event-handler.
+///
+/// This can be used to normalize the results in a chain of
+/// handlers to a common type.
+///
+/// The function must not map a consumed result to one that is
+/// not consumed. This is checked with a debug assertion.
+///
+/// ```rust
+/// use rat_event::handler::{HandleEventExt, Nop};
+/// use rat_event::{HandleEvent, Outcome, OutcomeOf, Regular};
+///
+/// struct Menu;
+/// impl HandleEvent<char, Regular, OutcomeOf<usize>> for Menu {
+///     fn handle(&mut self, event: &char, _qualifier: Regular) -> OutcomeOf<usize> {
+///         match event {
+///             '1' => OutcomeOf::Value(1),
+///             _ => OutcomeOf::Continue,
+///         }
+///     }
+/// }
+///
+/// let mut h = Menu.map_result(Outcome::from).chain(Nop);
+/// assert_eq!(h.handle(&'1', Regular), Outcome::Changed);
+/// ```
+pub struct MapResult<H, F, R> {
+    /// Event-handler.
+    pub handler: H,
+    /// Mapping.
+    pub map: F,
+    _phantom: PhantomData<fn(R)>,
+}
+
+impl<H, F, R> MapResult<H, F, R> {
+    /// New mapping.
+    pub fn new(handler: H, map: F) -> Self {
+        Self {
+            handler,
+            map,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<H, F, R> Debug for MapResult<H, F, R>
+where
+    H: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapResult")
+            .field("handler", &self.handler)
+            .finish()
+    }
+}
+
+impl<H, F, E, Q, R, R2> HandleEvent<E, Q, R2> for MapResult<H, F, R>
+where
+    H: HandleEvent<E, Q, R>,
+    F: FnMut(R) -> R2,
+    R: ConsumedEvent,
+    R2: ConsumedEvent,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R2 {
+        let r = self.handler.handle(event, qualifier);
+        let consumed = r.is_consumed();
+        let r2 = (self.map)(r);
+        debug_assert!(
+            !consumed || r2.is_consumed(),
+            "map_result() must not map a consumed result to one that is not consumed"
+        );
+        r2
+    }
+}
+
+/// Calls the event-handler only if the predicate is true for the event.
+///
+/// Otherwise, `R::default()` is returned, see
+/// [not consumed](ConsumedEvent#not-consumed).
+///
+/// There are some predicates for crossterm events in [util](crate::util).
+///
+/// ```rust ignore
+/// let mut h = (&mut state.table)
+///     .filter(mouse_in_rect(state.visible_area));
+/// flow!(h.handle(event, Regular));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Filtered<H, F> {
+    /// Event-handler.
+    pub handler: H,
+    /// Predicate.
+    pub predicate: F,
+}
+
+impl<H, F> Filtered<H, F> {
+    /// New filter.
+    pub fn new(handler: H, predicate: F) -> Self {
+        Self { handler, predicate }
+    }
+}
+
+impl<H, F, E, Q, R> HandleEvent<E, Q, R> for Filtered<H, F>
+where
+    H: HandleEvent<E, Q, R>,
+    F: FnMut(&E) -> bool,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        if (self.predicate)(event) {
+            self.handler.handle(event, qualifier)
+        } else {
+            R::default()
+        }
+    }
+}
+
+/// Gives an event-handler the semantics of [Dialog].
+///
+/// While it is active, all events go to the inner [Regular]
+/// event-handler, and _all_ events are consumed. A result that is not
+/// consumed is turned into `Outcome::Unchanged`. Nothing reaches the
+/// widgets below the dialog.
+///
+/// While it is not active it returns `R::default()`, see
+/// [not consumed](ConsumedEvent#not-consumed).
+///
+/// ```rust ignore
+/// dispatch!(event: dialog { state.confirm }, regular { state.table });
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ModalGuard<T> {
+    /// Dialog is active.
+    pub active: bool,
+    /// Event-handler of the dialog.
+    pub inner: T,
+}
+
+impl<T> ModalGuard<T> {
+    /// New inactive guard.
+    pub fn new(inner: T) -> Self {
+        Self {
+            active: false,
+            inner,
+        }
+    }
+
+    /// Activate the dialog.
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    /// Deactivate the dialog.
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    /// Dialog is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl<T, E, R> HandleEvent<E, Dialog, R> for ModalGuard<T>
+where
+    T: HandleEvent<E, Regular, R>,
+    R: ConsumedEvent + Default + From<Outcome>,
+{
+    fn handle(&mut self, event: &E, _qualifier: Dialog) -> R {
+        if !self.active {
+            return R::default();
+        }
+        let r = self.inner.handle(event, Regular);
+        if r.is_consumed() {
+            r
+        } else {
+            Outcome::Unchanged.into()
+        }
+    }
+}
+
+/// Implements [ReadOnly](crate::ReadOnly) with another event-handler.
+///
+/// Events for which `is_edit` returns true are recognized, but not
+/// handled, and return `Outcome::Unchanged`. All other events go
+/// to the event-handler of the wrapped qualifier.
+///
+/// ```rust
+/// use rat_event::handler::read_only;
+/// use rat_event::{HandleEvent, Outcome, ReadOnly, Regular};
+///
+/// struct Text {
+///     text: String,
+///     cursor: usize,
+/// }
+///
+/// impl HandleEvent<char, Regular, Outcome> for Text {
+///     fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+///         match event {
+///             '<' => self.cursor = self.cursor.saturating_sub(1),
+///             '>' => self.cursor = (self.cursor + 1).min(self.text.len()),
+///             c => self.text.insert(self.cursor, *c),
+///         }
+///         Outcome::Changed
+///     }
+/// }
+///
+/// impl HandleEvent<char, ReadOnly, Outcome> for Text {
+///     fn handle(&mut self, event: &char, qualifier: ReadOnly) -> Outcome {
+///         read_only(self, event, qualifier, |c| !matches!(c, '<' | '>'))
+///     }
+/// }
+///
+/// let mut text = Text {
+///     text: "abc".into(),
+///     cursor: 0,
+/// };
+/// assert_eq!(text.handle(&'>', ReadOnly), Outcome::Changed);
+/// assert_eq!(text.handle(&'x', ReadOnly), Outcome::Unchanged);
+/// assert_eq!(text.text, "abc");
+/// assert_eq!(text.cursor, 1);
+/// ```
+pub fn read_only<T, E, Q, R>(
+    state: &mut T,
+    event: &E,
+    qualifier: impl Into<ReadOnlyOf<Q>>,
+    is_edit: impl FnOnce(&E) -> bool,
+) -> R
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent + From<Outcome>,
+{
+    if is_edit(event) {
+        Outcome::Unchanged.into()
+    } else {
+        state.handle(event, qualifier.into().0)
+    }
+}
+
+/// Event-handler that does nothing.
+///
+/// Returns `R::default()`, see [not consumed](ConsumedEvent#not-consumed).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Nop;
+
+impl<E, Q, R> HandleEvent<E, Q, R> for Nop
+where
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, _event: &E, _qualifier: Q) -> R {
+        R::default()
+    }
+}
+
+/// Uses a closure as event-handler.
+///
+/// Construct with [handler_fn].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HandlerFn<F>(pub F);
+
+/// Uses a closure as event-handler.
+///
+/// Together with [Chain] this can add some key-bindings in front
+/// of the regular event-handling of a widget.
+///
+/// ```rust
+/// # #[cfg(feature = "crossterm")]
+/// # {
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::handler::{handler_fn, HandleEventExt};
+/// use rat_event::{ct_event, HandleEvent, Outcome, Regular};
+///
+/// #[derive(Default)]
+/// struct ListState {
+///     selected: usize,
+/// }
+///
+/// impl HandleEvent<Event, Regular, Outcome> for ListState {
+///     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+///         match event {
+///             ct_event!(keycode press Down) => {
+///                 self.selected += 1;
+///                 Outcome::Changed
+///             }
+///             _ => Outcome::Continue,
+///         }
+///     }
+/// }
+///
+/// let mut state = ListState::default();
+/// let mut quit = false;
+/// let mut h = handler_fn(|event: &Event, _qualifier: Regular| match event {
+///     ct_event!(key press CONTROL-'q') => {
+///         quit = true;
+///         Outcome::Unchanged
+///     }
+///     _ => Outcome::Continue,
+/// })
+/// .chain(&mut state);
+///
+/// let down = Event::Key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+/// let ctrl_q = Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+/// assert_eq!(h.handle(&down, Regular), Outcome::Changed);
+/// assert_eq!(h.handle(&ctrl_q, Regular), Outcome::Unchanged);
+/// drop(h);
+///
+/// assert!(quit);
+/// assert_eq!(state.selected, 1);
+/// # }
+/// ```
+pub fn handler_fn<F, E, Q, R>(f: F) -> HandlerFn<F>
+where
+    F: FnMut(&E, Q) -> R,
+    R: ConsumedEvent,
+{
+    HandlerFn(f)
+}
+
+impl<F, E, Q, R> HandleEvent<E, Q, R> for HandlerFn<F>
+where
+    F: FnMut(&E, Q) -> R,
+    R: ConsumedEvent,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        (self.0)(event, qualifier)
+    }
+}
+
+/// Calls the children in reverse order until one of them consumes the event.
+///
+/// Children that are rendered last are on top, and should get the
+/// events first. Otherwise, this works like the implementation of
+/// HandleEvent for slices.
+pub fn handle_rev<T, E, Q, R>(children: &mut [T], event: &E, qualifier: Q) -> R
+where
+    T: DynHandleEvent<E, Q, R>,
+    R: ConsumedEvent + Ord + Default,
+{
+    handle_until_consumed(children.iter_mut().rev(), event, qualifier)
+}
+
+/// Calls the popups from the highest z down until one of them
+/// consumes the event.
+///
+/// Each event-handler gets a [PopupZ] with its z. For the same z the
+/// later one goes first, as it was rendered later. Returns the max() of
+/// all results so far, and the z of the popup that consumed the event.
+/// For a mouse event everything below that z can be skipped.
+///
+/// ```rust
+/// use rat_event::handler::handle_popups;
+/// use rat_event::{HandleEvent, Outcome, PopupZ};
+///
+/// struct Menu {
+///     key: char,
+/// }
+///
+/// impl HandleEvent<char, PopupZ, Outcome> for Menu {
+///     fn handle(&mut self, event: &char, _qualifier: PopupZ) -> Outcome {
+///         if *event == self.key {
+///             Outcome::Changed
+///         } else {
+///             Outcome::Continue
+///         }
+///     }
+/// }
+///
+/// let mut dropdown = Menu { key: 'd' };
+/// let mut context = Menu { key: 'c' };
+///
+/// let (r, z) = handle_popups(&mut [(1, &mut dropdown), (2, &mut context)], &'d');
+/// assert_eq!(r, Outcome::Changed);
+/// assert_eq!(z, Some(1));
+/// ```
+pub fn handle_popups<E, R>(
+    handlers: &mut [(u16, &mut dyn HandleEvent<E, PopupZ, R>)],
+    event: &E,
+) -> (R, Option<u16>)
+where
+    R: ConsumedEvent + Ord + Default,
+{
+    let mut order = (0..handlers.len()).collect::<Vec<_>>();
+    order.sort_by_key(|v| Reverse((handlers[*v].0, *v)));
+
+    let mut r = R::default();
+    for idx in order {
+        let (z, handler) = &mut handlers[idx];
+        let v = handler.handle(event, PopupZ(*z));
+        let consumed = v.is_consumed();
+        r = max(r, v);
+        if consumed {
+            return (r, Some(*z));
+        }
+    }
+    (r, None)
+}
+
+pub(crate) fn handle_until_consumed<'a, T, E, Q, R>(
+    children: impl Iterator<Item = &'a mut T>,
+    event: &E,
+    qualifier: Q,
+) -> R
+where
+    T: DynHandleEvent<E, Q, R> + 'a,
+    R: ConsumedEvent + Ord + Default,
+{
+    let mut r = R::default();
+    for child in children {
+        let v = child.dyn_handle(event, &qualifier);
+        let consumed = v.is_consumed();
+        r = max(r, v);
+        if consumed {
+            break;
+        }
+    }
+    r
+}
+
+macro_rules! impl_tuple {
+    ($($n:tt $t:ident),+) => {
+        /// Calls the event-handlers from left to right until one of them
+        /// consumes the event. Returns the max() of all results so far.
+        impl<$($t,)+ E, Q, R> HandleEvent<E, Q, R> for ($($t,)+)
+        where
+            $($t: DynHandleEvent<E, Q, R>,)+
+            R: ConsumedEvent + Ord + Default,
+        {
+            fn handle(&mut self, event: &E, qualifier: Q) -> R {
+                let mut r = R::default();
+                $(
+                    let v = self.$n.dyn_handle(event, &qualifier);
+                    let consumed = v.is_consumed();
+                    r = max(r, v);
+                    if consumed {
+                        return r;
+                    }
+                )+
+                r
+            }
+        }
+    };
+}
+
+impl_tuple!(0 A, 1 B);
+impl_tuple!(0 A, 1 B, 2 C);
+impl_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 F);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 F, 5 G);
+
+/// Chains any number of event-handlers.
+///
+/// `chain!(a, b, c)` is the same as `Chain::new(a, Chain::new(b, c))`.
+#[macro_export]
+macro_rules! chain {
+    ($a:expr $(,)?) => {
+        $a
+    };
+    ($a:expr, $($rest:expr),+ $(,)?) => {
+        $crate::handler::Chain::new($a, $crate::chain!($($rest),+))
+    };
+}
@@ -1,14 +1,55 @@
 #![doc = include_str!("../readme.md")]
 
-use std::cmp::max;
+use ratatui::layout::{Position, Rect};
+use std::any::Any;
+use std::cell::RefCell;
+use std::cmp::{max, min, Ordering};
+use std::ops::{BitOr, BitOrAssign, ControlFlow, Deref};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+#[cfg(feature = "derive")]
+pub use rat_event_derive::{ConsumedEvent, HandleEvent, OutcomeConvert};
+
+// used by the log/trace arms of the flow macros.
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub use log;
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
+#[cfg(feature = "async")]
+pub mod async_handler;
+#[cfg(feature = "crossterm")]
 pub mod crossterm;
+#[cfg(all(feature = "crossterm_28", feature = "crossterm_29"))]
+compile_error!("The features crossterm_28 and crossterm_29 are mutually exclusive.");
+#[cfg(all(
+    feature = "crossterm",
+    not(any(feature = "crossterm_28", feature = "crossterm_29"))
+))]
+compile_error!("Feature crossterm needs one of crossterm_28 or crossterm_29.");
+pub mod clock;
+pub mod event;
+pub mod handler;
+pub mod source;
+#[cfg(feature = "termwiz")]
+pub mod termwiz;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod util;
+#[cfg(feature = "web")]
+pub mod web;
 
 /// All the regular and expected event-handling a widget can do.
 ///
 /// All the normal key-handling, maybe dependent on an internal
 /// focus-state, all the mouse-handling.
+///
+/// The focus-state can be given from the outside with
+/// `Focused(focus, Regular)`, see [Focused].
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Regular;
 
@@ -31,13 +72,45 @@ pub struct MouseOnly;
 /// * Menubar. Would define _two_ event-handlers, a regular one for all events
 ///   on the main menu bar, and a popup event-handler for the menus. The event-handling
 ///   function calls the popup handler first and the regular one at some time later.
+///
+/// With several overlapping popups the order of the calls is not enough.
+/// [ZAreas](crate::crossterm::ZAreas) records the popup areas during
+/// rendering and tells which popup gets a mouse event.
+///
+/// With explicit z values use [PopupZ] and
+/// [handle_popups](crate::handler::handle_popups).
+///
+/// [PopupCore](crate::crossterm::PopupCore) closes a popup with Esc,
+/// a click outside or the focus loss.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Popup;
 
+/// Like [Popup], with an explicit z value.
+///
+/// Higher z is on top. Regular widgets are at z 0, and
+/// [Popup] is the same as `PopupZ(1)`. The z values are the same
+/// as those used with [ZAreas](crate::crossterm::ZAreas).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PopupZ(pub u16);
+
+impl Default for PopupZ {
+    fn default() -> Self {
+        PopupZ(1)
+    }
+}
+
+impl From<Popup> for PopupZ {
+    fn from(_value: Popup) -> Self {
+        PopupZ::default()
+    }
+}
+
 /// Event-handling for a dialog like widget.
 ///
 /// Similar to [Popup] but with the extra that it consumes _all_ events when active.
 /// No regular widget gets any event, and we have modal behaviour.
+///
+/// Nested dialogs can be kept in a [DialogStack](crate::crossterm::DialogStack).
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Dialog;
 
@@ -53,6 +126,334 @@ pub struct Dialog;
 #[derive(Debug, Default, Clone, Copy)]
 pub struct DoubleClick;
 
+/// Event-handling during a drag-and-drop.
+///
+/// While a [DragDropState](crate::crossterm::DragDropState) is
+/// dragging, the container calls the DragDrop event-handlers of
+/// _all_ widgets that can be a drop target, and then the one of the
+/// DragDropState. A target shows a drop indicator for Moved and Drag
+/// events, and accepts the payload on the mouse-up inside its area.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DragDrop;
+
+/// Event-handling for application-wide hotkeys.
+///
+/// Hotkeys must be processed *before* any other event-handling,
+/// otherwise the focused widget gets the first chance to use the key.
+/// A widget that needs a hotkey for itself, e.g. a text field that
+/// uses Ctrl+Q, must claim it while focused instead.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HotKey;
+
+/// Event-handling for a widget that can't be edited.
+///
+/// Navigation, selection and scrolling work as usual. Any event that
+/// would change the content is still recognized, but returns
+/// `Outcome::Unchanged` instead of changing anything. So the event
+/// doesn't fall through to some other widget.
+///
+/// This is the same as `ReadOnlyOf(Regular)`. Use
+/// [read_only](crate::handler::read_only) to implement it with
+/// the Regular event-handler.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReadOnly;
+
+/// Read-only variant of another qualifier.
+///
+/// Has the semantics of [ReadOnly] for the event-handler of the
+/// wrapped qualifier, e.g. `ReadOnlyOf(MouseOnly)`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReadOnlyOf<Q>(pub Q);
+
+impl From<ReadOnly> for ReadOnlyOf<Regular> {
+    fn from(_value: ReadOnly) -> Self {
+        ReadOnlyOf(Regular)
+    }
+}
+
+/// Another qualifier together with the focus state of the widget.
+///
+/// Instead of keeping a copy of the focus in every widget, the
+/// container passes it along with the qualifier, e.g.
+/// `Focused(self.focus == Focus::List, Regular)`.
+///
+/// The recommended widget skeleton handles the keys only when focused,
+/// and the mouse always:
+///
+/// ```rust
+/// # #[cfg(feature = "crossterm")]
+/// # {
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::{ct_event, flow, Focused, HandleEvent, Outcome, Regular};
+/// use ratatui::layout::Rect;
+///
+/// struct CounterState {
+///     area: Rect,
+///     value: u32,
+/// }
+///
+/// impl<Q> HandleEvent<Event, Focused<Q>, Outcome> for CounterState {
+///     fn handle(&mut self, event: &Event, qualifier: Focused<Q>) -> Outcome {
+///         if qualifier.is_focused() {
+///             flow!(match event {
+///                 ct_event!(keycode press Up) => {
+///                     self.value += 1;
+///                     Outcome::Changed
+///                 }
+///                 _ => Outcome::Continue,
+///             });
+///         }
+///
+///         match event {
+///             ct_event!(mouse down Left for column, row)
+///                 if self.area.contains((*column, *row).into()) =>
+///             {
+///                 self.value += 1;
+///                 Outcome::Changed
+///             }
+///             _ => Outcome::Continue,
+///         }
+///     }
+/// }
+///
+/// let mut counter = CounterState {
+///     area: Rect::new(0, 0, 10, 1),
+///     value: 0,
+/// };
+/// let up = Event::Key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+/// assert_eq!(counter.handle(&up, Focused(false, Regular)), Outcome::Continue);
+/// assert_eq!(counter.handle(&up, Focused(true, Regular)), Outcome::Changed);
+/// # }
+/// ```
+///
+/// A widget that doesn't care about the focus can ignore it. Focused
+/// implements [HasQualifier] for itself and everything the wrapped
+/// qualifier contains, so `Focused(true, (Clipped(area), ReadOnly))`
+/// still has a Clipped and a ReadOnly. The wrapped qualifier must
+/// implement HasQualifier itself, put a type of your own into a tuple.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Focused<Q>(pub bool, pub Q);
+
+impl<Q> Focused<Q> {
+    /// Widget has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.0
+    }
+
+    /// The wrapped qualifier.
+    pub fn qualifier(&self) -> &Q {
+        &self.1
+    }
+}
+
+/// Event-handling for the widget under the mouse.
+///
+/// For tooltips or a status line that shows what's under the mouse.
+/// Hover works for all widgets, not only the focused one.
+///
+/// Convention: A Hover event-handler reacts only to
+/// `MouseEventKind::Moved` and returns `R::default()` for everything
+/// else, it never consumes a click. The result is specific to the
+/// widget, e.g. `OutcomeOf<Option<usize>>` for the hovered item.
+///
+/// Call order: Call the Hover event-handlers of all widgets before
+/// any Popup and Regular event-handlers, and call all of them even if
+/// one consumes the event, e.g. with [broadcast]. The widget the mouse
+/// leaves needs the event as well as the one it enters. A widget below
+/// a popup should check [ZAreas::is_obscured](crate::crossterm::ZAreas::is_obscured)
+/// and drop its hover.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Hover;
+
+/// Event-handling for a widget that is only partly visible.
+///
+/// Inside a scrolled viewport a widget may be rendered only in part,
+/// but its stored areas are the full ones. The Rect is the visible
+/// part in screen coordinates. An event-handler that gets this must
+/// intersect its areas with the clip before any hit-test, e.g. with
+/// [clip_hit](crate::util::clip_hit) or the `*_clipped` functions of
+/// [MouseFlags](crate::util::MouseFlags). Otherwise, it's the same
+/// as [Regular].
+///
+/// The container computes the clip: its own viewport area intersected
+/// with the clip it got itself, if any. It passes `Clipped(clip)` to
+/// all the children that are rendered inside the viewport. The
+/// children do the same for their own children.
+///
+/// ```rust ignore
+/// // a scroll-view inside another scroll-view.
+/// fn handle(&mut self, event: &Event, Clipped(outer): Clipped) -> Outcome {
+///     let clip = self.viewport.intersection(outer);
+///     flow!(self.content.handle(event, Clipped(clip)));
+///     ...
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Clipped(pub Rect);
+
+/// Event-handling for [TimeOut] events.
+///
+/// Cursor blink, auto-dismissed messages and the pending states of
+/// the time-based helpers need a pulse. The application sends a
+/// TimeOut when a deadline has passed, and the event-handlers with
+/// this qualifier do whatever is due at that time.
+///
+/// Convention: A container broadcasts a TimeOut to _all_ its children,
+/// e.g. with [broadcast]. It doesn't stop at the first one that consumes
+/// it, as any number of them may have something to do.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Tick;
+
+/// Time pulse for the [Tick] event-handlers.
+///
+/// The tag can be used to tell timers apart. The time-based helpers
+/// of this crate ignore it and only look at `now`.
+///
+/// The application computes the next deadline from all the helpers
+/// it uses, and uses it as the timeout for polling the next event.
+/// If the poll times out it sends a TimeOut.
+///
+/// ```rust ignore
+/// let deadline = [esc.deadline(), resize.pending_deadline(), keys.deadline()]
+///     .into_iter()
+///     .flatten()
+///     .min();
+/// let timeout = match deadline {
+///     Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+///     None => Duration::from_secs(1),
+/// };
+/// if event::poll(timeout)? {
+///     let event = event::read()?;
+///     // ...
+/// } else {
+///     let timeout = TimeOut::new(Instant::now());
+///     let mut r = Outcome::Continue;
+///     broadcast!(r: esc.handle(&timeout, Tick), resize.handle(&timeout, Tick));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeOut {
+    /// Current time.
+    pub now: Instant,
+    /// Tells timers apart.
+    pub tag: u32,
+}
+
+impl TimeOut {
+    /// New timeout with tag 0.
+    pub fn new(now: Instant) -> Self {
+        Self { now, tag: 0 }
+    }
+
+    /// Timeout with a tag.
+    pub fn tagged(now: Instant, tag: u32) -> Self {
+        Self { now, tag }
+    }
+}
+
+/// Looks for a qualifier of type T in a composite qualifier.
+///
+/// Tuples can be used as qualifiers to pass several independent bits
+/// of context, e.g. `(Clipped(area), ReadOnly)`. An event-handler that
+/// is generic over the qualifier can ask for the parts it knows about,
+/// without knowing the exact composition.
+///
+/// This is implemented for the qualifiers of this crate, which contain
+/// only themselves, for [Focused], which looks into the qualifier it
+/// wraps, and for tuples of up to 3 qualifiers. The parts must
+/// be `'static`, a reference like `&'a KeyMap` can't be found this way.
+///
+/// A tuple only compares its elements with T, it doesn't look into
+/// them. This allows elements of your own types, which don't implement
+/// HasQualifier. A [Focused] or a nested tuple inside a tuple hides
+/// its contents, `(Focused(true, ReadOnly), Clipped(area))` has no
+/// ReadOnly. Put the Focused outside instead,
+/// `Focused(true, (ReadOnly, Clipped(area)))` has both.
+///
+/// ```rust
+/// use rat_event::{Clipped, HasQualifier, ReadOnly, Regular};
+/// use ratatui::layout::Rect;
+///
+/// fn read_only<Q: HasQualifier<ReadOnly>>(qualifier: &Q) -> bool {
+///     qualifier.get().is_some()
+/// }
+///
+/// let clip = Rect::new(0, 0, 10, 10);
+/// assert!(read_only(&(Clipped(clip), ReadOnly)));
+/// assert!(!read_only(&(Clipped(clip), Regular)));
+/// assert!(read_only(&ReadOnly));
+///
+/// let q = (Regular, Clipped(clip));
+/// let found: Option<&Clipped> = q.get();
+/// assert_eq!(found.map(|v| v.0), Some(clip));
+/// ```
+pub trait HasQualifier<T> {
+    /// Returns the qualifier of type T, if there is one.
+    fn get(&self) -> Option<&T>;
+}
+
+macro_rules! impl_has_qualifier {
+    ($($q:ty),+) => {
+        $(
+            impl<T: 'static> HasQualifier<T> for $q {
+                fn get(&self) -> Option<&T> {
+                    (self as &dyn Any).downcast_ref::<T>()
+                }
+            }
+        )+
+    };
+}
+
+impl_has_qualifier!(
+    Regular,
+    MouseOnly,
+    Popup,
+    PopupZ,
+    Dialog,
+    DoubleClick,
+    DragDrop,
+    HotKey,
+    ReadOnly,
+    Hover,
+    Clipped,
+    Tick
+);
+
+impl<T: 'static, Q: 'static> HasQualifier<T> for ReadOnlyOf<Q> {
+    fn get(&self) -> Option<&T> {
+        (self as &dyn Any).downcast_ref::<T>()
+    }
+}
+
+impl<T: 'static, Q: HasQualifier<T> + 'static> HasQualifier<T> for Focused<Q> {
+    fn get(&self) -> Option<&T> {
+        if let Some(v) = (self as &dyn Any).downcast_ref::<T>() {
+            Some(v)
+        } else {
+            self.1.get()
+        }
+    }
+}
+
+macro_rules! impl_has_qualifier_tuple {
+    ($($n:tt $t:ident),+) => {
+        impl<T: 'static, $($t: 'static),+> HasQualifier<T> for ($($t,)+) {
+            fn get(&self) -> Option<&T> {
+                $(
+                if let Some(v) = (&self.$n as &dyn Any).downcast_ref::<T>() {
+                    return Some(v);
+                }
+                )+
+                None
+            }
+        }
+    };
+}
+
+impl_has_qualifier_tuple!(0 A);
+impl_has_qualifier_tuple!(0 A, 1 B);
+impl_has_qualifier_tuple!(0 A, 1 B, 2 C);
+
 ///
 /// A very broad trait for an event handler.
 ///
@@ -67,12 +468,26 @@ pub struct DoubleClick;
 ///
 /// * Event - The actual event type.
 /// * Qualifier - The qualifier allows creating more than one event-handler
-///         for a widget.
+///   for a widget.
 ///
 ///   This can be used as a variant of type-state, where the type given
 ///   selects the widget's behaviour, or to give some external context
 ///   to the widget, or to write your own key-bindings for a widget.
 ///
+///   Two or more independent qualifiers can be combined as a tuple,
+///   e.g. `(Clipped(area), ReadOnly)`. The event-handler can look for
+///   the parts it knows with [HasQualifier]. The adapters of this crate
+///   pass such tuples on unchanged.
+///
+///   The qualifier is passed by value. A qualifier that carries some data,
+///   e.g. a table of hotkeys, should be implemented as a reference
+///   `HandleEvent<Event, &'a HotKeys, R>`. This avoids rebuilding the
+///   qualifier for each call.
+///
+///   The containers (slices, Vec, tuples, [Chain](handler::Chain)) pass
+///   the qualifier to their children as a reference with [DynHandleEvent].
+///   They don't need `Qualifier: Clone`.
+///
 /// * R - Result of event-handling. This can give information to the
 ///   application what changed due to handling the event. This can
 ///   be very specific for each widget, but there is one general [Outcome]
@@ -90,12 +505,92 @@ where
     /// * self - The widget state.
     /// * event - Event type.
     /// * qualifier - Event handling qualifier.
-    ///   This library defines some standard values [Regular], [MouseOnly].
-    ///   Further ideas:
-    ///     * ReadOnly
-    ///     * Special behaviour like DoubleClick, HotKey.
+    ///   This library defines some standard values [Regular], [MouseOnly],
+    ///   [ReadOnly], and some for special behaviour like [DoubleClick],
+    ///   [HotKey], [Hover], [Clipped], [Tick]. [Focused] adds the
+    ///   focus-state to any of them.
     /// * Returns some result, see [Outcome]
     fn handle(&mut self, event: &Event, qualifier: Qualifier) -> Return;
+
+    /// Handle a batch of events.
+    ///
+    /// All events are handled, there is no short-circuit.
+    /// Returns the max() of all results, or `Return::default()` for
+    /// an empty batch.
+    ///
+    /// This is useful if several events are queued up at once,
+    /// e.g. a burst of scroll events. Handling all of them and
+    /// rendering only once avoids lagging behind the input.
+    fn handle_all(&mut self, events: &[Event], qualifier: Qualifier) -> Return
+    where
+        Qualifier: Clone,
+        Return: Ord + Default,
+    {
+        let mut r = Return::default();
+        for event in events {
+            r = max(r, self.handle(event, qualifier.clone()));
+        }
+        r
+    }
+}
+
+/// Object safe variant of [HandleEvent], with a borrowed qualifier.
+///
+/// The qualifier is given as a reference, and cloned for the call
+/// to [HandleEvent::handle]. This is implemented for everything that
+/// implements HandleEvent with a qualifier that is Clone. The zero-sized
+/// qualifiers of this crate are Copy, so they can be passed by value with
+/// `handle(event, Regular)` or by reference with `dyn_handle(event, &Regular)`.
+///
+/// This allows to store heterogeneous widget states as
+/// `Box<dyn DynHandleEvent<..>>`, see [util::dispatch].
+///
+/// The containers call their children with `dyn_handle()`. For a
+/// qualifier that is not Clone, implement DynHandleEvent directly,
+/// and the containers pass the qualifier on without cloning it.
+/// This works for the children themselves, not for a `&mut` to them.
+/// A [Chain](handler::Chain) gives the qualifier to its last handler
+/// by value, which needs a HandleEvent too.
+///
+/// ```rust
+/// use rat_event::{DynHandleEvent, HandleEvent, Outcome};
+///
+/// /// Not Clone.
+/// struct HotKeys(Vec<char>);
+///
+/// struct Button(char);
+///
+/// impl DynHandleEvent<char, HotKeys, Outcome> for Button {
+///     fn dyn_handle(&mut self, event: &char, qualifier: &HotKeys) -> Outcome {
+///         if *event == self.0 && qualifier.0.contains(event) {
+///             Outcome::Changed
+///         } else {
+///             Outcome::Continue
+///         }
+///     }
+/// }
+///
+/// let mut buttons = vec![Button('a'), Button('b')];
+/// let r = buttons.handle(&'b', HotKeys(vec!['a', 'b']));
+/// assert_eq!(r, Outcome::Changed);
+/// ```
+pub trait DynHandleEvent<Event, Qualifier, Return>
+where
+    Return: ConsumedEvent,
+{
+    /// Handle an event.
+    fn dyn_handle(&mut self, event: &Event, qualifier: &Qualifier) -> Return;
+}
+
+impl<T, E, Q, R> DynHandleEvent<E, Q, R> for T
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    Q: Clone,
+    R: ConsumedEvent,
+{
+    fn dyn_handle(&mut self, event: &E, qualifier: &Q) -> R {
+        self.handle(event, qualifier.clone())
+    }
 }
 
 /// Catch all event-handler for the null state `()`.
@@ -105,10 +600,154 @@ impl<E, Q> HandleEvent<E, Q, Outcome> for () {
     }
 }
 
+/// Delegates to the referenced value.
+///
+/// This allows generic functions that take a `H: HandleEvent`
+/// to work with split borrows of some larger state.
+impl<T, E, Q, R> HandleEvent<E, Q, R> for &mut T
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        (**self).handle(event, qualifier)
+    }
+}
+
+/// Delegates to the value, if any.
+///
+/// `None` returns `R::default()`, see [not consumed](ConsumedEvent#not-consumed).
+impl<T, E, Q, R> HandleEvent<E, Q, R> for Option<T>
+where
+    T: HandleEvent<E, Q, R>,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        match self {
+            Some(v) => v.handle(event, qualifier),
+            None => R::default(),
+        }
+    }
+}
+
+/// Delegates to the boxed value.
+impl<T, E, Q, R> HandleEvent<E, Q, R> for Box<T>
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        (**self).handle(event, qualifier)
+    }
+}
+
+/// Delegates to the value.
+impl<T, E, Q, R> HandleEvent<E, Q, R> for RefCell<T>
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        self.get_mut().handle(event, qualifier)
+    }
+}
+
+/// Delegates to the shared value.
+///
+/// __Caveat__
+/// If the value is already borrowed somewhere else, e.g. by a parent
+/// that is currently handling the event, this doesn't panic but
+/// returns `R::default()`, see [not consumed](ConsumedEvent#not-consumed).
+impl<T, E, Q, R> HandleEvent<E, Q, R> for Rc<RefCell<T>>
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        match self.try_borrow_mut() {
+            Ok(mut v) => v.handle(event, qualifier),
+            Err(_) => R::default(),
+        }
+    }
+}
+
+/// Delegates to the value.
+///
+/// A poisoned mutex returns `R::default()`, see
+/// [not consumed](ConsumedEvent#not-consumed).
+impl<T, E, Q, R> HandleEvent<E, Q, R> for Mutex<T>
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        match self.get_mut() {
+            Ok(v) => v.handle(event, qualifier),
+            Err(_) => R::default(),
+        }
+    }
+}
+
+/// Delegates to the shared value.
+///
+/// This locks the mutex and blocks until it's available.
+/// A poisoned mutex returns `R::default()`, see
+/// [not consumed](ConsumedEvent#not-consumed).
+impl<T, E, Q, R> HandleEvent<E, Q, R> for Arc<Mutex<T>>
+where
+    T: HandleEvent<E, Q, R> + ?Sized,
+    R: ConsumedEvent + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        match self.lock() {
+            Ok(mut v) => v.handle(event, qualifier),
+            Err(_) => R::default(),
+        }
+    }
+}
+
+/// Calls the children in order until one of them consumes the event.
+///
+/// Returns the max() of all results so far, which is the first
+/// consumed result for [Outcome]. If no child consumes the event,
+/// the max() of all the not consumed results is returned, or
+/// `R::default()` for an empty slice.
+///
+/// See [handler::handle_rev] for the reverse order.
+impl<T, E, Q, R> HandleEvent<E, Q, R> for [T]
+where
+    T: DynHandleEvent<E, Q, R>,
+    R: ConsumedEvent + Ord + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        handler::handle_until_consumed(self.iter_mut(), event, qualifier)
+    }
+}
+
+/// Calls the children in order until one of them consumes the event.
+///
+/// See the implementation for slices.
+impl<T, E, Q, R> HandleEvent<E, Q, R> for Vec<T>
+where
+    T: DynHandleEvent<E, Q, R>,
+    R: ConsumedEvent + Ord + Default,
+{
+    fn handle(&mut self, event: &E, qualifier: Q) -> R {
+        self.as_mut_slice().handle(event, qualifier)
+    }
+}
+
 /// When calling multiple event-handlers, the minimum information required
 /// from the result is consumed the event/didn't consume the event.
 ///
 /// See also [flow] and [try_flow] macros.
+///
+/// ## Not consumed
+///
+/// Generic code that has no handler to call, e.g. for a `None` or an
+/// empty list, returns `R::default()`. This only works if `Default` is
+/// the value that is not consumed, as is the case for [Outcome] and
+/// [OutcomeOf]. Result types used with such code must follow this.
 pub trait ConsumedEvent {
     /// Is this the 'consumed' result.
     fn is_consumed(&self) -> bool;
@@ -141,6 +780,52 @@ pub trait ConsumedEvent {
         }
     }
 
+    /// Map the result if it has been consumed.
+    ///
+    /// Leaves the value as is if it has not been consumed.
+    ///
+    /// ```rust
+    /// use rat_event::{ConsumedEvent, Outcome};
+    ///
+    /// # struct Field;
+    /// # impl Field { fn handle_focus(&mut self) -> Outcome { Outcome::Unchanged } }
+    /// # let mut field = Field;
+    /// // A focus change always requires a render,
+    /// // even if the widget itself didn't change.
+    /// let r = field.handle_focus().map_consumed(|v| match v {
+    ///     Outcome::Unchanged => Outcome::Changed,
+    ///     v => v,
+    /// });
+    /// assert_eq!(r, Outcome::Changed);
+    /// ```
+    #[inline(always)]
+    fn map_consumed<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> Self,
+    {
+        if self.is_consumed() {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Run some side effect if the result has been consumed.
+    ///
+    /// Returns self unchanged.
+    #[inline(always)]
+    fn on_consumed<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(&Self),
+    {
+        if self.is_consumed() {
+            f(&self);
+        }
+        self
+    }
+
     /// Then-chaining. Returns max(self, f()).
     #[inline(always)]
     fn and<F>(self, f: F) -> Self
@@ -162,6 +847,29 @@ pub trait ConsumedEvent {
     }
 }
 
+/// Allows inspecting a result behind a reference.
+///
+/// The chaining functions need `Self: Sized` and an owned value, they
+/// make no sense here.
+impl<T> ConsumedEvent for &T
+where
+    T: ConsumedEvent + ?Sized,
+{
+    fn is_consumed(&self) -> bool {
+        (**self).is_consumed()
+    }
+}
+
+/// Allows inspecting a result behind a reference.
+impl<T> ConsumedEvent for &mut T
+where
+    T: ConsumedEvent + ?Sized,
+{
+    fn is_consumed(&self) -> bool {
+        (**self).is_consumed()
+    }
+}
+
 impl<V, E> ConsumedEvent for Result<V, E>
 where
     V: ConsumedEvent,
@@ -174,42 +882,956 @@ where
     }
 }
 
-/// The baseline outcome for an event-handler.
+/// `Break` is always consumed, `Continue` delegates to the value.
 ///
-/// A widget can define its own type, if it has more things to report.
-/// It would be nice if those types are convertible to/from Outcome.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Outcome {
-    /// The given event has not been used at all.
-    Continue,
-    /// The event has been recognized, but nothing noticeable has changed.
-    /// Further processing for this event may stop.
-    /// Rendering the ui is not necessary.
-    Unchanged,
-    /// The event has been recognized and there is some change due to it.
-    /// Further processing for this event may stop.
-    /// Rendering the ui is advised.
-    Changed,
+/// This allows an application to use `ControlFlow` as the result
+/// of its event-handling and still use [flow] and friends.
+///
+/// ```rust
+/// use rat_event::{flow, ConsumedEvent, Outcome};
+/// use std::ops::ControlFlow;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum DialogOutcome {
+///     Continue,
+///     Changed,
+///     Quit,
+/// }
+///
+/// struct Dialog;
+///
+/// impl Dialog {
+///     fn handle(&mut self, event: &char) -> DialogOutcome {
+///         match event {
+///             'q' => DialogOutcome::Quit,
+///             'x' => DialogOutcome::Changed,
+///             _ => DialogOutcome::Continue,
+///         }
+///     }
+/// }
+///
+/// fn handle_event(dialog: &mut Dialog, event: &char) -> ControlFlow<(), Outcome> {
+///     flow!(match dialog.handle(event) {
+///         DialogOutcome::Quit => ControlFlow::Break(()),
+///         DialogOutcome::Changed => ControlFlow::Continue(Outcome::Changed),
+///         DialogOutcome::Continue => ControlFlow::Continue(Outcome::Continue),
+///     });
+///     // ... more handlers
+///     Outcome::Continue.into()
+/// }
+///
+/// let mut dialog = Dialog;
+/// let mut renders = 0;
+/// for event in ['a', 'x', 'q', 'x'] {
+///     match handle_event(&mut dialog, &event) {
+///         ControlFlow::Break(_) => break,
+///         ControlFlow::Continue(r) => {
+///             if r.needs_render() {
+///                 renders += 1;
+///             }
+///         }
+///     }
+/// }
+/// assert_eq!(renders, 1);
+/// ```
+impl<B, C> ConsumedEvent for ControlFlow<B, C>
+where
+    C: ConsumedEvent,
+{
+    fn is_consumed(&self) -> bool {
+        match self {
+            ControlFlow::Continue(v) => v.is_consumed(),
+            ControlFlow::Break(_) => true,
+        }
+    }
 }
 
-impl ConsumedEvent for Outcome {
+/// A pair of results is consumed if either of them is consumed.
+///
+/// This is useful if a handler produces two results, e.g. the
+/// outcome of the widget and the outcome of some focus-handling.
+///
+/// See [MaxInto] to combine both into one value.
+impl<A, B> ConsumedEvent for (A, B)
+where
+    A: ConsumedEvent,
+    B: ConsumedEvent,
+{
     fn is_consumed(&self) -> bool {
-        *self != Outcome::Continue
+        self.0.is_consumed() || self.1.is_consumed()
     }
 }
 
-/// Widgets often define functions that return bool to indicate a changed state.
-/// This converts `true` / `false` to `Outcome::Changed` / `Outcome::Unchanged`.
-impl From<bool> for Outcome {
-    fn from(value: bool) -> Self {
-        if value {
-            Outcome::Changed
+/// Folds a pair of results into one value.
+///
+/// Both are converted to the target type and the max() of both is returned.
+///
+/// ```rust
+/// use rat_event::{MaxInto, Outcome, OutcomeOf};
+///
+/// let r: Outcome = (OutcomeOf::Value(1), Outcome::Unchanged).max_into();
+/// assert_eq!(r, Outcome::Changed);
+/// ```
+pub trait MaxInto<O> {
+    /// Convert both and return the max().
+    fn max_into(self) -> O;
+}
+
+impl<A, B, O> MaxInto<O> for (A, B)
+where
+    A: Into<O>,
+    B: Into<O>,
+    O: Ord,
+{
+    fn max_into(self) -> O {
+        max(self.0.into(), self.1.into())
+    }
+}
+
+/// Wrapper for a Result where an `Err` is *not* considered as consumed.
+///
+/// The plain `Result` treats every error as consumed, which stops
+/// the event-handling. If an error should be propagated but other
+/// handlers should still see the event, use this instead.
+///
+/// See also `try_flow!(fallible err: ...)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fallible<R, E>(pub Result<R, E>);
+
+impl<R, E> Fallible<R, E> {
+    /// Returns the Result.
+    pub fn into_inner(self) -> Result<R, E> {
+        self.0
+    }
+}
+
+impl<R, E> From<Result<R, E>> for Fallible<R, E> {
+    fn from(value: Result<R, E>) -> Self {
+        Self(value)
+    }
+}
+
+impl<R, E> ConsumedEvent for Fallible<R, E>
+where
+    R: ConsumedEvent,
+{
+    fn is_consumed(&self) -> bool {
+        match &self.0 {
+            Ok(v) => v.is_consumed(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Wrapper that is always consumed.
+///
+/// Can be used for values that don't implement [ConsumedEvent]
+/// to use them with [flow] and friends.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Consumed<T>(pub T);
+
+impl<T> Consumed<T> {
+    /// Returns the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Consumed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> ConsumedEvent for Consumed<T> {
+    fn is_consumed(&self) -> bool {
+        true
+    }
+}
+
+/// Wrapper that is never consumed.
+///
+/// Can be used for values that don't implement [ConsumedEvent]
+/// to use them with [flow] and friends.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ignored<T>(pub T);
+
+impl<T> Ignored<T> {
+    /// Returns the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Ignored<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> ConsumedEvent for Ignored<T> {
+    fn is_consumed(&self) -> bool {
+        false
+    }
+}
+
+/// Wrapper that is consumed depending on a flag.
+///
+/// This is the runtime version of [Consumed] and [Ignored].
+///
+/// ```rust
+/// use rat_event::{ConsumedEvent, MaybeConsumed};
+///
+/// let r = MaybeConsumed::new("some text".to_string(), true);
+/// assert!(r.is_consumed());
+/// assert_eq!(r.len(), 9);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaybeConsumed<T> {
+    /// The value.
+    pub value: T,
+    /// Is this consumed.
+    pub consumed: bool,
+}
+
+impl<T> MaybeConsumed<T> {
+    /// New wrapper.
+    pub fn new(value: T, consumed: bool) -> Self {
+        Self { value, consumed }
+    }
+
+    /// Returns the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for MaybeConsumed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> ConsumedEvent for MaybeConsumed<T> {
+    fn is_consumed(&self) -> bool {
+        self.consumed
+    }
+}
+
+impl<T> From<Consumed<T>> for MaybeConsumed<T> {
+    fn from(value: Consumed<T>) -> Self {
+        Self::new(value.0, true)
+    }
+}
+
+impl<T> From<Ignored<T>> for MaybeConsumed<T> {
+    fn from(value: Ignored<T>) -> Self {
+        Self::new(value.0, false)
+    }
+}
+
+/// The baseline outcome for an event-handler.
+///
+/// A widget can define its own type, if it has more things to report.
+/// It would be nice if those types are convertible to/from Outcome.
+///
+/// The default is `Outcome::Continue`. Widget defined types should
+/// follow this, some generic code relies on it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    /// The given event has not been used at all.
+    #[default]
+    Continue,
+    /// The event has been recognized, but nothing noticeable has changed.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is not necessary.
+    Unchanged,
+    /// The event has been recognized and there is some change due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+}
+
+impl Outcome {
+    /// The event has not been used.
+    pub fn is_continue(&self) -> bool {
+        *self == Outcome::Continue
+    }
+
+    /// The event has been used, but nothing changed.
+    pub fn is_unchanged(&self) -> bool {
+        *self == Outcome::Unchanged
+    }
+
+    /// The event has been used and something changed.
+    pub fn is_changed(&self) -> bool {
+        *self == Outcome::Changed
+    }
+
+    /// Should the ui be rendered?
+    ///
+    /// Same as [Outcome::is_changed].
+    pub fn needs_render(&self) -> bool {
+        self.is_changed()
+    }
+
+    /// Outcome from two flags.
+    ///
+    /// * recognized - Has the event been recognized at all.
+    /// * changed - Did something change.
+    ///
+    /// Instead of
+    ///
+    /// ```rust
+    /// # use rat_event::Outcome;
+    /// # let (recognized, changed) = (true, false);
+    /// let r = match (recognized, changed) {
+    ///     (false, _) => Outcome::Continue,
+    ///     (true, false) => Outcome::Unchanged,
+    ///     (true, true) => Outcome::Changed,
+    /// };
+    /// # assert_eq!(r, Outcome::Unchanged);
+    /// ```
+    ///
+    /// write
+    ///
+    /// ```rust
+    /// # use rat_event::Outcome;
+    /// # let (recognized, changed) = (true, false);
+    /// let r = Outcome::changed_if(recognized, changed);
+    /// # assert_eq!(r, Outcome::Unchanged);
+    /// ```
+    pub fn changed_if(recognized: bool, changed: bool) -> Outcome {
+        match (recognized, changed) {
+            (false, _) => Outcome::Continue,
+            (true, false) => Outcome::Unchanged,
+            (true, true) => Outcome::Changed,
+        }
+    }
+
+    /// Converts to a ControlFlow. Breaks if the predicate is true.
+    ///
+    /// ```rust
+    /// use rat_event::Outcome;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let quit = true;
+    /// let r = Outcome::Changed.continue_or_break(|_| quit);
+    /// assert_eq!(r, ControlFlow::Break(()));
+    /// ```
+    pub fn continue_or_break<F>(self, pred: F) -> ControlFlow<(), Outcome>
+    where
+        F: FnOnce(Outcome) -> bool,
+    {
+        if pred(self) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(self)
+        }
+    }
+}
+
+impl ConsumedEvent for Outcome {
+    fn is_consumed(&self) -> bool {
+        *self != Outcome::Continue
+    }
+}
+
+/// Combines two outcomes. The result is the max() of both.
+///
+/// Widget defined outcomes should implement this too, with the same semantics.
+///
+/// ```rust
+/// use rat_event::Outcome;
+///
+/// let mut r = Outcome::Continue;
+/// r |= Outcome::Changed;
+/// r |= Outcome::Unchanged;
+/// assert_eq!(r, Outcome::Changed);
+/// ```
+impl BitOr for Outcome {
+    type Output = Outcome;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        max(self, rhs)
+    }
+}
+
+impl BitOrAssign for Outcome {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = max(*self, rhs);
+    }
+}
+
+/// Conversion of widget defined outcomes to the baseline [Outcome].
+///
+/// This is implemented for everything that is `Into<Outcome>`,
+/// and is mostly useful as a bound for generic code.
+///
+/// See also [util::assert_outcome_compatible].
+pub trait IntoOutcome {
+    /// Convert to the baseline Outcome.
+    fn into_outcome(self) -> Outcome;
+}
+
+impl<T> IntoOutcome for T
+where
+    T: Into<Outcome>,
+{
+    fn into_outcome(self) -> Outcome {
+        self.into()
+    }
+}
+
+/// Widgets often define functions that return bool to indicate a changed state.
+/// This converts `true` / `false` to `Outcome::Changed` / `Outcome::Unchanged`.
+impl From<bool> for Outcome {
+    fn from(value: bool) -> Self {
+        if value {
+            Outcome::Changed
         } else {
             Outcome::Unchanged
         }
     }
 }
 
+/// Always converts to `ControlFlow::Continue`.
+impl From<Outcome> for ControlFlow<(), Outcome> {
+    fn from(value: Outcome) -> Self {
+        ControlFlow::Continue(value)
+    }
+}
+
+/// Converts `None` to `Outcome::Continue` and
+/// `Some(true)` / `Some(false)` to `Outcome::Changed` / `Outcome::Unchanged`.
+///
+/// Instead of
+///
+/// ```rust
+/// # use rat_event::Outcome;
+/// # fn move_down() -> Option<bool> { Some(true) }
+/// let r = match move_down() {
+///     None => Outcome::Continue,
+///     Some(true) => Outcome::Changed,
+///     Some(false) => Outcome::Unchanged,
+/// };
+/// # assert_eq!(r, Outcome::Changed);
+/// ```
+///
+/// write
+///
+/// ```rust
+/// # use rat_event::Outcome;
+/// # fn move_down() -> Option<bool> { Some(true) }
+/// let r: Outcome = move_down().into();
+/// # assert_eq!(r, Outcome::Changed);
+/// ```
+impl From<Option<bool>> for Outcome {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            None => Outcome::Continue,
+            Some(v) => v.into(),
+        }
+    }
+}
+
+/// Combines a pair of results. Returns the max() of both.
+///
+/// This allows `flow!((widget_result, focus_result))`.
+impl<A, B> From<(A, B)> for Outcome
+where
+    A: Into<Outcome>,
+    B: Into<Outcome>,
+{
+    fn from(value: (A, B)) -> Self {
+        value.max_into()
+    }
+}
+
+/// Converts to `true` if the result is `Outcome::Changed`.
+///
+/// This is the 'should I render' question of the event-loop.
+impl From<Outcome> for bool {
+    fn from(value: Outcome) -> Self {
+        value == Outcome::Changed
+    }
+}
+
+/// An outcome that can carry some payload.
+///
+/// Widgets often want to report a value along with the change,
+/// e.g. the new selection or the activated item.
+///
+/// Ordering looks at the variant first, and `Value(_)` sorts above `Changed`.
+/// Two `Value`s are ordered by their payload, so max() and
+/// [ConsumedEvent::and] keep the larger value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutcomeOf<T> {
+    /// The given event has not been used at all.
+    #[default]
+    Continue,
+    /// The event has been recognized, but nothing noticeable has changed.
+    Unchanged,
+    /// The event has been recognized and there is some change due to it.
+    Changed,
+    /// The event has been recognized and resulted in some value.
+    /// Implies a change.
+    Value(T),
+}
+
+impl<T> OutcomeOf<T> {
+    fn rank(&self) -> u8 {
+        match self {
+            OutcomeOf::Continue => 0,
+            OutcomeOf::Unchanged => 1,
+            OutcomeOf::Changed => 2,
+            OutcomeOf::Value(_) => 3,
+        }
+    }
+
+    /// Returns the payload, if any.
+    pub fn value(self) -> Option<T> {
+        match self {
+            OutcomeOf::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> ConsumedEvent for OutcomeOf<T> {
+    fn is_consumed(&self) -> bool {
+        !matches!(self, OutcomeOf::Continue)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for OutcomeOf<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (OutcomeOf::Value(a), OutcomeOf::Value(b)) => a.partial_cmp(b),
+            _ => Some(self.rank().cmp(&other.rank())),
+        }
+    }
+}
+
+impl<T: Ord> Ord for OutcomeOf<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (OutcomeOf::Value(a), OutcomeOf::Value(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl<T> From<Outcome> for OutcomeOf<T> {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => OutcomeOf::Continue,
+            Outcome::Unchanged => OutcomeOf::Unchanged,
+            Outcome::Changed => OutcomeOf::Changed,
+        }
+    }
+}
+
+/// Combines two outcomes. The result is the max() of both.
+impl<T: Ord> BitOr for OutcomeOf<T> {
+    type Output = OutcomeOf<T>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        max(self, rhs)
+    }
+}
+
+impl<T: Ord> BitOrAssign for OutcomeOf<T> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        if rhs >= *self {
+            *self = rhs;
+        }
+    }
+}
+
+/// Drops the payload. A `Value` is converted to `Outcome::Changed`.
+impl<T> From<OutcomeOf<T>> for Outcome {
+    fn from(value: OutcomeOf<T>) -> Self {
+        match value {
+            OutcomeOf::Continue => Outcome::Continue,
+            OutcomeOf::Unchanged => Outcome::Unchanged,
+            OutcomeOf::Changed => Outcome::Changed,
+            OutcomeOf::Value(_) => Outcome::Changed,
+        }
+    }
+}
+
+/// Direction for [Navigation::Leave].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// An outcome for widgets that live in a container which moves the
+/// focus around, e.g. a form or split panes.
+///
+/// When Tab, Enter or some arrow key hits the edge of the widget,
+/// it can't do anything with it anymore, but the container can. The
+/// widget returns one of the navigation variants, and the container
+/// moves the focus accordingly.
+///
+/// Ordering: `Continue < Unchanged < Changed < FocusNext < FocusPrev < Leave(_)`.
+/// A navigation request outranks everything else, as it is the one
+/// thing the container must not lose when combining results. The order
+/// between the navigation requests is just the order of declaration,
+/// with `Up < Down < Left < Right` for Leave. It makes max() and `|`
+/// independent of the order of the results. The navigation requests
+/// convert to `Outcome::Changed`, a focus change always needs a render.
+///
+/// ```rust
+/// use rat_event::{Direction, Navigation, Outcome};
+///
+/// struct Form {
+///     focus: usize,
+///     fields: usize,
+/// }
+///
+/// impl Form {
+///     fn navigate(&mut self, r: Navigation) -> Outcome {
+///         match r {
+///             Navigation::FocusNext | Navigation::Leave(Direction::Down) => {
+///                 self.focus = (self.focus + 1) % self.fields;
+///                 Outcome::Changed
+///             }
+///             Navigation::FocusPrev | Navigation::Leave(Direction::Up) => {
+///                 self.focus = (self.focus + self.fields - 1) % self.fields;
+///                 Outcome::Changed
+///             }
+///             r => r.into(),
+///         }
+///     }
+/// }
+///
+/// let mut form = Form { focus: 0, fields: 3 };
+/// assert_eq!(form.navigate(Navigation::FocusPrev), Outcome::Changed);
+/// assert_eq!(form.focus, 2);
+/// assert_eq!(form.navigate(Navigation::Leave(Direction::Down)), Outcome::Changed);
+/// assert_eq!(form.focus, 0);
+/// assert_eq!(form.navigate(Navigation::Continue), Outcome::Continue);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Navigation {
+    /// The given event has not been used at all.
+    #[default]
+    Continue,
+    /// The event has been recognized, but nothing noticeable has changed.
+    Unchanged,
+    /// The event has been recognized and there is some change due to it.
+    Changed,
+    /// Move the focus to the next widget.
+    FocusNext,
+    /// Move the focus to the previous widget.
+    FocusPrev,
+    /// Leave the widget in the given direction.
+    Leave(Direction),
+}
+
+impl Navigation {
+    /// Is this a request to move the focus?
+    pub fn is_navigation(&self) -> bool {
+        matches!(
+            self,
+            Navigation::FocusNext | Navigation::FocusPrev | Navigation::Leave(_)
+        )
+    }
+}
+
+impl ConsumedEvent for Navigation {
+    fn is_consumed(&self) -> bool {
+        !matches!(self, Navigation::Continue)
+    }
+}
+
+impl From<Outcome> for Navigation {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => Navigation::Continue,
+            Outcome::Unchanged => Navigation::Unchanged,
+            Outcome::Changed => Navigation::Changed,
+        }
+    }
+}
+
+/// Combines two outcomes. The result is the max() of both.
+impl BitOr for Navigation {
+    type Output = Navigation;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        max(self, rhs)
+    }
+}
+
+impl BitOrAssign for Navigation {
+    fn bitor_assign(&mut self, rhs: Self) {
+        if rhs >= *self {
+            *self = rhs;
+        }
+    }
+}
+
+/// The navigation requests are converted to `Outcome::Changed`.
+impl From<Navigation> for Outcome {
+    fn from(value: Navigation) -> Self {
+        match value {
+            Navigation::Continue => Outcome::Continue,
+            Navigation::Unchanged => Outcome::Unchanged,
+            Navigation::Changed
+            | Navigation::FocusNext
+            | Navigation::FocusPrev
+            | Navigation::Leave(_) => Outcome::Changed,
+        }
+    }
+}
+
+/// An outcome that additionally requests a position for the
+/// terminal cursor.
+///
+/// Text-input widgets use this to tell the application where
+/// the cursor should be placed after handling an event.
+///
+/// Ordering looks at the outcome first, and then at the cursor.
+/// No cursor sorts below any cursor. When combining two results
+/// with `|` the cursor of the later consumed result wins, if it has one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CursorOutcome {
+    /// The regular outcome.
+    pub outcome: Outcome,
+    /// Requested cursor position.
+    pub cursor: Option<Position>,
+}
+
+impl CursorOutcome {
+    /// New outcome with a cursor position.
+    pub fn new(outcome: Outcome, cursor: Option<Position>) -> Self {
+        Self { outcome, cursor }
+    }
+}
+
+impl ConsumedEvent for CursorOutcome {
+    fn is_consumed(&self) -> bool {
+        self.outcome.is_consumed()
+    }
+}
+
+/// Combines two outcomes. The outcome is the max() of both,
+/// the cursor is taken from the later consumed outcome if it has one.
+impl BitOr for CursorOutcome {
+    type Output = CursorOutcome;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let cursor = if rhs.is_consumed() && rhs.cursor.is_some() {
+            rhs.cursor
+        } else {
+            self.cursor
+        };
+        CursorOutcome {
+            outcome: max(self.outcome, rhs.outcome),
+            cursor,
+        }
+    }
+}
+
+impl BitOrAssign for CursorOutcome {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl From<Outcome> for CursorOutcome {
+    fn from(value: Outcome) -> Self {
+        Self {
+            outcome: value,
+            cursor: None,
+        }
+    }
+}
+
+/// Drops the cursor.
+impl From<CursorOutcome> for Outcome {
+    fn from(value: CursorOutcome) -> Self {
+        value.outcome
+    }
+}
+
+/// An outcome that additionally asks for another render after
+/// some time, even if no event arrives.
+///
+/// This is for widgets that animate, e.g. a spinner or a blinking cursor.
+///
+/// When combining two results with `|` the outcome is the max() of
+/// both, and the smaller duration is kept. Ordering looks at the
+/// outcome first. For the same outcome no duration sorts lowest,
+/// and a smaller duration sorts above a larger one. That way max()
+/// keeps the smaller duration too.
+///
+/// The application uses the final `again` as the timeout for polling:
+///
+/// ```rust ignore
+/// let mut timeout = None;
+/// loop {
+///     if event::poll(timeout.unwrap_or(Duration::from_secs(1)))? {
+///         let r: PollOutcome = state.handle(&event::read()?, Regular);
+///         if r.needs_render() {
+///             terminal.draw(|f| render(f, &mut state))?;
+///         }
+///         timeout = r.again;
+///     } else {
+///         terminal.draw(|f| render(f, &mut state))?;
+///         timeout = None;
+///     }
+/// }
+/// ```
+///
+/// A handler that is not consumed can still have a hint, which
+/// [flow] would throw away. To keep those hints accumulate
+/// the results and use [flow] on the accumulator:
+///
+/// ```rust ignore
+/// let mut r = PollOutcome::default();
+/// r |= self.spinner.handle(event, Regular);
+/// flow!(r);
+/// r |= self.cursor.handle(event, Regular);
+/// flow!(r);
+/// r
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PollOutcome {
+    /// The regular outcome.
+    pub outcome: Outcome,
+    /// Render again after this time.
+    pub again: Option<Duration>,
+}
+
+impl PollOutcome {
+    /// New outcome with a poll hint.
+    pub fn new(outcome: Outcome, again: Option<Duration>) -> Self {
+        Self { outcome, again }
+    }
+
+    /// Should the ui be rendered now?
+    pub fn needs_render(&self) -> bool {
+        self.outcome.needs_render()
+    }
+}
+
+impl ConsumedEvent for PollOutcome {
+    fn is_consumed(&self) -> bool {
+        self.outcome.is_consumed()
+    }
+}
+
+impl PartialOrd for PollOutcome {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PollOutcome {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.outcome
+            .cmp(&other.outcome)
+            .then_with(|| match (self.again, other.again) {
+                (Some(a), Some(b)) => b.cmp(&a),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            })
+    }
+}
+
+/// Combines two outcomes. The outcome is the max() of both,
+/// and the smaller duration is kept.
+impl BitOr for PollOutcome {
+    type Output = PollOutcome;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let again = match (self.again, rhs.again) {
+            (Some(a), Some(b)) => Some(min(a, b)),
+            (a, b) => a.or(b),
+        };
+        PollOutcome {
+            outcome: max(self.outcome, rhs.outcome),
+            again,
+        }
+    }
+}
+
+impl BitOrAssign for PollOutcome {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl From<Outcome> for PollOutcome {
+    fn from(value: Outcome) -> Self {
+        Self {
+            outcome: value,
+            again: None,
+        }
+    }
+}
+
+/// Drops the poll hint.
+impl From<PollOutcome> for Outcome {
+    fn from(value: PollOutcome) -> Self {
+        value.outcome
+    }
+}
+
+#[cfg(feature = "log")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_log {
+    ($n:ident, $r:expr) => {
+        $crate::log::debug!("{} {:#?}", stringify!($n), $r)
+    };
+    ($n:ident) => {
+        $crate::log::debug!("{} continue", stringify!($n))
+    };
+}
+
+#[cfg(not(feature = "log"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_log {
+    ($($t:tt)*) => {
+        compile_error!("the log arm of the flow macros needs the feature 'log' of rat-event")
+    };
+}
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_trace {
+    ($n:ident, $r:expr) => {
+        $crate::tracing::debug!(handler = stringify!($n), result = ?$r, "consumed")
+    };
+    ($n:ident) => {
+        $crate::tracing::debug!(handler = stringify!($n), "continue")
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_trace {
+    ($($t:tt)*) => {
+        compile_error!("the trace arm of the flow macros needs the feature 'tracing' of rat-event")
+    };
+}
+
 /// Breaks the control-flow if the block returns a value
 /// for which [ConsumedEvent::is_consumed] is true.
 ///
@@ -218,18 +1840,98 @@ impl From<bool> for Outcome {
 /// *The difference to [try_flow] is that this on doesn't Ok-wrap the result.*
 ///
 /// Extras: If you add a marker as in `flow!(log ident: {...});`
-/// the result of the operation is written to the log.
+/// the result of the operation is written to the log. This needs
+/// the feature `log`, which is enabled by default.
+/// `flow!(trace ident: {...});` does the same with a tracing event,
+/// with the name as field `handler`. This needs the feature `tracing`.
+///
+/// Extras: `flow!('label: {...});` breaks out of the labeled block
+/// instead of returning. This works inside a loop over some children
+/// or in a closure, where a `return` would be wrong or impossible.
+///
+/// The expression can be awaited, as in `flow!(h.handle_async(e, q).await)`.
+/// In an `async fn` or an `async` block the return leaves the future
+/// with the result, so all the forms work for async handlers too.
+///
+/// Extras: `flow!({...}, consider a, b, c);` merges other outcomes,
+/// e.g. from focus handling or scrolling. If the result is consumed,
+/// the max() of the result and all the considered values is returned.
+/// Each of them is converted with `into()`. If the result is not
+/// consumed the considered values are not used, they are still
+/// available for the final result.
+///
+/// ```rust
+/// use rat_event::{flow, Outcome, OutcomeOf};
+///
+/// fn handle(widget: OutcomeOf<u32>, focus: Outcome, scroll: Outcome) -> Outcome {
+///     flow!(widget, consider focus, scroll);
+///     focus
+/// }
+///
+/// assert_eq!(
+///     handle(OutcomeOf::Unchanged, Outcome::Changed, Outcome::Continue),
+///     Outcome::Changed
+/// );
+/// assert_eq!(
+///     handle(OutcomeOf::Continue, Outcome::Unchanged, Outcome::Changed),
+///     Outcome::Unchanged
+/// );
+/// ```
+///
+/// ```rust
+/// use rat_event::{flow, Outcome};
+///
+/// let children = [Outcome::Continue, Outcome::Changed, Outcome::Unchanged];
+///
+/// let outcome = 'handled: {
+///     for child in children {
+///         flow!('handled: child);
+///     }
+///     Outcome::Continue
+/// };
+/// assert_eq!(outcome, Outcome::Changed);
+/// ```
 #[macro_export]
 macro_rules! flow {
     (log $n:ident: $x:expr) => {{
-        use log::debug;
         use $crate::ConsumedEvent;
         let r = $x;
         if r.is_consumed() {
-            debug!("{} {:#?}", stringify!($n), r);
+            $crate::__flow_log!($n, r);
+            return r.into();
+        } else {
+            $crate::__flow_log!($n);
+            _ = r;
+        }
+    }};
+    (trace $n:ident: $x:expr) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        if r.is_consumed() {
+            $crate::__flow_trace!($n, r);
             return r.into();
         } else {
-            debug!("{} continue", stringify!($n));
+            $crate::__flow_trace!($n);
+            _ = r;
+        }
+    }};
+    ($l:lifetime: $x:expr) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        if r.is_consumed() {
+            break $l r.into();
+        } else {
+            _ = r;
+        }
+    }};
+    ($x:expr, consider $($f:expr),+ $(,)?) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        if r.is_consumed() {
+            let r = r.into();
+            $(let r = std::cmp::max(r, $f.into());)+
+            return r;
+        } else {
             _ = r;
         }
     }};
@@ -253,17 +1955,131 @@ macro_rules! flow {
 ///
 /// Extras: If you add a marker as in `try_flow!(log ident: {...});`
 /// the result of the operation is written to the log.
+/// `try_flow!(trace ident: {...});` uses tracing instead. See [flow].
+///
+/// Extras: `try_flow!({...}, consider a, b, c);` merges other outcomes
+/// like [flow] does.
+///
+/// Fallible handlers can be chained with `try_flow!(h.handle(..)?)`.
+/// An error is returned immediately and converted via `From` like any
+/// other `?`, an `Ok` value is handled like any other result.
+///
+/// ```rust
+/// use rat_event::{try_flow, Outcome};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct AppError(String);
+///
+/// impl From<std::io::Error> for AppError {
+///     fn from(value: std::io::Error) -> Self {
+///         AppError(value.to_string())
+///     }
+/// }
+///
+/// fn save(event: &char) -> Result<Outcome, std::io::Error> {
+///     match event {
+///         's' => Ok(Outcome::Changed),
+///         'e' => Err(std::io::Error::other("disk full")),
+///         _ => Ok(Outcome::Continue),
+///     }
+/// }
+///
+/// fn handle(event: &char) -> Result<Outcome, AppError> {
+///     try_flow!(save(event)?);
+///     Ok(Outcome::Continue)
+/// }
+///
+/// assert_eq!(handle(&'s'), Ok(Outcome::Changed));
+/// assert_eq!(handle(&'e'), Err(AppError("disk full".into())));
+/// assert_eq!(handle(&'x'), Ok(Outcome::Continue));
+/// ```
+///
+/// If there is no `From` conversion for the error, map it before the `?`
+/// as in `try_flow!(h.handle(..).map_err(AppError::Widget)?)`.
+/// This works with the log marker too.
+///
+/// Extras: `try_flow!(fallible err: {...});` takes a `Result` or a [Fallible].
+/// An error doesn't break the control-flow, it is stored in the
+/// variable `err: Option<E>` instead, and the next handlers can still
+/// look at the event. Only the first error is kept. If a later handler
+/// consumes the event, the stored error is returned instead of the result.
+///
+/// ```rust
+/// use rat_event::{try_flow, Outcome};
+///
+/// fn open_file(event: &char) -> Result<Outcome, String> {
+///     if *event == 'o' {
+///         Err("no such file".into())
+///     } else {
+///         Ok(Outcome::Continue)
+///     }
+/// }
+///
+/// fn handle(event: &char) -> Result<Outcome, String> {
+///     let mut err = None;
+///     try_flow!(fallible err: open_file(event));
+///     try_flow!(fallible err: Ok::<_, String>(Outcome::Continue));
+///     if let Some(err) = err {
+///         return Err(err);
+///     }
+///     Ok(Outcome::Continue)
+/// }
+///
+/// assert_eq!(handle(&'o'), Err("no such file".into()));
+/// assert_eq!(handle(&'x'), Ok(Outcome::Continue));
+/// ```
 #[macro_export]
 macro_rules! try_flow {
+    (fallible $err:ident: $x:expr) => {{
+        use $crate::ConsumedEvent;
+        let r = $crate::Fallible::from($x);
+        let consumed = r.is_consumed();
+        match r.into_inner() {
+            Err(e) => {
+                if $err.is_none() {
+                    $err = Some(e);
+                }
+            }
+            Ok(r) => {
+                if consumed {
+                    if let Some(e) = $err.take() {
+                        return Err(e.into());
+                    }
+                    return Ok(r.into());
+                }
+            }
+        }
+    }};
     (log $n:ident: $x:expr) => {{
-        use log::debug;
         use $crate::ConsumedEvent;
         let r = $x;
         if r.is_consumed() {
-            debug!("{} {:#?}", stringify!($n), r);
+            $crate::__flow_log!($n, r);
+            return Ok(r.into());
+        } else {
+            $crate::__flow_log!($n);
+            _ = r;
+        }
+    }};
+    (trace $n:ident: $x:expr) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        if r.is_consumed() {
+            $crate::__flow_trace!($n, r);
             return Ok(r.into());
         } else {
-            debug!("{} continue", stringify!($n));
+            $crate::__flow_trace!($n);
+            _ = r;
+        }
+    }};
+    ($x:expr, consider $($f:expr),+ $(,)?) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        if r.is_consumed() {
+            let r = r.into();
+            $(let r = std::cmp::max(r, $f.into());)+
+            return Ok(r);
+        } else {
             _ = r;
         }
     }};
@@ -277,3 +2093,444 @@ macro_rules! try_flow {
         }
     }};
 }
+
+/// Evaluates the expressions from left to right and stops at the first
+/// result for which [ConsumedEvent::is_consumed] is true. This result
+/// is the value of the macro, otherwise it's the value of the last expression.
+///
+/// All expressions must have the same type. If you add a marker
+/// as in `handle_chain!(a, b, c => Outcome)`, every result is converted
+/// with `into()` first, and the expressions can have different types.
+///
+/// *The difference to [flow] is that this one is an expression and
+/// doesn't break the control-flow.*
+///
+/// ```rust
+/// use rat_event::{handle_chain, Outcome, OutcomeOf};
+///
+/// let r = handle_chain!(Outcome::Continue, Outcome::Changed, unreachable!());
+/// assert_eq!(r, Outcome::Changed);
+///
+/// let r = handle_chain!(
+///     OutcomeOf::<u32>::Continue,
+///     Outcome::Unchanged
+///     => Outcome
+/// );
+/// assert_eq!(r, Outcome::Unchanged);
+/// ```
+#[macro_export]
+macro_rules! handle_chain {
+    (@into $t:ty; $x:expr) => {{
+        let r: $t = $x.into();
+        r
+    }};
+    (@into $t:ty; $x:expr, $($xs:expr),+) => {{
+        use $crate::ConsumedEvent;
+        let r: $t = $x.into();
+        if r.is_consumed() {
+            r
+        } else {
+            $crate::handle_chain!(@into $t; $($xs),+)
+        }
+    }};
+    ($($xs:expr),+ => $t:ty) => {
+        $crate::handle_chain!(@into $t; $($xs),+)
+    };
+    ($x:expr $(,)?) => {
+        $x
+    };
+    ($x:expr, $($xs:expr),+ $(,)?) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        if r.is_consumed() {
+            r
+        } else {
+            $crate::handle_chain!($($xs),+)
+        }
+    }};
+}
+
+/// Always evaluates the expression and stores the max() of
+/// the variable and the result in the variable. The result is
+/// converted with `into()` first.
+///
+/// This is the same as [ConsumedEvent::and], but works with
+/// different result types, as long as they convert to the type
+/// of the variable.
+///
+/// ```rust
+/// use rat_event::{then, Outcome, OutcomeOf};
+///
+/// let mut r = Outcome::Continue;
+/// then!(r, OutcomeOf::<u32>::Unchanged);
+/// then!(r, Outcome::Continue);
+/// assert_eq!(r, Outcome::Unchanged);
+/// ```
+#[macro_export]
+macro_rules! then {
+    ($r:ident, $x:expr) => {{
+        $r = std::cmp::max($r, $x.into());
+    }};
+}
+
+/// Evaluates all the expressions and stores the max() of the
+/// variable and all results in the variable, as [then] does.
+///
+/// Use this if every handler must see the event, e.g. a tick or
+/// a resize that all children need to know about. For the usual case,
+/// where the first handler that consumes the event wins, use [flow]
+/// or [handle_chain].
+///
+/// ```rust
+/// use rat_event::{broadcast, Outcome, OutcomeOf};
+///
+/// let mut ticks = 0;
+/// let mut tick = |r: Outcome| {
+///     ticks += 1;
+///     r
+/// };
+///
+/// let mut r = Outcome::Continue;
+/// broadcast!(r: tick(Outcome::Changed), tick(Outcome::Unchanged), OutcomeOf::<u32>::Continue);
+/// assert_eq!(r, Outcome::Changed);
+/// assert_eq!(ticks, 2);
+/// ```
+#[macro_export]
+macro_rules! broadcast {
+    ($r:ident: $($x:expr),+ $(,)?) => {{
+        $($crate::then!($r, $x);)+
+    }};
+}
+
+/// Evaluates the expression only if the variable holds a result
+/// that is not consumed yet, and stores the result converted with
+/// `into()` in the variable.
+///
+/// *The difference to [flow] is that this one doesn't break the
+/// control-flow. The difference to [then] is that this one skips
+/// the expression once the event is consumed.*
+///
+/// Extras: `or_else!(r, {...}, consider a, b);` stores the max() of the
+/// result and all considered values, like `flow!(.., consider ..)` does.
+///
+/// ```rust
+/// use rat_event::{or_else, Outcome, OutcomeOf};
+///
+/// let focus = Outcome::Changed;
+///
+/// let mut r = Outcome::Continue;
+/// or_else!(r, OutcomeOf::<u32>::Continue);
+/// or_else!(r, Outcome::Unchanged, consider focus);
+/// or_else!(r, unreachable!() as Outcome);
+/// assert_eq!(r, Outcome::Changed);
+/// ```
+#[macro_export]
+macro_rules! or_else {
+    ($r:ident, $x:expr, consider $($f:expr),+ $(,)?) => {{
+        use $crate::ConsumedEvent;
+        if !$r.is_consumed() {
+            let r = $x.into();
+            $(let r = std::cmp::max(r, $f.into());)+
+            $r = r;
+        }
+    }};
+    ($r:ident, $x:expr $(,)?) => {{
+        use $crate::ConsumedEvent;
+        if !$r.is_consumed() {
+            $r = $x.into();
+        }
+    }};
+}
+
+/// Same as [or_else] for an expression that returns a `Result`.
+///
+/// An error is returned immediately, converted via `From` like any
+/// other `?`. The Ok value is stored in the variable.
+///
+/// ```rust
+/// use rat_event::{or_else_ok, Outcome};
+///
+/// fn handle(a: Result<Outcome, String>, b: Result<Outcome, String>) -> Result<Outcome, String> {
+///     let mut r = Outcome::Continue;
+///     or_else_ok!(r, a);
+///     or_else_ok!(r, b);
+///     Ok(r)
+/// }
+///
+/// assert_eq!(handle(Ok(Outcome::Continue), Ok(Outcome::Changed)), Ok(Outcome::Changed));
+/// assert_eq!(handle(Ok(Outcome::Unchanged), Err("bad".into())), Ok(Outcome::Unchanged));
+/// assert_eq!(handle(Ok(Outcome::Continue), Err("bad".into())), Err("bad".into()));
+/// ```
+#[macro_export]
+macro_rules! or_else_ok {
+    ($r:ident, $x:expr $(,)?) => {{
+        use $crate::ConsumedEvent;
+        if !$r.is_consumed() {
+            $r = $x?.into();
+        }
+    }};
+}
+
+/// Matches the event against a list of `pattern => expr` arms.
+///
+/// The value of the matching arm is converted with `into()`.
+/// If no arm matches, the result is `Default::default()`, which
+/// is the 'not consumed' value for [Outcome] and the other outcomes.
+/// The arms must be separated with a comma.
+///
+/// The [ct_event] macro can be used as pattern.
+///
+/// ```rust
+/// # #[cfg(feature = "crossterm")]
+/// # {
+/// use rat_event::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use rat_event::{ct_event, match_event, Outcome};
+///
+/// fn handle(event: &Event, count: &mut u32) -> Outcome {
+///     match_event!(event, {
+///         ct_event!(key press '+') => {
+///             *count += 1;
+///             Outcome::Changed
+///         },
+///         ct_event!(key press '-') if *count > 0 => {
+///             *count -= 1;
+///             Outcome::Changed
+///         },
+///         ct_event!(key press '-') => Outcome::Unchanged,
+///     })
+/// }
+///
+/// let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+///
+/// let mut count = 0;
+/// assert_eq!(handle(&key('+'), &mut count), Outcome::Changed);
+/// assert_eq!(handle(&key('x'), &mut count), Outcome::Continue);
+/// assert_eq!(count, 1);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! match_event {
+    ($event:expr, { $($p:pat $(if $g:expr)? => $x:expr),* $(,)? }) => {
+        match $event {
+            $($p $(if $g)? => $x.into(),)*
+            _ => Default::default(),
+        }
+    };
+}
+
+/// Calls the event-handlers phase by phase and breaks the control-flow
+/// like [flow] as soon as one of them consumes the event.
+///
+/// `dispatch!(event: dialog {a, b}, popup {c}, regular {d, e})`
+/// calls the handlers with the qualifier for their phase.
+/// The phases are `dialog`, `popup`, `regular` and `mouse` for
+/// [Dialog], [Popup], [Regular] and [MouseOnly]. They are processed
+/// in the given order.
+///
+/// An optional trailing `else { ... }` gives the value for an
+/// event that no one consumed.
+///
+/// ```rust
+/// use rat_event::{dispatch, HandleEvent, Outcome, Popup, Regular};
+///
+/// struct Menu(bool);
+///
+/// impl HandleEvent<char, Regular, Outcome> for Menu {
+///     fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+///         if *event == 'm' {
+///             self.0 = true;
+///             Outcome::Changed
+///         } else {
+///             Outcome::Continue
+///         }
+///     }
+/// }
+///
+/// impl HandleEvent<char, Popup, Outcome> for Menu {
+///     fn handle(&mut self, event: &char, _qualifier: Popup) -> Outcome {
+///         if self.0 && *event == 'x' {
+///             self.0 = false;
+///             Outcome::Changed
+///         } else {
+///             Outcome::Continue
+///         }
+///     }
+/// }
+///
+/// fn handle(event: &char, menu: &mut Menu) -> Outcome {
+///     dispatch!(event: popup { menu }, regular { menu } else {
+///         Outcome::Continue
+///     })
+/// }
+///
+/// let mut menu = Menu(false);
+/// assert_eq!(handle(&'x', &mut menu), Outcome::Continue);
+/// assert_eq!(handle(&'m', &mut menu), Outcome::Changed);
+/// assert_eq!(handle(&'x', &mut menu), Outcome::Changed);
+/// ```
+#[macro_export]
+macro_rules! dispatch {
+    (@qualifier dialog) => {
+        $crate::Dialog
+    };
+    (@qualifier popup) => {
+        $crate::Popup
+    };
+    (@qualifier regular) => {
+        $crate::Regular
+    };
+    (@qualifier mouse) => {
+        $crate::MouseOnly
+    };
+    ($event:ident: $($phase:ident { $($h:expr),* $(,)? }),+ $(,)? else $e:block) => {{
+        $crate::dispatch!($event: $($phase { $($h),* }),+);
+        $e
+    }};
+    ($event:ident: $($phase:ident { $($h:expr),* $(,)? }),+ $(,)?) => {{
+        use $crate::HandleEvent;
+        $($(
+            $crate::flow!($h.handle($event, $crate::dispatch!(@qualifier $phase)));
+        )*)+
+    }};
+}
+
+/// Implements [ConsumedEvent], `From<Outcome>` and `From<..> for Outcome`
+/// for a widget specific outcome enum.
+///
+/// The first three entries name the unit variants that correspond to
+/// [Outcome::Continue], [Outcome::Unchanged] and [Outcome::Changed].
+/// All further variants map to one of the base outcomes.
+///
+/// The variant that maps to Continue is the one that is not consumed.
+///
+/// ```rust
+/// use rat_event::{impl_outcome, ConsumedEvent, Outcome};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MenuOutcome {
+///     NotUsed,
+///     Unchanged,
+///     Changed,
+///     Selected(String),
+///     Hover { idx: usize },
+/// }
+///
+/// impl_outcome!(MenuOutcome {
+///     NotUsed => Continue,
+///     Unchanged => Unchanged,
+///     Changed => Changed,
+///     Selected(_) => Changed,
+///     Hover { .. } => Unchanged,
+/// });
+///
+/// assert!(!MenuOutcome::NotUsed.is_consumed());
+/// assert!(MenuOutcome::Selected("file".into()).is_consumed());
+/// assert_eq!(Outcome::from(MenuOutcome::Hover { idx: 1 }), Outcome::Unchanged);
+/// assert_eq!(MenuOutcome::from(Outcome::Continue), MenuOutcome::NotUsed);
+/// ```
+///
+/// The mapping for the not consumed variant is required.
+///
+/// ```rust,compile_fail
+/// use rat_event::impl_outcome;
+///
+/// enum MenuOutcome {
+///     Unchanged,
+///     Changed,
+/// }
+///
+/// impl_outcome!(MenuOutcome {
+///     Unchanged => Unchanged,
+///     Changed => Changed,
+/// });
+/// ```
+#[macro_export]
+macro_rules! impl_outcome {
+    ($name:ident {
+        $c:ident => Continue,
+        $u:ident => Unchanged,
+        $ch:ident => Changed
+        $(, $v:ident $(($($f:tt)*))? $({$($g:tt)*})? => $o:ident)*
+        $(,)?
+    }) => {
+        impl $crate::ConsumedEvent for $name {
+            fn is_consumed(&self) -> bool {
+                let o = match self {
+                    $name::$c => $crate::Outcome::Continue,
+                    $name::$u => $crate::Outcome::Unchanged,
+                    $name::$ch => $crate::Outcome::Changed,
+                    $($name::$v $(($($f)*))? $({$($g)*})? => $crate::Outcome::$o,)*
+                };
+                o != $crate::Outcome::Continue
+            }
+        }
+
+        impl From<$crate::Outcome> for $name {
+            fn from(value: $crate::Outcome) -> Self {
+                match value {
+                    $crate::Outcome::Continue => $name::$c,
+                    $crate::Outcome::Unchanged => $name::$u,
+                    $crate::Outcome::Changed => $name::$ch,
+                }
+            }
+        }
+
+        impl From<$name> for $crate::Outcome {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::$c => $crate::Outcome::Continue,
+                    $name::$u => $crate::Outcome::Unchanged,
+                    $name::$ch => $crate::Outcome::Changed,
+                    $($name::$v $(($($f)*))? $({$($g)*})? => $crate::Outcome::$o,)*
+                }
+            }
+        }
+    };
+    ($($t:tt)*) => {
+        compile_error!(
+            "impl_outcome! needs the unit variants for Continue, Unchanged and Changed first, \
+            as in `MyOutcome { Continue => Continue, Unchanged => Unchanged, Changed => Changed, .. }`"
+        );
+    };
+}
+
+/// Evaluates the expression and stores the max() of the variable
+/// and the result in the variable, as [then] does.
+///
+/// Extras: `max_flow!(acc, {...}, if_consumed break);` additionally
+/// breaks the enclosing loop if the result is consumed. Use this
+/// in a loop over some children, when the loop must stop at the
+/// first child that consumes the event, but the strongest outcome
+/// seen so far is still needed.
+/// `max_flow!(acc, {...}, if_consumed break 'label);` breaks the
+/// labeled loop.
+///
+/// ```rust
+/// use rat_event::{max_flow, Outcome};
+///
+/// let children = [Outcome::Continue, Outcome::Unchanged, Outcome::Changed];
+///
+/// let mut acc = Outcome::Continue;
+/// let mut seen = 0;
+/// for child in children {
+///     seen += 1;
+///     max_flow!(acc, child, if_consumed break);
+/// }
+/// assert_eq!(acc, Outcome::Unchanged);
+/// assert_eq!(seen, 2);
+/// ```
+#[macro_export]
+macro_rules! max_flow {
+    ($acc:ident, $x:expr, if_consumed break $($l:lifetime)?) => {{
+        use $crate::ConsumedEvent;
+        let r = $x;
+        let consumed = r.is_consumed();
+        $acc = std::cmp::max($acc, r.into());
+        if consumed {
+            break $($l)?;
+        }
+    }};
+    ($acc:ident, $x:expr $(,)?) => {{
+        $acc = std::cmp::max($acc, $x.into());
+    }};
+}
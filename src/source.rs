@@ -0,0 +1,276 @@
+//!
+//! A common interface for everything that produces events.
+//!
+//! The main loop can drain the terminal, channels from background
+//! threads and timers the same way. Two sources are combined with
+//! [merge], the result is a source again.
+//!
+//! ```rust
+//! use rat_event::source::{merge, EventSource, Merged, Priority};
+//! use std::sync::mpsc;
+//! use std::time::Duration;
+//!
+//! let (tx_input, input) = mpsc::channel::<char>();
+//! let (tx_work, work) = mpsc::channel::<String>();
+//! tx_work.send("file changed".into()).expect("send");
+//! tx_input.send('q').expect("send");
+//!
+//! // input before the background work.
+//! let mut source = merge(input, work).priority(Priority::First);
+//! assert_eq!(
+//!     source.poll(Some(Duration::ZERO))?,
+//!     Some(Merged::First('q'))
+//! );
+//! assert_eq!(
+//!     source.poll(Some(Duration::ZERO))?,
+//!     Some(Merged::Second("file changed".into()))
+//! );
+//! assert_eq!(source.poll(Some(Duration::from_millis(10)))?, None);
+//! # Ok::<(), rat_event::source::SourceError>(())
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Produces events.
+pub trait EventSource<E> {
+    /// Waits for the next event.
+    ///
+    /// Waits up to the timeout, or forever if there is no timeout.
+    /// A timeout of zero only checks for an event that is already
+    /// there. Returns `Ok(None)` if the timeout runs out.
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<E>, SourceError>;
+}
+
+impl<E, S> EventSource<E> for &mut S
+where
+    S: EventSource<E> + ?Sized,
+{
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<E>, SourceError> {
+        (**self).poll(timeout)
+    }
+}
+
+/// Error from an [EventSource].
+#[derive(Debug)]
+pub enum SourceError {
+    /// Reading the events failed.
+    Io(io::Error),
+    /// The source will never produce another event.
+    /// E.g. all senders of a channel are gone.
+    Disconnected,
+}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceError::Io(e) => write!(f, "event source: {}", e),
+            SourceError::Disconnected => write!(f, "event source is disconnected"),
+        }
+    }
+}
+
+impl Error for SourceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SourceError::Io(e) => Some(e),
+            SourceError::Disconnected => None,
+        }
+    }
+}
+
+impl From<io::Error> for SourceError {
+    fn from(value: io::Error) -> Self {
+        SourceError::Io(value)
+    }
+}
+
+impl<E> EventSource<E> for mpsc::Receiver<E> {
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<E>, SourceError> {
+        match timeout {
+            Some(timeout) => match self.recv_timeout(timeout) {
+                Ok(v) => Ok(Some(v)),
+                Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+                Err(mpsc::RecvTimeoutError::Disconnected) => Err(SourceError::Disconnected),
+            },
+            None => match self.recv() {
+                Ok(v) => Ok(Some(v)),
+                Err(mpsc::RecvError) => Err(SourceError::Disconnected),
+            },
+        }
+    }
+}
+
+/// Event of a [Merge].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Merged<A, B> {
+    /// Event of the first source.
+    First(A),
+    /// Event of the second source.
+    Second(B),
+}
+
+/// Which source of a [Merge] is read first.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// The first source is always read first. The second only gets
+    /// a turn when the first one has nothing.
+    #[default]
+    First,
+    /// The second source is always read first.
+    Second,
+    /// Take turns. After an event of one source, the other one
+    /// is read first.
+    Alternate,
+}
+
+/// Combines two sources. See [merge].
+#[derive(Debug)]
+pub struct Merge<SA, SB> {
+    first: SA,
+    second: SB,
+    priority: Priority,
+    slice: Duration,
+    second_next: bool,
+}
+
+/// Combines two sources.
+///
+/// The result is an [EventSource] for [Merged] events. By default,
+/// the first source has priority, put the terminal there and the
+/// background events second.
+///
+/// An error of either source is returned as is.
+pub fn merge<SA, SB>(first: SA, second: SB) -> Merge<SA, SB> {
+    Merge {
+        first,
+        second,
+        priority: Priority::First,
+        slice: Duration::from_millis(10),
+        second_next: false,
+    }
+}
+
+impl<SA, SB> Merge<SA, SB> {
+    /// Which source is read first.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self.second_next = priority == Priority::Second;
+        self
+    }
+
+    /// The sources can't be waited on at the same time. The merge
+    /// waits on the source with priority for this long, and then
+    /// checks the other one. Defaults to 10ms.
+    ///
+    /// This is the delay the other source may see.
+    pub fn slice(mut self, slice: Duration) -> Self {
+        self.slice = slice;
+        self
+    }
+
+    /// The first source.
+    pub fn first(&self) -> &SA {
+        &self.first
+    }
+
+    /// The first source.
+    pub fn first_mut(&mut self) -> &mut SA {
+        &mut self.first
+    }
+
+    /// The second source.
+    pub fn second(&self) -> &SB {
+        &self.second
+    }
+
+    /// The second source.
+    pub fn second_mut(&mut self) -> &mut SB {
+        &mut self.second
+    }
+
+    /// Split into the two sources.
+    pub fn into_inner(self) -> (SA, SB) {
+        (self.first, self.second)
+    }
+
+    fn took_first(&mut self) {
+        if self.priority == Priority::Alternate {
+            self.second_next = true;
+        }
+    }
+
+    fn took_second(&mut self) {
+        if self.priority == Priority::Alternate {
+            self.second_next = false;
+        }
+    }
+}
+
+/// Wait on the preferred source first, then check the other.
+fn poll_both<A, B, SA, SB>(
+    merge: &mut Merge<SA, SB>,
+    wait: Duration,
+) -> Result<Option<Merged<A, B>>, SourceError>
+where
+    SA: EventSource<A>,
+    SB: EventSource<B>,
+{
+    let (first_wait, second_wait) = if merge.second_next {
+        (Duration::ZERO, wait)
+    } else {
+        (wait, Duration::ZERO)
+    };
+
+    if merge.second_next {
+        if let Some(v) = merge.second.poll(Some(second_wait))? {
+            merge.took_second();
+            return Ok(Some(Merged::Second(v)));
+        }
+    }
+    if let Some(v) = merge.first.poll(Some(first_wait))? {
+        merge.took_first();
+        return Ok(Some(Merged::First(v)));
+    }
+    if !merge.second_next {
+        if let Some(v) = merge.second.poll(Some(second_wait))? {
+            merge.took_second();
+            return Ok(Some(Merged::Second(v)));
+        }
+    }
+    Ok(None)
+}
+
+impl<A, B, SA, SB> EventSource<Merged<A, B>> for Merge<SA, SB>
+where
+    SA: EventSource<A>,
+    SB: EventSource<B>,
+{
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<Merged<A, B>>, SourceError> {
+        let deadline = timeout.map(|v| Instant::now() + v);
+
+        // anything that is already there.
+        if let Some(v) = poll_both(self, Duration::ZERO)? {
+            return Ok(Some(v));
+        }
+
+        loop {
+            let wait = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(None);
+                    }
+                    (deadline - now).min(self.slice)
+                }
+                None => self.slice,
+            };
+            if let Some(v) = poll_both(self, wait)? {
+                return Ok(Some(v));
+            }
+        }
+    }
+}
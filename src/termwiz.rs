@@ -0,0 +1,268 @@
+//!
+//! Support for termwiz.
+//!
+//! Needs the feature `termwiz`.
+//!
+//! The termwiz input events this crate is compiled against are
+//! re-exported as `rat_event::termwiz::input`.
+//!
+//! There are no event-handlers for the termwiz events themselves.
+//! Widgets implement `HandleEvent<rat_event::event::Event, ..>` and
+//! the application converts each [input::InputEvent] once
+//! with an [InputConverter]. The same widget then works with crossterm
+//! and termwiz, and [MouseFlags](crate::util::MouseFlags) works with
+//! its functions ending in `_event`.
+//!
+//! ```rust
+//! use rat_event::event::{Event, KeyCode, Modifiers};
+//! use rat_event::termwiz::input::{self, InputEvent};
+//! use rat_event::termwiz::InputConverter;
+//! use rat_event::{HandleEvent, Outcome, Regular};
+//!
+//! struct Counter(u32);
+//!
+//! impl HandleEvent<Event, Regular, Outcome> for Counter {
+//!     fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+//!         match event {
+//!             Event::Key(key) if key.is_press() && key.code == KeyCode::Char('+') => {
+//!                 self.0 += 1;
+//!                 Outcome::Changed
+//!             }
+//!             _ => Outcome::Continue,
+//!         }
+//!     }
+//! }
+//!
+//! let mut converter = InputConverter::new();
+//! let mut counter = Counter(0);
+//!
+//! let plus = InputEvent::Key(input::KeyEvent {
+//!     key: input::KeyCode::Char('+'),
+//!     modifiers: input::Modifiers::NONE,
+//! });
+//! let r: Outcome = converter.handle(&mut counter, &plus, Regular);
+//! assert_eq!(r, Outcome::Changed);
+//! assert_eq!(counter.0, 1);
+//! ```
+//!
+//! termwiz reports key presses only. All converted key events
+//! are [KeyKind::Press](crate::event::KeyKind::Press).
+
+pub use ::termwiz::input;
+
+use crate::event::{Event, KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseKind};
+use crate::{ConsumedEvent, HandleEvent};
+use input::{InputEvent, MouseButtons};
+
+impl From<input::Modifiers> for Modifiers {
+    fn from(value: input::Modifiers) -> Self {
+        let mut m = Modifiers::NONE;
+        for (c, v) in [
+            (input::Modifiers::SHIFT, Modifiers::SHIFT),
+            (input::Modifiers::LEFT_SHIFT, Modifiers::SHIFT),
+            (input::Modifiers::RIGHT_SHIFT, Modifiers::SHIFT),
+            (input::Modifiers::CTRL, Modifiers::CONTROL),
+            (input::Modifiers::LEFT_CTRL, Modifiers::CONTROL),
+            (input::Modifiers::RIGHT_CTRL, Modifiers::CONTROL),
+            (input::Modifiers::ALT, Modifiers::ALT),
+            (input::Modifiers::LEFT_ALT, Modifiers::ALT),
+            (input::Modifiers::RIGHT_ALT, Modifiers::ALT),
+            (input::Modifiers::SUPER, Modifiers::SUPER),
+        ] {
+            if value.contains(c) {
+                m |= v;
+            }
+        }
+        m
+    }
+}
+
+impl From<input::KeyCode> for KeyCode {
+    /// The keypad keys are mapped to the keys they stand for.
+    /// Keys that are not part of the model are [KeyCode::Other].
+    fn from(value: input::KeyCode) -> Self {
+        use input::KeyCode as K;
+        match value {
+            K::Char(c) => KeyCode::Char(c),
+            K::Backspace => KeyCode::Backspace,
+            K::Tab => KeyCode::Tab,
+            K::Enter => KeyCode::Enter,
+            K::Escape => KeyCode::Esc,
+            K::PageUp | K::KeyPadPageUp => KeyCode::PageUp,
+            K::PageDown | K::KeyPadPageDown => KeyCode::PageDown,
+            K::End | K::KeyPadEnd => KeyCode::End,
+            K::Home | K::KeyPadHome => KeyCode::Home,
+            K::LeftArrow | K::ApplicationLeftArrow => KeyCode::Left,
+            K::RightArrow | K::ApplicationRightArrow => KeyCode::Right,
+            K::UpArrow | K::ApplicationUpArrow => KeyCode::Up,
+            K::DownArrow | K::ApplicationDownArrow => KeyCode::Down,
+            K::Insert => KeyCode::Insert,
+            K::Delete => KeyCode::Delete,
+            K::Function(n) => KeyCode::F(n),
+            K::Numpad0 => KeyCode::Char('0'),
+            K::Numpad1 => KeyCode::Char('1'),
+            K::Numpad2 => KeyCode::Char('2'),
+            K::Numpad3 => KeyCode::Char('3'),
+            K::Numpad4 => KeyCode::Char('4'),
+            K::Numpad5 => KeyCode::Char('5'),
+            K::Numpad6 => KeyCode::Char('6'),
+            K::Numpad7 => KeyCode::Char('7'),
+            K::Numpad8 => KeyCode::Char('8'),
+            K::Numpad9 => KeyCode::Char('9'),
+            K::Multiply => KeyCode::Char('*'),
+            K::Add => KeyCode::Char('+'),
+            K::Subtract => KeyCode::Char('-'),
+            K::Decimal => KeyCode::Char('.'),
+            K::Divide => KeyCode::Char('/'),
+            _ => KeyCode::Other,
+        }
+    }
+}
+
+impl From<&input::KeyEvent> for KeyEvent {
+    /// Shift+Tab is returned as BackTab, with Shift, as crossterm does.
+    fn from(value: &input::KeyEvent) -> Self {
+        let modifiers = Modifiers::from(value.modifiers);
+        let code = match value.key {
+            input::KeyCode::Tab if modifiers.contains(Modifiers::SHIFT) => KeyCode::BackTab,
+            key => KeyCode::from(key),
+        };
+        KeyEvent::new(code, modifiers)
+    }
+}
+
+impl From<input::KeyEvent> for KeyEvent {
+    fn from(value: input::KeyEvent) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// Converts termwiz events into the backend-neutral
+/// [event](crate::event) model.
+///
+/// termwiz reports which mouse buttons are held with each mouse
+/// event, not what changed. The converter remembers the buttons of
+/// the last report and derives Down, Up, Drag and Moved from the
+/// difference. Use one converter for all events of a terminal,
+/// and convert each event only once.
+///
+/// If one report both presses and releases a button, only the
+/// press is reported.
+///
+/// The mouse positions are 0-based, as for crossterm. termwiz
+/// reports them 1-based for terminals except the Windows console.
+#[derive(Debug, Default, Clone)]
+pub struct InputConverter {
+    buttons: MouseButtons,
+}
+
+impl InputConverter {
+    /// New converter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the held mouse buttons.
+    ///
+    /// The next report with a button held is a Down.
+    pub fn clear(&mut self) {
+        self.buttons = MouseButtons::NONE;
+    }
+
+    /// Converts one event.
+    ///
+    /// Returns None for PixelMouse and Wake, which have no
+    /// counterpart in the model.
+    pub fn convert(&mut self, event: &InputEvent) -> Option<Event> {
+        match event {
+            InputEvent::Key(key) => Some(Event::Key(key.into())),
+            InputEvent::Mouse(m) => Some(Event::Mouse(self.convert_mouse(m))),
+            InputEvent::Resized { cols, rows } => Some(Event::Resize(
+                (*cols).try_into().unwrap_or(u16::MAX),
+                (*rows).try_into().unwrap_or(u16::MAX),
+            )),
+            InputEvent::Paste(s) => Some(Event::Paste(s.clone())),
+            InputEvent::PixelMouse(_) | InputEvent::Wake => None,
+        }
+    }
+
+    /// Converts a mouse report.
+    ///
+    /// The wheel is reported as scroll events, with the same
+    /// directions crossterm uses for the same escape sequences.
+    pub fn convert_mouse(&mut self, event: &input::MouseEvent) -> MouseEvent {
+        let buttons = &event.mouse_buttons;
+        let kind = if buttons.contains(MouseButtons::VERT_WHEEL) {
+            if buttons.contains(MouseButtons::WHEEL_POSITIVE) {
+                MouseKind::ScrollUp
+            } else {
+                MouseKind::ScrollDown
+            }
+        } else if buttons.contains(MouseButtons::HORZ_WHEEL) {
+            if buttons.contains(MouseButtons::WHEEL_POSITIVE) {
+                MouseKind::ScrollLeft
+            } else {
+                MouseKind::ScrollRight
+            }
+        } else {
+            let kind = mouse_kind(&self.buttons, buttons);
+            self.buttons = buttons.clone();
+            kind
+        };
+
+        let (column, row) = if cfg!(windows) {
+            (event.x, event.y)
+        } else {
+            (event.x.saturating_sub(1), event.y.saturating_sub(1))
+        };
+
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: event.modifiers.into(),
+        }
+    }
+
+    /// Converts the event and calls the event-handler for the
+    /// backend-neutral events.
+    ///
+    /// Events that can't be converted are not consumed, the result
+    /// is `R::default()`, see [not consumed](ConsumedEvent#not-consumed).
+    pub fn handle<T, Q, R>(&mut self, state: &mut T, event: &InputEvent, qualifier: Q) -> R
+    where
+        T: HandleEvent<Event, Q, R>,
+        R: ConsumedEvent + Default,
+    {
+        match self.convert(event) {
+            Some(event) => state.handle(&event, qualifier),
+            None => R::default(),
+        }
+    }
+}
+
+const BUTTONS: [(MouseButtons, MouseButton); 3] = [
+    (MouseButtons::LEFT, MouseButton::Left),
+    (MouseButtons::RIGHT, MouseButton::Right),
+    (MouseButtons::MIDDLE, MouseButton::Middle),
+];
+
+/// Mouse event from the buttons before and after.
+fn mouse_kind(before: &MouseButtons, after: &MouseButtons) -> MouseKind {
+    for (b, v) in BUTTONS {
+        if after.contains(b.clone()) && !before.contains(b) {
+            return MouseKind::Down(v);
+        }
+    }
+    for (b, v) in BUTTONS {
+        if before.contains(b.clone()) && !after.contains(b) {
+            return MouseKind::Up(v);
+        }
+    }
+    for (b, v) in BUTTONS {
+        if after.contains(b) {
+            return MouseKind::Drag(v);
+        }
+    }
+    MouseKind::Moved
+}
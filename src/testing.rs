@@ -0,0 +1,183 @@
+//!
+//! Helpers for testing event-handlers.
+//!
+//! Needs the feature `testing`. Add it to the dev-dependencies:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! rat-event = { version = "1.2", features = ["testing"] }
+//! ```
+//!
+//! ```rust
+//! use rat_event::testing::Harness;
+//! use rat_event::{HandleEvent, Outcome, Regular};
+//!
+//! #[derive(Default)]
+//! struct Counter(u32);
+//!
+//! impl HandleEvent<char, Regular, Outcome> for Counter {
+//!     fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+//!         match event {
+//!             '+' => {
+//!                 self.0 += 1;
+//!                 Outcome::Changed
+//!             }
+//!             ' ' => Outcome::Unchanged,
+//!             _ => Outcome::Continue,
+//!         }
+//!     }
+//! }
+//!
+//! let mut h = Harness::new(Counter::default());
+//! assert_eq!(h.feed(&'+'), Outcome::Changed);
+//! h.assert_consumed();
+//!
+//! h.feed_all(&['+', ' ', 'x']);
+//! h.assert_outcomes(&[
+//!     Outcome::Changed,
+//!     Outcome::Changed,
+//!     Outcome::Unchanged,
+//!     Outcome::Continue,
+//! ]);
+//! assert_eq!(h.state.0, 2);
+//! ```
+//!
+//! With crossterm, the module [crossterm] has builders for the usual
+//! events and gestures.
+
+use crate::clock::MockClock;
+use crate::{ConsumedEvent, HandleEvent, Outcome, Regular};
+use std::fmt::Debug;
+use std::time::Duration;
+
+#[cfg(feature = "crossterm")]
+pub mod crossterm;
+
+/// Runs events through an event-handler and records the results.
+///
+/// The harness owns the state, and calls its [HandleEvent] with
+/// the qualifier given at construction. Every result is recorded,
+/// the assertions check the recorded results.
+///
+/// It comes with a [MockClock]. Give `clock.shared()` to the
+/// state, which passes its time on to the time-dependent parts,
+/// and [advance](Harness::advance) the time between the events.
+#[derive(Debug, Clone)]
+pub struct Harness<S, Q = Regular, R = Outcome> {
+    /// The state under test.
+    pub state: S,
+    /// The qualifier used for every call.
+    pub qualifier: Q,
+    /// Time for the test.
+    pub clock: MockClock,
+    outcomes: Vec<R>,
+}
+
+impl<S> Harness<S> {
+    /// Harness for the [Regular] event-handler returning an [Outcome].
+    pub fn new(state: S) -> Self {
+        Self::with_qualifier(state, Regular)
+    }
+}
+
+impl<S, Q, R> Harness<S, Q, R> {
+    /// Harness for any event-handler.
+    pub fn with_qualifier(state: S, qualifier: Q) -> Self {
+        Self {
+            state,
+            qualifier,
+            clock: MockClock::new(),
+            outcomes: Vec::new(),
+        }
+    }
+
+    /// Move the clock forward.
+    pub fn advance(&mut self, duration: Duration) -> &mut Self {
+        self.clock.advance(duration);
+        self
+    }
+
+    /// Handles one event. Records and returns the result.
+    pub fn feed<E>(&mut self, event: &E) -> R
+    where
+        S: HandleEvent<E, Q, R>,
+        Q: Clone,
+        R: ConsumedEvent + Clone,
+    {
+        let r = self.state.handle(event, self.qualifier.clone());
+        self.outcomes.push(r.clone());
+        r
+    }
+
+    /// Handles all events, each one on its own.
+    pub fn feed_all<E>(&mut self, events: &[E]) -> &mut Self
+    where
+        S: HandleEvent<E, Q, R>,
+        Q: Clone,
+        R: ConsumedEvent + Clone,
+    {
+        for event in events {
+            self.feed(event);
+        }
+        self
+    }
+
+    /// All results since the last [clear](Harness::clear) or
+    /// [assert_outcomes](Harness::assert_outcomes).
+    pub fn outcomes(&self) -> &[R] {
+        &self.outcomes
+    }
+
+    /// The last result.
+    pub fn last(&self) -> Option<&R> {
+        self.outcomes.last()
+    }
+
+    /// Forget the recorded results.
+    pub fn clear(&mut self) {
+        self.outcomes.clear();
+    }
+
+    /// Returns the state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// The last result consumed the event.
+    #[track_caller]
+    pub fn assert_consumed(&self)
+    where
+        R: ConsumedEvent + Debug,
+    {
+        match self.outcomes.last() {
+            Some(r) if r.is_consumed() => {}
+            Some(r) => panic!("expected a consumed event, got {:?}", r),
+            None => panic!("expected a consumed event, but nothing was fed"),
+        }
+    }
+
+    /// The last result didn't consume the event.
+    #[track_caller]
+    pub fn assert_not_consumed(&self)
+    where
+        R: ConsumedEvent + Debug,
+    {
+        match self.outcomes.last() {
+            Some(r) if !r.is_consumed() => {}
+            Some(r) => panic!("expected an event that is not consumed, got {:?}", r),
+            None => panic!("expected an event that is not consumed, but nothing was fed"),
+        }
+    }
+
+    /// The results since the last check are exactly these.
+    ///
+    /// Clears the recorded results, the next check starts fresh.
+    #[track_caller]
+    pub fn assert_outcomes(&mut self, expected: &[R])
+    where
+        R: PartialEq + Debug,
+    {
+        assert_eq!(self.outcomes.as_slice(), expected, "outcomes");
+        self.outcomes.clear();
+    }
+}
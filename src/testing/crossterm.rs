@@ -0,0 +1,155 @@
+//!
+//! Ready-made crossterm events.
+//!
+//! Single events, and arrays for gestures that take more than one.
+//! All of them are presses of the left button or key presses, the
+//! positions are in cells.
+//!
+//! ```rust
+//! use rat_event::testing::crossterm::{click, double_click_seq, key_ctrl};
+//! use rat_event::ct_event;
+//!
+//! let [down, up] = click(3, 4);
+//! assert!(matches!(down, ct_event!(mouse down Left for x, y) if x == 3 && y == 4));
+//! assert!(matches!(up, ct_event!(mouse up Left)));
+//! assert_eq!(double_click_seq(3, 4).len(), 4);
+//! assert!(matches!(key_ctrl('s'), ct_event!(key press CONTROL-'s')));
+//! ```
+
+use crate::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+/// Mouse event.
+pub fn mouse(kind: MouseEventKind, x: u16, y: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: x,
+        row: y,
+        modifiers,
+    })
+}
+
+/// Left mouse-down.
+pub fn mouse_down(x: u16, y: u16) -> Event {
+    mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        x,
+        y,
+        KeyModifiers::NONE,
+    )
+}
+
+/// Left mouse-up.
+pub fn mouse_up(x: u16, y: u16) -> Event {
+    mouse(
+        MouseEventKind::Up(MouseButton::Left),
+        x,
+        y,
+        KeyModifiers::NONE,
+    )
+}
+
+/// Drag with the left button.
+pub fn mouse_drag(x: u16, y: u16) -> Event {
+    mouse(
+        MouseEventKind::Drag(MouseButton::Left),
+        x,
+        y,
+        KeyModifiers::NONE,
+    )
+}
+
+/// Mouse move without a button.
+pub fn mouse_moved(x: u16, y: u16) -> Event {
+    mouse(MouseEventKind::Moved, x, y, KeyModifiers::NONE)
+}
+
+/// Left click, mouse-down and mouse-up.
+pub fn click(x: u16, y: u16) -> [Event; 2] {
+    click_with(x, y, KeyModifiers::NONE)
+}
+
+/// Left click with CONTROL.
+pub fn click_ctrl(x: u16, y: u16) -> [Event; 2] {
+    click_with(x, y, KeyModifiers::CONTROL)
+}
+
+/// Left click with any modifiers.
+pub fn click_with(x: u16, y: u16, modifiers: KeyModifiers) -> [Event; 2] {
+    [
+        mouse(MouseEventKind::Down(MouseButton::Left), x, y, modifiers),
+        mouse(MouseEventKind::Up(MouseButton::Left), x, y, modifiers),
+    ]
+}
+
+/// Two left clicks at the same position.
+///
+/// They are a double-click if they are handled within
+/// the [double_click_timeout](crate::util::double_click_timeout).
+pub fn double_click_seq(x: u16, y: u16) -> [Event; 4] {
+    let [down, up] = click(x, y);
+    [down.clone(), up.clone(), down, up]
+}
+
+/// Drag with the left button.
+///
+/// A mouse-down at `from`, `steps` drags in a straight line
+/// that end at `to`, and a mouse-up at `to`. There is at least
+/// one drag.
+pub fn drag_seq(from: (u16, u16), to: (u16, u16), steps: u16) -> Vec<Event> {
+    let steps = steps.max(1);
+    let mut events = Vec::with_capacity(steps as usize + 2);
+    events.push(mouse_down(from.0, from.1));
+    for i in 1..=steps {
+        let step = |a: u16, b: u16| {
+            let d = (b as i32 - a as i32) * i as i32 / steps as i32;
+            (a as i32 + d) as u16
+        };
+        events.push(mouse_drag(step(from.0, to.0), step(from.1, to.1)));
+    }
+    events.push(mouse_up(to.0, to.1));
+    events
+}
+
+/// Scroll up at the position.
+pub fn scroll_up_at(x: u16, y: u16) -> Event {
+    mouse(MouseEventKind::ScrollUp, x, y, KeyModifiers::NONE)
+}
+
+/// Scroll down at the position.
+pub fn scroll_down_at(x: u16, y: u16) -> Event {
+    mouse(MouseEventKind::ScrollDown, x, y, KeyModifiers::NONE)
+}
+
+/// Key press.
+pub fn key_code(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new_with_kind(
+        code,
+        modifiers,
+        KeyEventKind::Press,
+    ))
+}
+
+/// Press of a character, without modifiers.
+///
+/// An uppercase character is sent without SHIFT, this matches
+/// `ct_event!(key press 'A')`.
+pub fn key(c: char) -> Event {
+    key_code(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+/// Press of a character with CONTROL.
+pub fn key_ctrl(c: char) -> Event {
+    key_code(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+/// Press of a character with ALT.
+pub fn key_alt(c: char) -> Event {
+    key_code(KeyCode::Char(c), KeyModifiers::ALT)
+}
+
+/// Presses for all characters of the text.
+pub fn type_text(text: &str) -> Vec<Event> {
+    text.chars().map(key).collect()
+}
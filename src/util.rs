@@ -2,13 +2,21 @@
 //! Some utility functions that pop up all the time.
 //!
 
-use crate::Outcome;
-use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crate::event::{self, Modifiers, MouseKind};
+use crate::{ConsumedEvent, DynHandleEvent, HandleEvent, Outcome, Regular};
 use ratatui::layout::{Position, Rect};
+use std::borrow::Cow;
 use std::cell::Cell;
+use std::cmp::max;
+use std::fmt::Debug;
+use std::iter::Peekable;
+use std::str::Chars;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::time::SystemTime;
 
+#[cfg(feature = "crossterm")]
+use crate::crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
 /// Which of the given rects is at the position.
 pub fn item_at(areas: &[Rect], x_pos: u16, y_pos: u16) -> Option<usize> {
     for (i, r) in areas.iter().enumerate() {
@@ -19,6 +27,14 @@ pub fn item_at(areas: &[Rect], x_pos: u16, y_pos: u16) -> Option<usize> {
     None
 }
 
+/// Is the position inside the visible part of the area?
+///
+/// For event-handlers with the [Clipped](crate::Clipped) qualifier.
+pub fn clip_hit(area: Rect, clip: Rect, x_pos: u16, y_pos: u16) -> bool {
+    area.intersection(clip)
+        .contains(Position::new(x_pos, y_pos))
+}
+
 /// Which row of the given contains the position.
 /// This uses only the vertical components of the given areas.
 ///
@@ -99,9 +115,10 @@ pub fn column_at_drag(encompassing: Rect, areas: &[Rect], x_pos: u16) -> Result<
 /// except Drag events.
 ///
 /// This should catch all events when using a popup area.
-pub fn mouse_trap(event: &crossterm::event::Event, area: Rect) -> Outcome {
+#[cfg(feature = "crossterm")]
+pub fn mouse_trap(event: &Event, area: Rect) -> Outcome {
     match event {
-        crossterm::event::Event::Mouse(MouseEvent {
+        Event::Mouse(MouseEvent {
             kind:
                 MouseEventKind::ScrollLeft
                 | MouseEventKind::ScrollRight
@@ -118,6 +135,194 @@ pub fn mouse_trap(event: &crossterm::event::Event, area: Rect) -> Outcome {
     }
 }
 
+/// Combines the outcomes of a list of event-handlers.
+/// The result is the max() of all of them, or `Outcome::Continue`
+/// for an empty list.
+pub fn max_outcome(iter: impl IntoIterator<Item = Outcome>) -> Outcome {
+    iter.into_iter().fold(Outcome::Continue, |a, b| a | b)
+}
+
+/// Calls the event-handlers in order until one of them consumes the event.
+///
+/// Returns the max() of all results, or `R::default()` for an empty list.
+///
+/// ```rust
+/// use rat_event::util::dispatch;
+/// use rat_event::{DynHandleEvent, HandleEvent, Outcome, Regular};
+///
+/// struct Button;
+/// impl HandleEvent<char, Regular, Outcome> for Button {
+///     fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+///         match event {
+///             'b' => Outcome::Changed,
+///             _ => Outcome::Continue,
+///         }
+///     }
+/// }
+///
+/// struct Label;
+/// impl HandleEvent<char, Regular, Outcome> for Label {
+///     fn handle(&mut self, _event: &char, _qualifier: Regular) -> Outcome {
+///         Outcome::Continue
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Input(String);
+/// impl HandleEvent<char, Regular, Outcome> for Input {
+///     fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+///         self.0.push(*event);
+///         Outcome::Changed
+///     }
+/// }
+///
+/// let mut children: Vec<Box<dyn DynHandleEvent<char, Regular, Outcome>>> = vec![
+///     Box::new(Button),
+///     Box::new(Label),
+///     Box::new(Input::default()),
+/// ];
+///
+/// assert_eq!(dispatch(&mut children, &'b', &Regular), Outcome::Changed);
+/// assert_eq!(dispatch(&mut children, &'x', &Regular), Outcome::Changed);
+/// ```
+pub fn dispatch<'a, E, Q, R>(
+    handlers: &mut [Box<dyn DynHandleEvent<E, Q, R> + 'a>],
+    event: &E,
+    qualifier: &Q,
+) -> R
+where
+    R: ConsumedEvent + Ord + Default,
+{
+    let mut r = R::default();
+    for h in handlers.iter_mut() {
+        let v = h.dyn_handle(event, qualifier);
+        let consumed = v.is_consumed();
+        r = max(r, v);
+        if consumed {
+            break;
+        }
+    }
+    r
+}
+
+/// Checks that a widget defined outcome is compatible with [Outcome].
+///
+/// * The conversions from and to Outcome must round-trip.
+/// * The order of the variants must be the same, otherwise max()
+///   and `|` don't work as expected.
+/// * is_consumed() must give the same answers.
+///
+/// Panics if any of this fails. Meant to be called in a test.
+///
+/// ```rust
+/// use rat_event::util::assert_outcome_compatible;
+/// use rat_event::OutcomeOf;
+///
+/// assert_outcome_compatible::<OutcomeOf<u32>>();
+/// ```
+pub fn assert_outcome_compatible<T>()
+where
+    T: From<Outcome> + Into<Outcome> + ConsumedEvent + Ord + Clone + Debug,
+{
+    let all = [Outcome::Continue, Outcome::Unchanged, Outcome::Changed];
+    for o in all {
+        let t = T::from(o);
+        assert_eq!(
+            t.is_consumed(),
+            o.is_consumed(),
+            "is_consumed() differs for {:?}",
+            o
+        );
+        let back: Outcome = t.clone().into();
+        assert_eq!(back, o, "conversion doesn't round-trip for {:?}", t);
+    }
+    for w in all.windows(2) {
+        let a = T::from(w[0]);
+        let b = T::from(w[1]);
+        assert!(a < b, "order differs: {:?} must be less than {:?}", a, b);
+    }
+}
+
+/// Checks that the order of a widget defined outcome is compatible
+/// with the order of [Outcome].
+///
+/// Takes a list of values of the outcome and checks for all pairs
+/// that `a <= b` implies `Outcome::from(a) <= Outcome::from(b)`.
+/// Any value that is not consumed must map to `Outcome::Continue`.
+///
+/// Panics if any of this fails. Meant to be called in a test.
+///
+/// ```rust
+/// use rat_event::util::assert_outcome_order;
+/// use rat_event::OutcomeOf;
+///
+/// assert_outcome_order(&[
+///     OutcomeOf::Continue,
+///     OutcomeOf::Unchanged,
+///     OutcomeOf::Changed,
+///     OutcomeOf::Value(1),
+///     OutcomeOf::Value(2),
+/// ]);
+/// ```
+pub fn assert_outcome_order<T>(values: &[T])
+where
+    T: Into<Outcome> + ConsumedEvent + Ord + Clone + Debug,
+{
+    for a in values {
+        let oa: Outcome = a.clone().into();
+        if !a.is_consumed() {
+            assert_eq!(
+                oa,
+                Outcome::Continue,
+                "{:?} is not consumed, but maps to {:?}",
+                a,
+                oa
+            );
+        }
+        for b in values {
+            let ob: Outcome = b.clone().into();
+            if a <= b {
+                assert!(
+                    oa <= ob,
+                    "order differs: {:?} <= {:?} but {:?} > {:?}",
+                    a,
+                    b,
+                    oa,
+                    ob
+                );
+            }
+        }
+    }
+}
+
+/// Predicate for [Filtered](crate::handler::Filtered).
+///
+/// Lets mouse events pass if they are inside the area.
+/// All other events pass too.
+#[cfg(feature = "crossterm")]
+pub fn mouse_in_rect(area: Rect) -> impl FnMut(&Event) -> bool {
+    move |event| match event {
+        Event::Mouse(MouseEvent { column, row, .. }) => area.contains(Position::new(*column, *row)),
+        _ => true,
+    }
+}
+
+/// Predicate for [Filtered](crate::handler::Filtered).
+///
+/// Only key events pass.
+#[cfg(feature = "crossterm")]
+pub fn keys_only() -> impl FnMut(&Event) -> bool {
+    |event| matches!(event, Event::Key(_))
+}
+
+/// Predicate for [Filtered](crate::handler::Filtered).
+///
+/// Only mouse events pass.
+#[cfg(feature = "crossterm")]
+pub fn mouse_only() -> impl FnMut(&Event) -> bool {
+    |event| matches!(event, Event::Mouse(_))
+}
+
 /// Click states for double click.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Clicks {
@@ -132,6 +337,9 @@ pub enum Clicks {
 ///
 /// This helps with double-click and mouse drag recognition.
 /// Add this to your widget state.
+///
+/// The functions take crossterm's `&MouseEvent`, the functions ending
+/// in `_event` take the backend-neutral [MouseEvent](crate::event::MouseEvent).
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct MouseFlags {
     /// Timestamp for double click
@@ -145,9 +353,12 @@ pub struct MouseFlags {
 }
 
 impl MouseFlags {
-    /// Returns column/row extracted from the Mouse-Event.
-    pub fn pos_of(&self, event: &MouseEvent) -> (u16, u16) {
-        (event.column, event.row)
+    /// Resets all flags. A drag or double-click in progress is forgotten.
+    pub fn clear(&self) {
+        self.time.set(None);
+        self.click.set(Clicks::None);
+        self.drag.set(false);
+        self.hover.set(false);
     }
 
     /// Which of the given rects is at the position.
@@ -202,26 +413,19 @@ impl MouseFlags {
     ) -> Result<usize, isize> {
         column_at_drag(encompassing, areas, x_pos)
     }
+}
+
+/// The functions for crossterm events.
+#[cfg(feature = "crossterm")]
+impl MouseFlags {
+    /// Returns column/row extracted from the Mouse-Event.
+    pub fn pos_of(&self, event: &MouseEvent) -> (u16, u16) {
+        (event.column, event.row)
+    }
 
     /// Checks if this is a hover event for the widget.
     pub fn hover(&self, area: Rect, event: &MouseEvent) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Moved,
-                column,
-                row,
-                modifiers: KeyModifiers::NONE,
-            } => {
-                let old_hover = self.hover.get();
-                if area.contains((*column, *row).into()) {
-                    self.hover.set(true);
-                } else {
-                    self.hover.set(false);
-                }
-                old_hover != self.hover.get()
-            }
-            _ => false,
-        }
+        self.hover_event(area, &event.into())
     }
 
     /// Checks if this is a drag event for the widget.
@@ -250,39 +454,7 @@ impl MouseFlags {
     ///
     /// This function handles that case.
     pub fn drag2(&self, area: Rect, event: &MouseEvent, filter: KeyModifiers) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => {
-                if area.contains((*column, *row).into()) {
-                    self.drag.set(true);
-                } else {
-                    self.drag.set(false);
-                }
-            }
-            MouseEvent {
-                kind: MouseEventKind::Drag(MouseButton::Left),
-                modifiers,
-                ..
-            } if *modifiers == filter => {
-                if self.drag.get() {
-                    return true;
-                }
-            }
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left) | MouseEventKind::Moved,
-                ..
-            } => {
-                self.drag.set(false);
-            }
-
-            _ => {}
-        }
-
-        false
+        self.drag2_event(area, &event.into(), filter.into())
     }
 
     /// Checks for double-click events.
@@ -304,84 +476,179 @@ impl MouseFlags {
 
     /// Checks for double-click events.
     /// This one can have an extra KeyModifiers.
-    ///
-    /// This can be integrated in the event-match with a guard:
-    ///
-    /// ```rust ignore
-    /// match event {
-    ///         Event::Mouse(m) if state.mouse.doubleclick(state.area, m) => {
-    ///             state.flip = !state.flip;
-    ///             Outcome::Changed
-    ///         }
-    /// }
-    /// ```
-    ///
     pub fn doubleclick2(&self, area: Rect, event: &MouseEvent, filter: KeyModifiers) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                if area.contains((*column, *row).into()) {
-                    match self.click.get() {
-                        Clicks::Up1(_) => {
-                            if let Some(time) = self.time.get() {
-                                if time.elapsed().unwrap_or_default().as_millis() as u32
-                                    > double_click_timeout()
-                                {
-                                    self.time.set(Some(SystemTime::now()));
-                                    self.click.set(Clicks::Down1(0));
-                                    break 'f false;
-                                }
-                            }
-                            self.click.set(Clicks::Down2(0));
-                        }
-                        _ => {
-                            self.time.set(Some(SystemTime::now()));
-                            self.click.set(Clicks::Down1(0));
-                        }
-                    }
-                    break 'f false;
-                } else {
-                    self.time.set(None);
-                    self.click.set(Clicks::None);
-                    break 'f false;
-                }
-            }
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                if area.contains((*column, *row).into()) {
-                    match self.click.get() {
-                        Clicks::Down1(_) => {
-                            self.click.set(Clicks::Up1(0));
-                            break 'f false;
-                        }
-                        Clicks::Up1(_) => {
-                            self.click.set(Clicks::None);
-                            break 'f true;
-                        }
-                        Clicks::Down2(_) => {
-                            self.click.set(Clicks::None);
-                            break 'f true;
-                        }
-                        _ => {
-                            self.click.set(Clicks::None);
-                            break 'f false;
-                        }
-                    }
-                } else {
-                    self.click.set(Clicks::None);
-                    break 'f false;
-                }
-            }
-            _ => false,
+        self.doubleclick2_at(area, event, filter, SystemTime::now())
+    }
+
+    /// Checks for double-click events.
+    /// This one takes the current time, e.g. from a
+    /// [MockClock](crate::clock::MockClock).
+    pub fn doubleclick2_at(
+        &self,
+        area: Rect,
+        event: &MouseEvent,
+        filter: KeyModifiers,
+        now: SystemTime,
+    ) -> bool {
+        self.doubleclick2_event_at(area, &event.into(), filter.into(), now)
+    }
+}
+
+/// The same functions for the backend-neutral [MouseEvent](crate::event::MouseEvent).
+impl MouseFlags {
+    /// Returns column/row extracted from the Mouse-Event.
+    pub fn pos_of_event(&self, event: &event::MouseEvent) -> (u16, u16) {
+        (event.column, event.row)
+    }
+
+    /// Checks if this is a hover event for the widget.
+    pub fn hover_event(&self, area: Rect, event: &event::MouseEvent) -> bool {
+        let hover = Cell::new(self.hover.get().then_some(0));
+        let r = hover_step(&hover, &[area], event);
+        self.hover.set(hover.get().is_some());
+        r
+    }
+
+    /// Checks if this is a drag event for the widget.
+    pub fn drag_event(&self, area: Rect, event: &event::MouseEvent) -> bool {
+        self.drag2_event(area, event, Modifiers::NONE)
+    }
+
+    /// Checks if this is a drag event for the widget, with modifiers.
+    pub fn drag2_event(&self, area: Rect, event: &event::MouseEvent, filter: Modifiers) -> bool {
+        let drag = Cell::new(self.drag.get().then_some(0));
+        let r = drag_step(&drag, &[area], event, filter);
+        self.drag.set(drag.get().is_some());
+        r
+    }
+
+    /// Checks for double-click events.
+    pub fn doubleclick_event(&self, area: Rect, event: &event::MouseEvent) -> bool {
+        self.doubleclick2_event(area, event, Modifiers::NONE)
+    }
+
+    /// Checks for double-click events, with modifiers.
+    pub fn doubleclick2_event(
+        &self,
+        area: Rect,
+        event: &event::MouseEvent,
+        filter: Modifiers,
+    ) -> bool {
+        self.doubleclick2_event_at(area, event, filter, SystemTime::now())
+    }
+
+    /// Checks for double-click events, with modifiers and the current time.
+    pub fn doubleclick2_event_at(
+        &self,
+        area: Rect,
+        event: &event::MouseEvent,
+        filter: Modifiers,
+        now: SystemTime,
+    ) -> bool {
+        doubleclick_step(&self.click, &self.time, &[area], event, filter, now)
+    }
+}
+
+/// Variants for a widget that is only partly visible.
+///
+/// With a clip these use only the part of the area inside the clip.
+/// Without one they are the same as the plain functions.
+/// See [Clipped](crate::Clipped).
+impl MouseFlags {
+    /// Checks if this is a hover event for the visible part of the widget.
+    #[cfg(feature = "crossterm")]
+    pub fn hover_clipped(&self, area: Rect, clip: Option<Rect>, event: &MouseEvent) -> bool {
+        self.hover(clip_area(area, clip), event)
+    }
+
+    /// Checks if this is a drag event for the widget. The drag must
+    /// have been started in the visible part.
+    #[cfg(feature = "crossterm")]
+    pub fn drag_clipped(
+        &self,
+        area: Rect,
+        clip: Option<Rect>,
+        event: &MouseEvent,
+        filter: KeyModifiers,
+    ) -> bool {
+        self.drag2(clip_area(area, clip), event, filter)
+    }
+
+    /// Checks for double-click events in the visible part of the widget.
+    #[cfg(feature = "crossterm")]
+    pub fn doubleclick_clipped(
+        &self,
+        area: Rect,
+        clip: Option<Rect>,
+        event: &MouseEvent,
+        filter: KeyModifiers,
+    ) -> bool {
+        self.doubleclick2(clip_area(area, clip), event, filter)
+    }
+
+    /// Checks if this is a hover event for the visible part of the widget.
+    pub fn hover_clipped_event(
+        &self,
+        area: Rect,
+        clip: Option<Rect>,
+        event: &event::MouseEvent,
+    ) -> bool {
+        self.hover_event(clip_area(area, clip), event)
+    }
+
+    /// Checks if this is a drag event for the widget. The drag must
+    /// have been started in the visible part.
+    pub fn drag_clipped_event(
+        &self,
+        area: Rect,
+        clip: Option<Rect>,
+        event: &event::MouseEvent,
+        filter: Modifiers,
+    ) -> bool {
+        self.drag2_event(clip_area(area, clip), event, filter)
+    }
+
+    /// Checks for double-click events in the visible part of the widget.
+    pub fn doubleclick_clipped_event(
+        &self,
+        area: Rect,
+        clip: Option<Rect>,
+        event: &event::MouseEvent,
+        filter: Modifiers,
+    ) -> bool {
+        self.doubleclick2_event(clip_area(area, clip), event, filter)
+    }
+}
+
+fn clip_area(area: Rect, clip: Option<Rect>) -> Rect {
+    match clip {
+        Some(clip) => area.intersection(clip),
+        None => area,
+    }
+}
+
+/// Clears the flags when the terminal loses the focus.
+///
+/// Otherwise, a drag that was in progress would continue with the
+/// next mouse event after the focus returns.
+/// The event is never consumed.
+#[cfg(feature = "crossterm")]
+impl HandleEvent<Event, Regular, Outcome> for MouseFlags {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        if let Event::FocusLost = event {
+            self.clear();
         }
+        Outcome::Continue
+    }
+}
+
+/// The same for the backend-neutral events.
+impl HandleEvent<event::Event, Regular, Outcome> for MouseFlags {
+    fn handle(&mut self, event: &event::Event, _qualifier: Regular) -> Outcome {
+        if let event::Event::FocusLost = event {
+            self.clear();
+        }
+        Outcome::Continue
     }
 }
 
@@ -389,6 +656,9 @@ impl MouseFlags {
 ///
 /// This helps with double-click and mouse drag recognition.
 /// Add this to your widget state.
+///
+/// The functions take crossterm's `&MouseEvent`, the functions ending
+/// in `_event` take the backend-neutral [MouseEvent](crate::event::MouseEvent).
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct MouseFlagsN {
     /// Timestamp for double click
@@ -402,9 +672,12 @@ pub struct MouseFlagsN {
 }
 
 impl MouseFlagsN {
-    /// Returns column/row extracted from the Mouse-Event.
-    pub fn pos_of(&self, event: &MouseEvent) -> (u16, u16) {
-        (event.column, event.row)
+    /// Resets all flags. A drag or double-click in progress is forgotten.
+    pub fn clear(&self) {
+        self.time.set(None);
+        self.click.set(Clicks::None);
+        self.drag.set(None);
+        self.hover.set(None);
     }
 
     /// Which of the given rects is at the position.
@@ -459,26 +732,19 @@ impl MouseFlagsN {
     ) -> Result<usize, isize> {
         column_at_drag(encompassing, areas, x_pos)
     }
+}
+
+/// The functions for crossterm events.
+#[cfg(feature = "crossterm")]
+impl MouseFlagsN {
+    /// Returns column/row extracted from the Mouse-Event.
+    pub fn pos_of(&self, event: &MouseEvent) -> (u16, u16) {
+        (event.column, event.row)
+    }
 
     /// Checks if this is a hover event for the widget.
     pub fn hover(&self, areas: &[Rect], event: &MouseEvent) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Moved,
-                column,
-                row,
-                modifiers: KeyModifiers::NONE,
-            } => {
-                let old_hover = self.hover.get();
-                if let Some(n) = self.item_at(areas, *column, *row) {
-                    self.hover.set(Some(n));
-                } else {
-                    self.hover.set(None);
-                }
-                old_hover != self.hover.get()
-            }
-            _ => false,
-        }
+        self.hover_event(areas, &event.into())
     }
 
     /// Checks if this is a drag event for the widget.
@@ -498,40 +764,7 @@ impl MouseFlagsN {
     ///
     /// This function handles that case.
     pub fn drag2(&self, areas: &[Rect], event: &MouseEvent, filter: KeyModifiers) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => {
-                self.drag.set(None);
-                for (n, area) in areas.iter().enumerate() {
-                    if area.contains((*column, *row).into()) {
-                        self.drag.set(Some(n));
-                    }
-                }
-            }
-            MouseEvent {
-                kind: MouseEventKind::Drag(MouseButton::Left),
-                modifiers,
-                ..
-            } if *modifiers == filter => {
-                if self.drag.get().is_some() {
-                    return true;
-                }
-            }
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left) | MouseEventKind::Moved,
-                ..
-            } => {
-                self.drag.set(None);
-            }
-
-            _ => {}
-        }
-
-        false
+        self.drag2_event(areas, &event.into(), filter.into())
     }
 
     /// Checks for double-click events.
@@ -540,7 +773,7 @@ impl MouseFlagsN {
     ///
     /// ```rust ignore
     /// match event {
-    ///         Event::Mouse(m) if state.mouse.doubleclick(state.area, m) => {
+    ///         Event::Mouse(m) if state.mouse.doubleclick(&state.areas, m) => {
     ///             state.flip = !state.flip;
     ///             Outcome::Changed
     ///         }
@@ -553,101 +786,204 @@ impl MouseFlagsN {
 
     /// Checks for double-click events.
     /// This one can have an extra KeyModifiers.
-    ///
-    /// This can be integrated in the event-match with a guard:
-    ///
-    /// ```rust ignore
-    /// match event {
-    ///         Event::Mouse(m) if state.mouse.doubleclick(state.area, m) => {
-    ///             state.flip = !state.flip;
-    ///             Outcome::Changed
-    ///         }
-    /// }
-    /// ```
-    ///
     pub fn doubleclick2(&self, areas: &[Rect], event: &MouseEvent, filter: KeyModifiers) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                for (n, area) in areas.iter().enumerate() {
-                    if area.contains((*column, *row).into()) {
-                        match self.click.get() {
-                            Clicks::Up1(v) => {
-                                if let Some(time) = self.time.get() {
-                                    if time.elapsed().unwrap_or_default().as_millis() as u32
-                                        > double_click_timeout()
-                                    {
-                                        self.time.set(Some(SystemTime::now()));
-                                        self.click.set(Clicks::Down1(n));
-                                        break 'f false;
-                                    }
-                                }
-                                if n == v {
-                                    self.click.set(Clicks::Down2(n));
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            _ => {
-                                self.time.set(Some(SystemTime::now()));
-                                self.click.set(Clicks::Down1(n));
-                            }
-                        }
-                        break 'f false;
-                    }
+        self.doubleclick2_at(areas, event, filter, SystemTime::now())
+    }
+
+    /// Checks for double-click events.
+    /// This one takes the current time, e.g. from a
+    /// [MockClock](crate::clock::MockClock).
+    pub fn doubleclick2_at(
+        &self,
+        areas: &[Rect],
+        event: &MouseEvent,
+        filter: KeyModifiers,
+        now: SystemTime,
+    ) -> bool {
+        self.doubleclick2_event_at(areas, &event.into(), filter.into(), now)
+    }
+}
+
+/// The same functions for the backend-neutral [MouseEvent](crate::event::MouseEvent).
+impl MouseFlagsN {
+    /// Returns column/row extracted from the Mouse-Event.
+    pub fn pos_of_event(&self, event: &event::MouseEvent) -> (u16, u16) {
+        (event.column, event.row)
+    }
+
+    /// Checks if this is a hover event for the widget.
+    pub fn hover_event(&self, areas: &[Rect], event: &event::MouseEvent) -> bool {
+        hover_step(&self.hover, areas, event)
+    }
+
+    /// Checks if this is a drag event for the widget.
+    pub fn drag_event(&self, areas: &[Rect], event: &event::MouseEvent) -> bool {
+        self.drag2_event(areas, event, Modifiers::NONE)
+    }
+
+    /// Checks if this is a drag event for the widget, with modifiers.
+    pub fn drag2_event(
+        &self,
+        areas: &[Rect],
+        event: &event::MouseEvent,
+        filter: Modifiers,
+    ) -> bool {
+        drag_step(&self.drag, areas, event, filter)
+    }
+
+    /// Checks for double-click events.
+    pub fn doubleclick_event(&self, areas: &[Rect], event: &event::MouseEvent) -> bool {
+        self.doubleclick2_event(areas, event, Modifiers::NONE)
+    }
+
+    /// Checks for double-click events, with modifiers.
+    pub fn doubleclick2_event(
+        &self,
+        areas: &[Rect],
+        event: &event::MouseEvent,
+        filter: Modifiers,
+    ) -> bool {
+        self.doubleclick2_event_at(areas, event, filter, SystemTime::now())
+    }
+
+    /// Checks for double-click events, with modifiers and the current time.
+    pub fn doubleclick2_event_at(
+        &self,
+        areas: &[Rect],
+        event: &event::MouseEvent,
+        filter: Modifiers,
+        now: SystemTime,
+    ) -> bool {
+        doubleclick_step(&self.click, &self.time, areas, event, filter, now)
+    }
+}
+
+/// Clears the flags when the terminal loses the focus.
+///
+/// The event is never consumed.
+#[cfg(feature = "crossterm")]
+impl HandleEvent<Event, Regular, Outcome> for MouseFlagsN {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        if let Event::FocusLost = event {
+            self.clear();
+        }
+        Outcome::Continue
+    }
+}
+
+/// The same for the backend-neutral events.
+impl HandleEvent<event::Event, Regular, Outcome> for MouseFlagsN {
+    fn handle(&mut self, event: &event::Event, _qualifier: Regular) -> Outcome {
+        if let event::Event::FocusLost = event {
+            self.clear();
+        }
+        Outcome::Continue
+    }
+}
+
+/// Hover state machine. Returns true if the hovered area changed.
+fn hover_step(hover: &Cell<Option<usize>>, areas: &[Rect], m: &event::MouseEvent) -> bool {
+    if m.kind == MouseKind::Moved && m.modifiers == Modifiers::NONE {
+        let old_hover = hover.get();
+        hover.set(item_at(areas, m.column, m.row));
+        old_hover != hover.get()
+    } else {
+        false
+    }
+}
+
+/// Drag state machine. Returns true for a drag that started in one
+/// of the areas.
+fn drag_step(
+    drag: &Cell<Option<usize>>,
+    areas: &[Rect],
+    m: &event::MouseEvent,
+    filter: Modifiers,
+) -> bool {
+    match m.kind {
+        MouseKind::Down(event::MouseButton::Left) if m.modifiers == filter => {
+            drag.set(None);
+            for (n, area) in areas.iter().enumerate() {
+                if area.contains((m.column, m.row).into()) {
+                    drag.set(Some(n));
                 }
-                self.time.set(None);
-                self.click.set(Clicks::None);
-                false
             }
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                for (n, area) in areas.iter().enumerate() {
-                    if area.contains((*column, *row).into()) {
-                        match self.click.get() {
-                            Clicks::Down1(v) => {
-                                if n == v {
-                                    self.click.set(Clicks::Up1(v));
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            Clicks::Up1(v) => {
-                                if n == v {
-                                    self.click.set(Clicks::None);
-                                    break 'f true;
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            Clicks::Down2(v) => {
-                                if n == v {
-                                    self.click.set(Clicks::None);
-                                    break 'f true;
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            _ => {
-                                self.click.set(Clicks::None);
-                            }
+            false
+        }
+        MouseKind::Drag(event::MouseButton::Left) if m.modifiers == filter => drag.get().is_some(),
+        MouseKind::Up(event::MouseButton::Left) | MouseKind::Moved => {
+            drag.set(None);
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Double-click state machine. Returns true for the second mouse-up
+/// in the same area.
+fn doubleclick_step(
+    click: &Cell<Clicks>,
+    time: &Cell<Option<SystemTime>>,
+    areas: &[Rect],
+    m: &event::MouseEvent,
+    filter: Modifiers,
+    now: SystemTime,
+) -> bool {
+    if m.modifiers != filter {
+        return false;
+    }
+    match m.kind {
+        MouseKind::Down(event::MouseButton::Left) => {
+            let Some(n) = item_at(areas, m.column, m.row) else {
+                time.set(None);
+                click.set(Clicks::None);
+                return false;
+            };
+            match click.get() {
+                Clicks::Up1(v) => {
+                    if let Some(t) = time.get() {
+                        if now.duration_since(t).unwrap_or_default().as_millis() as u32
+                            > double_click_timeout()
+                        {
+                            time.set(Some(now));
+                            click.set(Clicks::Down1(n));
+                            return false;
                         }
-                        break 'f false;
                     }
+                    if n == v {
+                        click.set(Clicks::Down2(n));
+                    } else {
+                        click.set(Clicks::None);
+                    }
+                }
+                _ => {
+                    time.set(Some(now));
+                    click.set(Clicks::Down1(n));
                 }
-                self.click.set(Clicks::None);
-                false
             }
-            _ => false,
+            false
         }
+        MouseKind::Up(event::MouseButton::Left) => {
+            let Some(n) = item_at(areas, m.column, m.row) else {
+                click.set(Clicks::None);
+                return false;
+            };
+            match click.get() {
+                Clicks::Down1(v) if n == v => {
+                    click.set(Clicks::Up1(v));
+                    false
+                }
+                Clicks::Up1(v) | Clicks::Down2(v) if n == v => {
+                    click.set(Clicks::None);
+                    true
+                }
+                _ => {
+                    click.set(Clicks::None);
+                    false
+                }
+            }
+        }
+        _ => false,
     }
 }
 
@@ -673,7 +1009,7 @@ static ENHANCED_KEYS: AtomicBool = AtomicBool::new(false);
 /// This flag is set during startup of the application when
 /// configuring the terminal.
 pub fn have_keyboard_enhancement() -> bool {
-     ENHANCED_KEYS.load(Ordering::Acquire)
+    ENHANCED_KEYS.load(Ordering::Acquire)
 }
 
 /// Set the flag for enhanced keys.
@@ -681,10 +1017,115 @@ pub fn have_keyboard_enhancement() -> bool {
 /// For windows + crossterm this can always be set true.
 ///
 /// For unix this needs to activate the enhancements with PushKeyboardEnhancementFlags
-/// + it still needs to query supports_keyboard_enhancement().
+/// and it still needs to query supports_keyboard_enhancement().
 /// If you enable REPORT_ALL_KEYS_AS_ESCAPE_CODES you need REPORT_ALTERNATE_KEYS to,
 /// otherwise shift+key will not return something useful.
 ///
 pub fn set_have_keyboard_enhancement(have: bool) {
     ENHANCED_KEYS.store(have, Ordering::Release);
-}
\ No newline at end of file
+}
+
+/// Cleans up the text of a `Event::Paste`.
+///
+/// Removes all control characters except newline and tab.
+/// Escape sequences are removed as a whole, e.g. the color
+/// `\x1b[31m` or the title `\x1b]0;title\x07`, not only the ESC.
+/// An ESC that doesn't start a sequence is removed alone, the
+/// following text is kept.
+/// If `crlf` is true, `\r\n` and a single `\r` are converted to `\n`,
+/// otherwise `\r` is kept as is.
+///
+/// Bracketed paste must be enabled with `EnableBracketedPaste`.
+/// Otherwise, the terminal sends the pasted text as a burst of
+/// regular key events, where each line break is an Enter key.
+/// Those are handled by the normal key bindings, and there is
+/// no way to tell them apart from typing.
+///
+/// The repeat filters see such a burst as very fast typing.
+/// `crossterm::key_press_only` lets all of it pass, the keys
+/// arrive as presses. `crossterm::RepeatThrottle` drops a
+/// character that follows the same character within its interval,
+/// e.g. the second `l` of `hello`. Don't throttle the character
+/// keys of a text input, or pasted text loses characters.
+pub fn sanitize_paste(text: &str, crlf: bool) -> Cow<'_, str> {
+    let keep = |c: char| !c.is_control() || c == '\n' || c == '\t' || (!crlf && c == '\r');
+    if text.chars().all(keep) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' if crlf => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                result.push('\n');
+            }
+            // anything that doesn't continue the sequence is kept.
+            '\x1b' => match chars.peek() {
+                Some('[') => {
+                    chars.next();
+                    skip_csi(&mut chars);
+                }
+                Some(']' | 'P' | 'X' | '^' | '_') => {
+                    chars.next();
+                    skip_control_string(&mut chars);
+                }
+                Some('\x20'..='\x2f') => {
+                    // intermediate bytes up to the final byte.
+                    while let Some('\x20'..='\x2f') = chars.peek() {
+                        chars.next();
+                    }
+                    if let Some('\x30'..='\x7e') = chars.peek() {
+                        chars.next();
+                    }
+                }
+                Some('\x30'..='\x7e') => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            // 8-bit CSI
+            '\u{9b}' => skip_csi(&mut chars),
+            // 8-bit DCS, SOS, OSC, PM, APC
+            '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => skip_control_string(&mut chars),
+            c if keep(c) => result.push(c),
+            _ => {}
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Skips the parameters and the final byte of a CSI sequence.
+fn skip_csi(chars: &mut Peekable<Chars<'_>>) {
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\x20'..='\x3f' => {
+                chars.next();
+            }
+            '\x40'..='\x7e' => {
+                chars.next();
+                break;
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Skips an OSC/DCS/.. string up to BEL or the string terminator.
+fn skip_control_string(chars: &mut Peekable<Chars<'_>>) {
+    while let Some(c) = chars.next() {
+        match c {
+            '\x07' | '\u{9c}' => break,
+            '\x1b' => {
+                if chars.peek() == Some(&'\\') {
+                    chars.next();
+                }
+                break;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//!
+//! Conversions from the fields of DOM events.
+//!
+//! For ratatui apps that render in the browser. The DOM
+//! `KeyboardEvent` and `MouseEvent` are converted into the
+//! backend-neutral [event](crate::event) model, so the same
+//! event-handlers and [MouseFlags](crate::util::MouseFlags) work
+//! in wasm builds.
+//!
+//! This needs only the raw fields of the DOM events, there is no
+//! dependency on web-sys. Mouse positions must already be in cells.
+//!
+//! Widgets that handle crossterm events can be used too. With the
+//! feature `crossterm` the results convert into the crossterm events,
+//! and [ct_event!](crate::ct_event) matches them as usual.
+//!
+//! ```rust
+//! use rat_event::event::{KeyCode, Modifiers};
+//! use rat_event::web::key_from_dom;
+//!
+//! let key = key_from_dom("ArrowUp", "ArrowUp", false, false, true, false).expect("key");
+//! assert_eq!(key.code, KeyCode::Up);
+//! assert_eq!(key.modifiers, Modifiers::SHIFT);
+//!
+//! // dead keys wait for the next key.
+//! assert!(key_from_dom("Dead", "Quote", false, false, false, false).is_none());
+//! ```
+
+use crate::event::{KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseKind};
+
+/// Modifiers from the flags of a DOM event.
+///
+/// The meta key (Cmd or the Windows key) is mapped to
+/// [Modifiers::SUPER], as crossterm does.
+pub fn modifiers_from_dom(ctrl: bool, alt: bool, shift: bool, meta: bool) -> Modifiers {
+    let mut m = Modifiers::NONE;
+    if ctrl {
+        m |= Modifiers::CONTROL;
+    }
+    if alt {
+        m |= Modifiers::ALT;
+    }
+    if shift {
+        m |= Modifiers::SHIFT;
+    }
+    if meta {
+        m |= Modifiers::SUPER;
+    }
+    m
+}
+
+/// Key press from the fields of a DOM `keydown` event.
+///
+/// * key - `KeyboardEvent.key`, the printed character or the name
+///   of the key.
+/// * code - `KeyboardEvent.code`, the physical key. Only used if the
+///   key is "Unidentified".
+///
+/// Returns None for
+/// * dead keys and IME composition ("Dead", "Process"). The
+///   composed character arrives with a later event.
+/// * the modifier keys by themselves, as a terminal doesn't
+///   report them either.
+///
+/// Named keys that are not part of the model return [KeyCode::Other].
+///
+/// Shift+Tab is returned as BackTab, with Shift, as crossterm does.
+/// For `keyup` set the kind of the result to Release.
+pub fn key_from_dom(
+    key: &str,
+    code: &str,
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+) -> Option<KeyEvent> {
+    let modifiers = modifiers_from_dom(ctrl, alt, shift, meta);
+
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyEvent::new(KeyCode::Char(c), modifiers));
+    }
+
+    let code = match key {
+        "Dead" | "Process" => return None,
+        "Shift" | "Control" | "Alt" | "AltGraph" | "Meta" | "OS" | "Super" | "Hyper"
+        | "CapsLock" | "NumLock" | "ScrollLock" | "Fn" | "FnLock" => return None,
+        "Unidentified" => return key_from_code(code, shift).map(|v| KeyEvent::new(v, modifiers)),
+        "Backspace" => KeyCode::Backspace,
+        "Enter" => KeyCode::Enter,
+        "ArrowLeft" | "Left" => KeyCode::Left,
+        "ArrowRight" | "Right" => KeyCode::Right,
+        "ArrowUp" | "Up" => KeyCode::Up,
+        "ArrowDown" | "Down" => KeyCode::Down,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Tab" if shift => KeyCode::BackTab,
+        "Tab" => KeyCode::Tab,
+        "Delete" | "Del" => KeyCode::Delete,
+        "Insert" => KeyCode::Insert,
+        "Escape" | "Esc" => KeyCode::Esc,
+        "Spacebar" => KeyCode::Char(' '),
+        _ => function_key(key).unwrap_or(KeyCode::Other),
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// "F1" to "F24".
+fn function_key(key: &str) -> Option<KeyCode> {
+    let n = key.strip_prefix('F')?.parse::<u8>().ok()?;
+    (1..=24).contains(&n).then_some(KeyCode::F(n))
+}
+
+/// Fallback to the physical key, for letters, digits and space.
+fn key_from_code(code: &str, shift: bool) -> Option<KeyCode> {
+    if let Some(c) = code.strip_prefix("Key") {
+        let mut c = c.chars();
+        if let (Some(c), None) = (c.next(), c.next()) {
+            if c.is_ascii_uppercase() {
+                return Some(KeyCode::Char(if shift {
+                    c
+                } else {
+                    c.to_ascii_lowercase()
+                }));
+            }
+        }
+        return None;
+    }
+    if let Some(c) = code
+        .strip_prefix("Digit")
+        .or_else(|| code.strip_prefix("Numpad"))
+    {
+        let mut c = c.chars();
+        if let (Some(c), None) = (c.next(), c.next()) {
+            if c.is_ascii_digit() {
+                return Some(KeyCode::Char(c));
+            }
+        }
+        return None;
+    }
+    match code {
+        "Space" => Some(KeyCode::Char(' ')),
+        _ => None,
+    }
+}
+
+/// Mouse event from the fields of a DOM mouse event.
+///
+/// * kind - the event type, "mousedown", "mouseup" or "mousemove".
+/// * button - `MouseEvent.button`, 0 is left, 1 is middle, 2 is right.
+///   For "mousemove" this is the button that is held down, or -1 if
+///   there is none. Use [button_from_buttons] to get it from
+///   `MouseEvent.buttons`.
+/// * column, row - the position in cells.
+///
+/// A "mousemove" with a button held down is a drag. Returns None for
+/// any other kind and for the extra buttons.
+pub fn mouse_from_dom(
+    kind: &str,
+    button: i16,
+    column: u16,
+    row: u16,
+    modifiers: Modifiers,
+) -> Option<MouseEvent> {
+    let button = match button {
+        0 => Some(MouseButton::Left),
+        1 => Some(MouseButton::Middle),
+        2 => Some(MouseButton::Right),
+        _ => None,
+    };
+    let kind = match (kind, button) {
+        ("mousedown", Some(b)) => MouseKind::Down(b),
+        ("mouseup", Some(b)) => MouseKind::Up(b),
+        ("mousemove", Some(b)) => MouseKind::Drag(b),
+        ("mousemove", None) => MouseKind::Moved,
+        _ => return None,
+    };
+    Some(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers,
+    })
+}
+
+/// The button for a "mousemove" from `MouseEvent.buttons`.
+///
+/// The bitmask uses a different numbering than `MouseEvent.button`.
+/// Returns the button for [mouse_from_dom], with left before right
+/// before middle, or -1 if no button is held down.
+pub fn button_from_buttons(buttons: u16) -> i16 {
+    if buttons & 1 != 0 {
+        0
+    } else if buttons & 2 != 0 {
+        2
+    } else if buttons & 4 != 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Scroll event from the deltas of a DOM "wheel" event.
+///
+/// The vertical delta wins if both are set. Returns None if
+/// both are 0.
+pub fn wheel_from_dom(
+    delta_x: f64,
+    delta_y: f64,
+    column: u16,
+    row: u16,
+    modifiers: Modifiers,
+) -> Option<MouseEvent> {
+    let kind = if delta_y > 0.0 {
+        MouseKind::ScrollDown
+    } else if delta_y < 0.0 {
+        MouseKind::ScrollUp
+    } else if delta_x > 0.0 {
+        MouseKind::ScrollRight
+    } else if delta_x < 0.0 {
+        MouseKind::ScrollLeft
+    } else {
+        return None;
+    };
+    Some(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers,
+    })
+}
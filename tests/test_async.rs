@@ -0,0 +1,137 @@
+#![cfg(feature = "async")]
+
+use rat_event::async_handler::{BoxFuture, HandleEventAsync};
+use rat_event::{flow, try_flow, HandleEvent, Outcome, OutcomeOf, Regular};
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+            return v;
+        }
+    }
+}
+
+struct SyncState;
+
+impl HandleEvent<char, Regular, Outcome> for SyncState {
+    fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+        if *event == 'a' {
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+struct AsyncState {
+    count: u32,
+}
+
+async fn lookup(event: char) -> bool {
+    event == 'b'
+}
+
+impl HandleEventAsync<char, Regular, Outcome> for AsyncState {
+    fn handle_async<'a>(
+        &'a mut self,
+        event: &'a char,
+        _qualifier: Regular,
+    ) -> BoxFuture<'a, Outcome> {
+        Box::pin(async move {
+            if lookup(*event).await {
+                self.count += 1;
+                Outcome::Changed
+            } else {
+                Outcome::Continue
+            }
+        })
+    }
+}
+
+#[test]
+fn test_bridge() {
+    let mut state = SyncState;
+    assert_eq!(
+        block_on(state.handle_async(&'a', Regular)),
+        Outcome::Changed
+    );
+    assert_eq!(
+        block_on(state.handle_async(&'x', Regular)),
+        Outcome::Continue
+    );
+}
+
+#[test]
+fn test_async() {
+    let mut state = AsyncState { count: 0 };
+    assert_eq!(
+        block_on(state.handle_async(&'b', Regular)),
+        Outcome::Changed
+    );
+    assert_eq!(
+        block_on(state.handle_async(&'x', Regular)),
+        Outcome::Continue
+    );
+    assert_eq!(state.count, 1);
+
+    let mut handlers: Vec<Box<dyn HandleEventAsync<char, Regular, Outcome>>> =
+        vec![Box::new(SyncState), Box::new(AsyncState { count: 0 })];
+    let mut r = Outcome::Continue;
+    for h in handlers.iter_mut() {
+        r = block_on(h.handle_async(&'b', Regular));
+        if r != Outcome::Continue {
+            break;
+        }
+    }
+    assert_eq!(r, Outcome::Changed);
+}
+
+async fn handle_all(state: &mut AsyncState, event: char, focus: Outcome) -> Outcome {
+    flow!(SyncState.handle_async(&event, Regular).await);
+    flow!(state.handle_async(&event, Regular).await, consider focus);
+    focus
+}
+
+#[test]
+fn test_flow_async_fn() {
+    let mut state = AsyncState { count: 0 };
+    assert_eq!(
+        block_on(handle_all(&mut state, 'a', Outcome::Continue)),
+        Outcome::Changed
+    );
+    assert_eq!(state.count, 0);
+    assert_eq!(
+        block_on(handle_all(&mut state, 'b', Outcome::Unchanged)),
+        Outcome::Changed
+    );
+    assert_eq!(state.count, 1);
+    assert_eq!(
+        block_on(handle_all(&mut state, 'x', Outcome::Unchanged)),
+        Outcome::Unchanged
+    );
+}
+
+#[test]
+fn test_try_flow_async_block() {
+    let mut state = AsyncState { count: 0 };
+
+    let fut = async move {
+        try_flow!(state.handle_async(&'x', Regular).await);
+        let r: Result<OutcomeOf<u32>, String> = Ok(OutcomeOf::Value(1));
+        try_flow!(async { r }.await?);
+        Ok::<_, String>(OutcomeOf::Continue)
+    };
+    assert_eq!(block_on(fut), Ok(OutcomeOf::Value(1)));
+
+    let fut = async move {
+        let r: Result<Outcome, String> = Err("failed".into());
+        try_flow!(async { r }.await?);
+        Ok::<_, String>(OutcomeOf::<u32>::Continue)
+    };
+    assert_eq!(block_on(fut), Err("failed".into()));
+}
@@ -0,0 +1,3198 @@
+#![cfg(feature = "crossterm")]
+
+use rat_event::clock::MockClock;
+use rat_event::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode, MouseButton,
+    MouseEvent, MouseEventKind,
+};
+use rat_event::util::MouseFlags;
+use rat_event::{ct_event, match_event, HandleEvent, Outcome, OutcomeOf, Regular};
+use ratatui::layout::Rect;
+
+fn key(code: KeyCode, modifiers: KeyModifiers, kind: KeyEventKind) -> Event {
+    Event::Key(KeyEvent::new_with_kind(code, modifiers, kind))
+}
+
+fn kind_of(event: &Event) -> &'static str {
+    match event {
+        ct_event!(key release 'w') => "release",
+        ct_event!(key repeat 'w') => "repeat",
+        ct_event!(key press 'w') => "press",
+        ct_event!(keycode release Up) => "release",
+        ct_event!(keycode repeat Up) => "repeat",
+        ct_event!(keycode press Up) => "press",
+        ct_event!(keycode any-kind CONTROL-Enter) => "any",
+        ct_event!(key any-kind 'x') => "any",
+        _ => "none",
+    }
+}
+
+#[test]
+fn test_key_kind() {
+    use KeyEventKind::*;
+
+    let none = KeyModifiers::NONE;
+    assert_eq!(kind_of(&key(KeyCode::Char('w'), none, Press)), "press");
+    assert_eq!(kind_of(&key(KeyCode::Char('w'), none, Repeat)), "repeat");
+    assert_eq!(kind_of(&key(KeyCode::Char('w'), none, Release)), "release");
+
+    assert_eq!(kind_of(&key(KeyCode::Up, none, Press)), "press");
+    assert_eq!(kind_of(&key(KeyCode::Up, none, Repeat)), "repeat");
+    assert_eq!(kind_of(&key(KeyCode::Up, none, Release)), "release");
+
+    let ctrl = KeyModifiers::CONTROL;
+    for kind in [Press, Repeat, Release] {
+        assert_eq!(kind_of(&key(KeyCode::Enter, ctrl, kind)), "any");
+        assert_eq!(kind_of(&key(KeyCode::Char('x'), none, kind)), "any");
+    }
+    assert_eq!(kind_of(&key(KeyCode::Enter, none, Press)), "none");
+}
+
+#[test]
+fn test_press_covers_repeat() {
+    assert!(matches!(
+        key(KeyCode::Char('w'), KeyModifiers::NONE, KeyEventKind::Repeat),
+        ct_event!(key press 'w')
+    ));
+}
+
+fn fkey(event: &Event) -> Option<(&'static str, u8)> {
+    match event {
+        ct_event!(keycode press SHIFT-F(5)) | ct_event!(keycode press F(17)) => Some(("shift", 5)),
+        ct_event!(keycode press CONTROL-F(n)) => Some(("control", *n)),
+        ct_event!(keycode release F(n @ 1..=24)) => Some(("release", *n)),
+        ct_event!(keycode press F(1)) => Some(("help", 1)),
+        ct_event!(keycode press F(n)) => Some(("plain", *n)),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_function_keys() {
+    use KeyEventKind::*;
+
+    let none = KeyModifiers::NONE;
+    assert_eq!(fkey(&key(KeyCode::F(1), none, Press)), Some(("help", 1)));
+    assert_eq!(fkey(&key(KeyCode::F(7), none, Press)), Some(("plain", 7)));
+    assert_eq!(
+        fkey(&key(KeyCode::F(24), none, Repeat)),
+        Some(("plain", 24))
+    );
+    assert_eq!(
+        fkey(&key(KeyCode::F(3), none, Release)),
+        Some(("release", 3))
+    );
+    assert_eq!(
+        fkey(&key(KeyCode::F(12), KeyModifiers::CONTROL, Press)),
+        Some(("control", 12))
+    );
+
+    // both ways a terminal might report SHIFT-F5
+    assert_eq!(
+        fkey(&key(KeyCode::F(5), KeyModifiers::SHIFT, Press)),
+        Some(("shift", 5))
+    );
+    assert_eq!(fkey(&key(KeyCode::F(17), none, Press)), Some(("shift", 5)));
+
+    assert_eq!(fkey(&key(KeyCode::F(6), KeyModifiers::SHIFT, Press)), None);
+}
+
+fn chord(event: &Event) -> &'static str {
+    match event {
+        ct_event!(key press CONTROL_SHIFT-'S') => "save as",
+        ct_event!(keycode press CONTROL_ALT-Delete) => "reboot",
+        ct_event!(keycode press ALT_SHIFT-Tab) => "prev window",
+        ct_event!(key press CONTROL_ALT_SHIFT-'x') => "all",
+        ct_event!(key press shifted '?') => "help",
+        ct_event!(key release shifted '?') => "help released",
+        _ => "none",
+    }
+}
+
+#[test]
+fn test_modifier_combinations() {
+    use KeyEventKind::*;
+
+    let ctrl = KeyModifiers::CONTROL;
+    let alt = KeyModifiers::ALT;
+    let shift = KeyModifiers::SHIFT;
+
+    assert_eq!(
+        chord(&key(KeyCode::Char('S'), ctrl | shift, Press)),
+        "save as"
+    );
+    assert_eq!(chord(&key(KeyCode::Char('S'), ctrl, Press)), "none");
+    assert_eq!(chord(&key(KeyCode::Delete, ctrl | alt, Press)), "reboot");
+    assert_eq!(chord(&key(KeyCode::Delete, ctrl, Press)), "none");
+    assert_eq!(chord(&key(KeyCode::Tab, alt | shift, Press)), "prev window");
+    assert_eq!(
+        chord(&key(KeyCode::Char('x'), ctrl | alt | shift, Press)),
+        "all"
+    );
+
+    assert_eq!(chord(&key(KeyCode::Char('?'), shift, Press)), "help");
+    assert_eq!(
+        chord(&key(KeyCode::Char('?'), KeyModifiers::NONE, Press)),
+        "help"
+    );
+    assert_eq!(chord(&key(KeyCode::Char('?'), ctrl, Press)), "none");
+    assert_eq!(
+        chord(&key(KeyCode::Char('?'), shift, Release)),
+        "help released"
+    );
+}
+
+fn text_input(event: &Event) -> Option<String> {
+    match event {
+        ct_event!(key press CONTROL-c) => Some(format!("ctrl {}", c)),
+        ct_event!(key press shifted c) => Some(c.to_string()),
+        ct_event!(key press ANY-_) => Some("other".into()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_char_binding() {
+    use KeyEventKind::*;
+
+    let none = KeyModifiers::NONE;
+    let shift = KeyModifiers::SHIFT;
+    assert_eq!(
+        text_input(&key(KeyCode::Char('ä'), none, Press)),
+        Some("ä".into())
+    );
+    assert_eq!(
+        text_input(&key(KeyCode::Char('Ö'), shift, Press)),
+        Some("Ö".into())
+    );
+    assert_eq!(
+        text_input(&key(KeyCode::Char('🦀'), none, Press)),
+        Some("🦀".into())
+    );
+    assert_eq!(
+        text_input(&key(KeyCode::Char(' '), none, Press)),
+        Some(" ".into())
+    );
+    assert_eq!(
+        text_input(&key(KeyCode::Char('w'), KeyModifiers::CONTROL, Press)),
+        Some("ctrl w".into())
+    );
+    assert_eq!(
+        text_input(&key(KeyCode::Char('w'), KeyModifiers::ALT, Press)),
+        Some("other".into())
+    );
+    assert_eq!(text_input(&key(KeyCode::Enter, none, Press)), None);
+    assert_eq!(text_input(&key(KeyCode::Tab, none, Press)), None);
+    assert_eq!(text_input(&key(KeyCode::Char('ä'), none, Release)), None);
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers,
+    })
+}
+
+fn mouse_action(event: &Event, flags: &MouseFlags, area: Rect) -> String {
+    match event {
+        ct_event!(mouse any for m) if flags.drag(area, m) => "dragging".into(),
+        ct_event!(mouse down CONTROL-Left for x, y) => format!("ctrl down {} {}", x, y),
+        ct_event!(mouse down Left for x, y) if *x > 10 => format!("down right {}", y),
+        ct_event!(mouse down Left for x, y) => format!("down {} {}", x, y),
+        ct_event!(mouse up Right) => "up right".into(),
+        ct_event!(mouse drag ALT-Left) => "alt drag".into(),
+        ct_event!(mouse moved CONTROL for x, y) => format!("ctrl moved {} {}", x, y),
+        ct_event!(mouse moved for x, y) => format!("moved {} {}", x, y),
+        ct_event!(scroll up for x, y) => format!("scroll {} {}", x, y),
+        ct_event!(scroll SHIFT down for _x, _y) => "shift scroll".into(),
+        _ => "none".into(),
+    }
+}
+
+#[test]
+fn test_mouse_patterns() {
+    let flags = MouseFlags::default();
+    let area = Rect::new(0, 0, 20, 20);
+    let none = KeyModifiers::NONE;
+    let down = MouseEventKind::Down(MouseButton::Left);
+
+    assert_eq!(
+        mouse_action(&mouse(down, 3, 4, none), &flags, area),
+        "down 3 4"
+    );
+    assert_eq!(
+        mouse_action(&mouse(down, 13, 4, none), &flags, area),
+        "down right 4"
+    );
+    assert_eq!(
+        mouse_action(&mouse(down, 3, 4, KeyModifiers::CONTROL), &flags, area),
+        "ctrl down 3 4"
+    );
+    assert_eq!(
+        mouse_action(
+            &mouse(MouseEventKind::Up(MouseButton::Right), 1, 1, none),
+            &flags,
+            area
+        ),
+        "up right"
+    );
+    assert_eq!(
+        mouse_action(
+            &mouse(
+                MouseEventKind::Drag(MouseButton::Left),
+                1,
+                1,
+                KeyModifiers::ALT
+            ),
+            &flags,
+            area
+        ),
+        "alt drag"
+    );
+    assert_eq!(
+        mouse_action(&mouse(MouseEventKind::Moved, 5, 6, none), &flags, area),
+        "moved 5 6"
+    );
+    assert_eq!(
+        mouse_action(
+            &mouse(MouseEventKind::Moved, 5, 6, KeyModifiers::CONTROL),
+            &flags,
+            area
+        ),
+        "ctrl moved 5 6"
+    );
+    assert_eq!(
+        mouse_action(&mouse(MouseEventKind::ScrollUp, 7, 8, none), &flags, area),
+        "scroll 7 8"
+    );
+    assert_eq!(
+        mouse_action(
+            &mouse(MouseEventKind::ScrollDown, 7, 8, KeyModifiers::SHIFT),
+            &flags,
+            area
+        ),
+        "shift scroll"
+    );
+
+    // a drag started with the MouseFlags takes precedence
+    flags.drag.set(true);
+    assert_eq!(
+        mouse_action(
+            &mouse(MouseEventKind::Drag(MouseButton::Left), 1, 1, none),
+            &flags,
+            area
+        ),
+        "dragging"
+    );
+}
+
+#[test]
+fn test_mouse_flags_deref() {
+    let flags = MouseFlags::default();
+    let area = Rect::new(0, 0, 20, 20);
+    let m = MouseEvent {
+        kind: MouseEventKind::Moved,
+        column: 1,
+        row: 1,
+        modifiers: KeyModifiers::NONE,
+    };
+
+    // references to references and boxes coerce.
+    let events = [&m];
+    for e in events.iter() {
+        let e: &&MouseEvent = e;
+        assert!(flags.hover(area, e));
+        assert_eq!(flags.pos_of(e), (1, 1));
+    }
+    assert!(!flags.hover(area, &Box::new(m)));
+}
+
+fn keypad(code: KeyCode, state: KeyEventState) -> Event {
+    let mut key = KeyEvent::new(code, KeyModifiers::NONE);
+    key.state = state;
+    Event::Key(key)
+}
+
+fn calculator(event: &Event) -> &'static str {
+    match event {
+        ct_event!(keycode keypad Enter) => "keypad enter",
+        ct_event!(key keypad '5') => "keypad 5",
+        ct_event!(keycode press Enter) => "enter",
+        ct_event!(key press '5') => "5",
+        ct_event!(keycode media PlayPause) => "play",
+        _ => "none",
+    }
+}
+
+#[test]
+fn test_keypad_media() {
+    assert_eq!(
+        calculator(&keypad(KeyCode::Enter, KeyEventState::KEYPAD)),
+        "keypad enter"
+    );
+    assert_eq!(
+        calculator(&keypad(
+            KeyCode::Char('5'),
+            KeyEventState::KEYPAD | KeyEventState::NUM_LOCK
+        )),
+        "keypad 5"
+    );
+    assert_eq!(
+        calculator(&keypad(
+            KeyCode::Char('5'),
+            KeyEventState::KEYPAD | KeyEventState::CAPS_LOCK | KeyEventState::NUM_LOCK
+        )),
+        "keypad 5"
+    );
+
+    // without the enhancement flags the keypad looks like the main keyboard
+    assert_eq!(
+        calculator(&keypad(KeyCode::Enter, KeyEventState::NONE)),
+        "enter"
+    );
+    assert_eq!(
+        calculator(&keypad(KeyCode::Char('5'), KeyEventState::NUM_LOCK)),
+        "5"
+    );
+
+    assert_eq!(
+        calculator(&keypad(
+            KeyCode::Media(MediaKeyCode::PlayPause),
+            KeyEventState::NONE
+        )),
+        "play"
+    );
+    assert_eq!(
+        calculator(&keypad(
+            KeyCode::Media(MediaKeyCode::Stop),
+            KeyEventState::NONE
+        )),
+        "none"
+    );
+}
+
+#[test]
+fn test_paste() {
+    let event = Event::Paste("text".into());
+    assert!(matches!(event, ct_event!(paste)));
+    match &event {
+        ct_event!(paste s) => assert_eq!(s, "text"),
+        _ => unreachable!(),
+    }
+    assert!(!matches!(Event::FocusLost, ct_event!(paste)));
+}
+
+fn window(event: &Event) -> String {
+    match event {
+        ct_event!(focus_gained) => "gained".into(),
+        ct_event!(focus_lost) => "lost".into(),
+        ct_event!(resized for w, h) if *w < 10 => format!("small {}", h),
+        ct_event!(resized) => "resized".into(),
+        _ => "none".into(),
+    }
+}
+
+#[test]
+fn test_focus_resize() {
+    assert_eq!(window(&Event::FocusGained), "gained");
+    assert_eq!(window(&Event::FocusLost), "lost");
+    assert_eq!(window(&Event::Resize(5, 7)), "small 7");
+    assert_eq!(window(&Event::Resize(80, 24)), "resized");
+}
+
+#[test]
+fn test_normalize_key() {
+    use rat_event::crossterm::normalize_key;
+
+    let table = [
+        // the three representations of Shift+a
+        (('A', KeyModifiers::SHIFT), ('A', KeyModifiers::NONE)),
+        (('A', KeyModifiers::NONE), ('A', KeyModifiers::NONE)),
+        (('a', KeyModifiers::SHIFT), ('A', KeyModifiers::NONE)),
+        // other modifiers stay
+        (
+            ('a', KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+            ('A', KeyModifiers::CONTROL),
+        ),
+        (('a', KeyModifiers::CONTROL), ('a', KeyModifiers::CONTROL)),
+        (('a', KeyModifiers::NONE), ('a', KeyModifiers::NONE)),
+        // no case, only the modifier changes
+        (('?', KeyModifiers::SHIFT), ('?', KeyModifiers::NONE)),
+        (('ß', KeyModifiers::SHIFT), ('ß', KeyModifiers::NONE)),
+    ];
+    for ((c, m), (nc, nm)) in table {
+        let key = normalize_key(KeyEvent::new(KeyCode::Char(c), m));
+        assert_eq!(key, KeyEvent::new(KeyCode::Char(nc), nm), "{:?} {:?}", c, m);
+    }
+
+    for (c, m) in [
+        ('A', KeyModifiers::SHIFT),
+        ('A', KeyModifiers::NONE),
+        ('a', KeyModifiers::SHIFT),
+    ] {
+        let event = Event::Key(normalize_key(KeyEvent::new(KeyCode::Char(c), m)));
+        assert!(matches!(event, ct_event!(key press 'A')));
+    }
+
+    let key = KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT);
+    assert_eq!(normalize_key(key), key);
+}
+
+fn compat(event: &Event) -> &'static str {
+    match event {
+        ct_event!(keycode press compat Enter) => "enter",
+        ct_event!(keycode press compat Tab) => "tab",
+        ct_event!(keycode press compat Esc) => "esc",
+        _ => "none",
+    }
+}
+
+#[test]
+fn test_compat_keys() {
+    use rat_event::crossterm::{is_enter, is_esc, is_tab};
+
+    let press = |code, m| KeyEvent::new(code, m);
+    let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+    assert!(is_enter(&press(KeyCode::Enter, KeyModifiers::NONE)));
+    assert!(is_enter(&ctrl('m')));
+    assert!(!is_enter(&press(KeyCode::Enter, KeyModifiers::ALT)));
+    assert!(!is_enter(&press(KeyCode::Char('m'), KeyModifiers::NONE)));
+    assert!(!is_enter(&KeyEvent::new_with_kind(
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        KeyEventKind::Release
+    )));
+
+    assert!(is_tab(&press(KeyCode::Tab, KeyModifiers::NONE)));
+    assert!(is_tab(&ctrl('i')));
+    assert!(!is_tab(&ctrl('m')));
+
+    assert!(is_esc(&press(KeyCode::Esc, KeyModifiers::NONE)));
+    assert!(is_esc(&ctrl('[')));
+    assert!(!is_esc(&ctrl('i')));
+
+    assert_eq!(
+        compat(&Event::Key(press(KeyCode::Enter, KeyModifiers::NONE))),
+        "enter"
+    );
+    assert_eq!(compat(&Event::Key(ctrl('m'))), "enter");
+    assert_eq!(
+        compat(&Event::Key(press(KeyCode::Tab, KeyModifiers::NONE))),
+        "tab"
+    );
+    assert_eq!(compat(&Event::Key(ctrl('i'))), "tab");
+    assert_eq!(
+        compat(&Event::Key(press(KeyCode::Esc, KeyModifiers::NONE))),
+        "esc"
+    );
+    assert_eq!(compat(&Event::Key(ctrl('['))), "esc");
+    assert_eq!(compat(&Event::Key(ctrl('x'))), "none");
+}
+
+fn char_key(c: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+}
+
+fn match_counter(event: &Event, count: &mut u32) -> OutcomeOf<u32> {
+    match_event!(event, {
+        ct_event!(key press '+') => {
+            *count += 1;
+            OutcomeOf::Value(*count)
+        },
+        ct_event!(key press '-') if *count > 0 => {
+            *count -= 1;
+            OutcomeOf::Value(*count)
+        },
+        ct_event!(key press '-') => Outcome::Unchanged,
+        Event::FocusLost => Outcome::Changed,
+    })
+}
+
+#[test]
+fn test_match_event() {
+    let mut count = 0;
+    assert_eq!(
+        match_counter(&char_key('-'), &mut count),
+        OutcomeOf::Unchanged
+    );
+    assert_eq!(
+        match_counter(&char_key('+'), &mut count),
+        OutcomeOf::Value(1)
+    );
+    assert_eq!(
+        match_counter(&char_key('-'), &mut count),
+        OutcomeOf::Value(0)
+    );
+    assert_eq!(
+        match_counter(&char_key('x'), &mut count),
+        OutcomeOf::Continue
+    );
+    assert_eq!(
+        match_counter(&Event::FocusLost, &mut count),
+        OutcomeOf::Changed
+    );
+    assert_eq!(
+        match_counter(&Event::FocusGained, &mut count),
+        OutcomeOf::Continue
+    );
+}
+
+#[test]
+fn test_fmt_key() {
+    use rat_event::crossterm::event::MediaKeyCode;
+    use rat_event::crossterm::{fmt_key, fmt_key_with_style, KeyFmtStyle};
+
+    let k = |code, modifiers| KeyEvent::new(code, modifiers);
+    let ctrl = KeyModifiers::CONTROL;
+    let alt = KeyModifiers::ALT;
+    let shift = KeyModifiers::SHIFT;
+
+    assert_eq!(fmt_key(&k(KeyCode::Char('s'), ctrl)), "Ctrl+s");
+    // modifiers are always sorted the same way
+    assert_eq!(
+        fmt_key(&k(KeyCode::Char('S'), shift | ctrl)),
+        "Ctrl+Shift+S"
+    );
+    assert_eq!(fmt_key(&k(KeyCode::Enter, alt)), "Alt+Enter");
+    assert_eq!(fmt_key(&k(KeyCode::F(5), KeyModifiers::NONE)), "F5");
+    assert_eq!(fmt_key(&k(KeyCode::F(17), shift)), "Shift+F17");
+    assert_eq!(fmt_key(&k(KeyCode::Char(' '), ctrl)), "Ctrl+Space");
+    assert_eq!(
+        fmt_key(&k(
+            KeyCode::Media(MediaKeyCode::PlayPause),
+            KeyModifiers::NONE
+        )),
+        "PlayPause"
+    );
+
+    let symbols = KeyFmtStyle::Symbols;
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Enter, alt), symbols),
+        "Alt+↵"
+    );
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Left, ctrl), symbols),
+        "Ctrl+←"
+    );
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Esc, KeyModifiers::NONE), symbols),
+        "Esc"
+    );
+
+    let emacs = KeyFmtStyle::Emacs;
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Char('s'), ctrl), emacs),
+        "C-s"
+    );
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Enter, alt), emacs),
+        "M-enter"
+    );
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::F(5), ctrl | shift), emacs),
+        "C-S-f5"
+    );
+
+    let terse = KeyFmtStyle::Terse;
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Char('s'), ctrl), terse),
+        "^S"
+    );
+    assert_eq!(
+        fmt_key_with_style(&k(KeyCode::Char('x'), alt), terse),
+        "M-x"
+    );
+    assert_eq!(fmt_key_with_style(&k(KeyCode::F(5), ctrl), terse), "^F5");
+}
+
+#[test]
+fn test_parse_key() {
+    use rat_event::crossterm::{parse_key, KeyParseError};
+
+    let k = |code, modifiers| Ok(KeyEvent::new(code, modifiers));
+    let ctrl = KeyModifiers::CONTROL;
+    let alt = KeyModifiers::ALT;
+    let shift = KeyModifiers::SHIFT;
+    let none = KeyModifiers::NONE;
+
+    assert_eq!(parse_key("ctrl+shift+f5"), k(KeyCode::F(5), ctrl | shift));
+    assert_eq!(parse_key("Alt+Enter"), k(KeyCode::Enter, alt));
+    assert_eq!(parse_key("META-RETURN"), k(KeyCode::Enter, alt));
+    assert_eq!(parse_key("space"), k(KeyCode::Char(' '), none));
+    assert_eq!(parse_key("C-x"), k(KeyCode::Char('x'), ctrl));
+    assert_eq!(parse_key("c-X"), k(KeyCode::Char('X'), ctrl));
+    assert_eq!(parse_key("s-tab"), k(KeyCode::Tab, shift));
+    assert_eq!(parse_key("backtab"), k(KeyCode::BackTab, none));
+    assert_eq!(parse_key("PgDn"), k(KeyCode::PageDown, none));
+    assert_eq!(parse_key("ctrl+del"), k(KeyCode::Delete, ctrl));
+    assert_eq!(parse_key("f24"), k(KeyCode::F(24), none));
+    assert_eq!(parse_key("f"), k(KeyCode::Char('f'), none));
+    assert_eq!(parse_key("ä"), k(KeyCode::Char('ä'), none));
+    assert_eq!(parse_key("ctrl++"), k(KeyCode::Char('+'), ctrl));
+    assert_eq!(parse_key("C--"), k(KeyCode::Char('-'), ctrl));
+    assert_eq!(parse_key("-"), k(KeyCode::Char('-'), none));
+    assert_eq!(parse_key(" up "), k(KeyCode::Up, none));
+
+    assert_eq!(parse_key(""), Err(KeyParseError::Empty));
+    assert_eq!(
+        parse_key("ctrl+"),
+        Err(KeyParseError::MissingKey("ctrl+".into()))
+    );
+    assert_eq!(
+        parse_key("cntrl+s"),
+        Err(KeyParseError::UnknownModifier("cntrl".into()))
+    );
+    assert_eq!(
+        parse_key("ctrl+f25"),
+        Err(KeyParseError::UnknownKey("f25".into()))
+    );
+    assert_eq!(
+        parse_key("ctrl+entr"),
+        Err(KeyParseError::UnknownKey("entr".into()))
+    );
+    assert_eq!(
+        parse_key("cntrl+s").unwrap_err().to_string(),
+        "unknown modifier 'cntrl'"
+    );
+}
+
+#[test]
+fn test_fmt_parse_roundtrip() {
+    use rat_event::crossterm::{fmt_key_with_style, parse_key, KeyFmtStyle};
+
+    let codes = [
+        KeyCode::Char('a'),
+        KeyCode::Char('A'),
+        KeyCode::Char(' '),
+        KeyCode::Char('+'),
+        KeyCode::Char('-'),
+        KeyCode::Char('ö'),
+        KeyCode::F(1),
+        KeyCode::F(24),
+        KeyCode::Enter,
+        KeyCode::Esc,
+        KeyCode::Tab,
+        KeyCode::BackTab,
+        KeyCode::Backspace,
+        KeyCode::Home,
+        KeyCode::End,
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Left,
+        KeyCode::Right,
+        KeyCode::Insert,
+        KeyCode::Delete,
+        KeyCode::CapsLock,
+        KeyCode::PrintScreen,
+        KeyCode::KeypadBegin,
+    ];
+    let modifiers = [
+        KeyModifiers::NONE,
+        KeyModifiers::CONTROL,
+        KeyModifiers::ALT,
+        KeyModifiers::SHIFT,
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT,
+        KeyModifiers::SUPER | KeyModifiers::HYPER,
+    ];
+
+    for style in [KeyFmtStyle::Plain, KeyFmtStyle::Emacs] {
+        for code in codes {
+            for modifiers in modifiers {
+                let key = KeyEvent::new(code, modifiers);
+                let text = fmt_key_with_style(&key, style);
+                assert_eq!(parse_key(&text), Ok(key), "{:?} {}", style, text);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_double_click_state() {
+    use rat_event::crossterm::{DoubleClickOutcome, DoubleClickState};
+    use rat_event::util::double_click_timeout;
+    use rat_event::{ConsumedEvent, DoubleClick};
+    use std::time::Duration;
+
+    let clock = MockClock::new();
+    let mut state = DoubleClickState::new();
+    state.area = Rect::new(0, 0, 10, 10);
+    state.clock = clock.shared();
+
+    let none = KeyModifiers::NONE;
+    let down = mouse(MouseEventKind::Down(MouseButton::Left), 3, 4, none);
+    let up = mouse(MouseEventKind::Up(MouseButton::Left), 3, 4, none);
+
+    assert_eq!(
+        state.handle(&down, DoubleClick),
+        DoubleClickOutcome::Continue
+    );
+    assert_eq!(state.handle(&up, DoubleClick), DoubleClickOutcome::Continue);
+    assert_eq!(
+        state.handle(&down, DoubleClick),
+        DoubleClickOutcome::Continue
+    );
+    let r = state.handle(&up, DoubleClick);
+    assert_eq!(r, DoubleClickOutcome::ClickedAt(3, 4));
+    assert!(r.is_consumed());
+    assert_eq!(Outcome::from(r), Outcome::Changed);
+
+    // too slow
+    state.handle(&down, DoubleClick);
+    state.handle(&up, DoubleClick);
+    clock.advance(Duration::from_millis(double_click_timeout() as u64 + 1));
+    state.handle(&down, DoubleClick);
+    assert_eq!(state.handle(&up, DoubleClick), DoubleClickOutcome::Continue);
+
+    // outside the area
+    let down = mouse(MouseEventKind::Down(MouseButton::Left), 30, 4, none);
+    let up = mouse(MouseEventKind::Up(MouseButton::Left), 30, 4, none);
+    for _ in 0..2 {
+        assert_eq!(
+            state.handle(&down, DoubleClick),
+            DoubleClickOutcome::Continue
+        );
+        assert_eq!(state.handle(&up, DoubleClick), DoubleClickOutcome::Continue);
+    }
+}
+
+#[test]
+fn test_repeat_throttle() {
+    use rat_event::crossterm::RepeatThrottle;
+    use std::time::{Duration, Instant};
+
+    let mut throttle = RepeatThrottle::new(Duration::from_millis(100));
+    let t0 = Instant::now();
+    let ms = |n| t0 + Duration::from_millis(n);
+
+    let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+    let ctrl_down = KeyEvent::new(KeyCode::Down, KeyModifiers::CONTROL);
+    let up = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+
+    assert!(throttle.allow(&down, ms(0)));
+    assert!(!throttle.allow(&down, ms(30)));
+    assert!(!throttle.allow(&down, ms(60)));
+    // other keys are independent
+    assert!(throttle.allow(&ctrl_down, ms(60)));
+    assert!(throttle.allow(&up, ms(70)));
+    assert_eq!(throttle.pending(), Some(down));
+    assert_eq!(throttle.pending(), None);
+
+    assert!(!throttle.allow(&down, ms(90)));
+    // after the interval, the pending one is obsolete
+    assert!(throttle.allow(&down, ms(100)));
+    assert_eq!(throttle.pending(), None);
+
+    // release always passes and resets the key
+    let release = KeyEvent::new_with_kind(KeyCode::Down, KeyModifiers::NONE, KeyEventKind::Release);
+    assert!(throttle.allow(&release, ms(110)));
+    assert!(throttle.allow(&down, ms(120)));
+}
+
+#[test]
+fn test_repeat_throttle_two_keys() {
+    use rat_event::crossterm::RepeatThrottle;
+    use std::time::{Duration, Instant};
+
+    let mut throttle = RepeatThrottle::new(Duration::from_millis(100));
+    let t0 = Instant::now();
+    let ms = |n| t0 + Duration::from_millis(n);
+
+    let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+    let down_rep = KeyEvent::new_with_kind(KeyCode::Down, KeyModifiers::NONE, KeyEventKind::Repeat);
+    let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+    let right_rep =
+        KeyEvent::new_with_kind(KeyCode::Right, KeyModifiers::NONE, KeyEventKind::Repeat);
+
+    // both keys held, interleaved.
+    assert!(throttle.allow(&down, ms(0)));
+    assert!(throttle.allow(&right, ms(5)));
+    assert!(!throttle.allow(&down, ms(30)));
+    assert!(!throttle.allow(&right, ms(35)));
+    assert!(!throttle.allow(&down_rep, ms(60)));
+    assert!(!throttle.allow(&right_rep, ms(65)));
+
+    // the final event of each key survives.
+    assert_eq!(throttle.pending(), Some(down_rep));
+    assert_eq!(throttle.pending(), Some(right_rep));
+    assert_eq!(throttle.pending(), None);
+
+    assert!(!throttle.allow(&down, ms(70)));
+    assert!(!throttle.allow(&right, ms(75)));
+    // one key passes again, the other one stays pending.
+    assert!(throttle.allow(&down, ms(100)));
+    assert_eq!(throttle.drain_pending().collect::<Vec<_>>(), vec![right]);
+    assert_eq!(throttle.pending(), None);
+}
+
+struct Counter {
+    count: u32,
+}
+
+impl HandleEvent<Event, Regular, Outcome> for Counter {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            ct_event!(key press '+') | ct_event!(keycode press Up) => {
+                self.count += 1;
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+#[test]
+fn test_press_release_pair() {
+    use rat_event::crossterm::key_press_only;
+
+    let mut counter = Counter { count: 0 };
+    let mut consumed = 0;
+    for code in [KeyCode::Char('+'), KeyCode::Up] {
+        for kind in [KeyEventKind::Press, KeyEventKind::Release] {
+            let event = key(code, KeyModifiers::NONE, kind);
+            if counter.handle(&event, Regular) == Outcome::Changed {
+                consumed += 1;
+            }
+            assert_eq!(
+                key_press_only(&event).is_some(),
+                kind == KeyEventKind::Press
+            );
+        }
+    }
+    assert_eq!(consumed, 2);
+    assert_eq!(counter.count, 2);
+
+    let repeat = key(KeyCode::Up, KeyModifiers::NONE, KeyEventKind::Repeat);
+    assert!(key_press_only(&repeat).is_some());
+    assert!(key_press_only(&Event::FocusGained).is_none());
+}
+
+fn mouse_at(kind: MouseEventKind, row: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: 0,
+        row,
+        modifiers,
+    })
+}
+
+#[test]
+fn test_coalesce_events() {
+    use rat_event::crossterm::coalesce_events;
+    use MouseEventKind::*;
+
+    let none = KeyModifiers::NONE;
+    let m = |kind, row| mouse_at(kind, row, none);
+    let a = key(KeyCode::Char('a'), none, KeyEventKind::Press);
+
+    let table = [
+        // net scroll count
+        (
+            vec![m(ScrollDown, 2), m(ScrollDown, 2), m(ScrollDown, 2)],
+            vec![m(ScrollDown, 2), m(ScrollDown, 2), m(ScrollDown, 2)],
+        ),
+        // a new position is a new run
+        (
+            vec![m(ScrollDown, 0), m(ScrollDown, 0), m(ScrollDown, 5)],
+            vec![m(ScrollDown, 0), m(ScrollDown, 0), m(ScrollDown, 5)],
+        ),
+        (
+            vec![
+                m(ScrollDown, 0),
+                m(ScrollUp, 0),
+                m(ScrollDown, 5),
+                m(ScrollDown, 5),
+            ],
+            vec![m(ScrollDown, 5), m(ScrollDown, 5)],
+        ),
+        (
+            vec![m(ScrollDown, 0), m(ScrollUp, 0), m(ScrollUp, 1)],
+            vec![m(ScrollUp, 1)],
+        ),
+        (vec![m(ScrollDown, 0), m(ScrollUp, 0)], vec![]),
+        // horizontal is a different run
+        (
+            vec![m(ScrollDown, 0), m(ScrollLeft, 0), m(ScrollUp, 0)],
+            vec![m(ScrollDown, 0), m(ScrollLeft, 0), m(ScrollUp, 0)],
+        ),
+        // and different modifiers
+        (
+            vec![
+                m(ScrollDown, 0),
+                mouse_at(ScrollUp, 0, KeyModifiers::SHIFT),
+                m(ScrollDown, 0),
+            ],
+            vec![
+                m(ScrollDown, 0),
+                mouse_at(ScrollUp, 0, KeyModifiers::SHIFT),
+                m(ScrollDown, 0),
+            ],
+        ),
+        // last move/drag
+        (
+            vec![m(Moved, 0), m(Moved, 1), m(Moved, 2)],
+            vec![m(Moved, 2)],
+        ),
+        (
+            vec![
+                m(Down(MouseButton::Left), 0),
+                m(Drag(MouseButton::Left), 1),
+                m(Drag(MouseButton::Left), 2),
+                m(Up(MouseButton::Left), 2),
+            ],
+            vec![
+                m(Down(MouseButton::Left), 0),
+                m(Drag(MouseButton::Left), 2),
+                m(Up(MouseButton::Left), 2),
+            ],
+        ),
+        // nothing merges across keys
+        (
+            vec![m(ScrollDown, 0), a.clone(), m(ScrollUp, 0)],
+            vec![m(ScrollDown, 0), a.clone(), m(ScrollUp, 0)],
+        ),
+        (
+            vec![m(Moved, 0), a.clone(), m(Moved, 1), m(Moved, 2)],
+            vec![m(Moved, 0), a.clone(), m(Moved, 2)],
+        ),
+        (
+            vec![m(Moved, 0), m(ScrollDown, 0), m(Moved, 1)],
+            vec![m(Moved, 0), m(ScrollDown, 0), m(Moved, 1)],
+        ),
+    ];
+
+    for (input, expect) in table {
+        let mut events = input.clone();
+        coalesce_events(&mut events);
+        assert_eq!(events, expect, "{:?}", input);
+    }
+}
+
+#[test]
+fn test_crossterm_source() {
+    use rat_event::crossterm::CrosstermSource;
+
+    let source = CrosstermSource::new();
+    assert!(!source.is_coalesce());
+    let source = source.coalesce(true);
+    assert!(source.is_coalesce());
+}
+
+fn focus_nav(event: &Event) -> &'static str {
+    match event {
+        ct_event!(keycode press backtab) => "prev",
+        ct_event!(keycode press Tab) => "next",
+        _ => "none",
+    }
+}
+
+#[test]
+fn test_backtab() {
+    use rat_event::crossterm::{is_backtab, normalize_key};
+
+    let backtab = KeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE);
+    let table = [
+        ((KeyCode::BackTab, KeyModifiers::NONE), true),
+        ((KeyCode::BackTab, KeyModifiers::SHIFT), true),
+        ((KeyCode::Tab, KeyModifiers::SHIFT), true),
+        ((KeyCode::Tab, KeyModifiers::NONE), false),
+        ((KeyCode::BackTab, KeyModifiers::CONTROL), false),
+        (
+            (KeyCode::Tab, KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+            false,
+        ),
+    ];
+    for ((code, m), is) in table {
+        let key = KeyEvent::new(code, m);
+        assert_eq!(is_backtab(&key), is, "{:?} {:?}", code, m);
+        assert_eq!(
+            focus_nav(&Event::Key(key)) == "prev",
+            is,
+            "{:?} {:?}",
+            code,
+            m
+        );
+        if is {
+            assert_eq!(normalize_key(key), backtab);
+        }
+    }
+
+    assert_eq!(
+        focus_nav(&Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE))),
+        "next"
+    );
+    assert_eq!(
+        normalize_key(KeyEvent::new(
+            KeyCode::Tab,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )),
+        KeyEvent::new(KeyCode::BackTab, KeyModifiers::CONTROL)
+    );
+    assert!(!is_backtab(&KeyEvent::new_with_kind(
+        KeyCode::BackTab,
+        KeyModifiers::SHIFT,
+        KeyEventKind::Release
+    )));
+}
+
+#[test]
+fn test_alt_accelerator() {
+    use rat_event::crossterm::alt_accelerator;
+
+    let press = |code, m| Event::Key(KeyEvent::new(code, m));
+    assert_eq!(
+        alt_accelerator(&press(KeyCode::Char('f'), KeyModifiers::ALT)),
+        Some('f')
+    );
+    assert_eq!(
+        alt_accelerator(&press(
+            KeyCode::Char('F'),
+            KeyModifiers::ALT | KeyModifiers::SHIFT
+        )),
+        Some('f')
+    );
+    assert_eq!(
+        alt_accelerator(&press(KeyCode::Char('F'), KeyModifiers::ALT)),
+        Some('f')
+    );
+    assert_eq!(
+        alt_accelerator(&press(KeyCode::Left, KeyModifiers::ALT)),
+        None
+    );
+    assert_eq!(
+        alt_accelerator(&press(KeyCode::Char('f'), KeyModifiers::NONE)),
+        None
+    );
+    assert_eq!(
+        alt_accelerator(&press(
+            KeyCode::Char('f'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        )),
+        None
+    );
+    assert_eq!(
+        alt_accelerator(&key(
+            KeyCode::Char('f'),
+            KeyModifiers::ALT,
+            KeyEventKind::Release
+        )),
+        None
+    );
+}
+
+#[test]
+fn test_alt_hold() {
+    use rat_event::crossterm::event::ModifierKeyCode;
+    use rat_event::crossterm::AltHold;
+
+    let alt = |kind| {
+        key(
+            KeyCode::Modifier(ModifierKeyCode::LeftAlt),
+            KeyModifiers::ALT,
+            kind,
+        )
+    };
+    let x = |kind| key(KeyCode::Char('x'), KeyModifiers::ALT, kind);
+
+    // no release seen yet: the press toggles.
+    let mut hold = AltHold::new();
+    assert!(hold.check(&alt(KeyEventKind::Press)));
+    assert!(!hold.is_armed());
+
+    // with releases.
+    let mut hold = AltHold::new();
+    assert!(!hold.check(&x(KeyEventKind::Release)));
+    assert!(!hold.check(&alt(KeyEventKind::Press)));
+    assert!(hold.is_armed());
+    assert!(!hold.check(&alt(KeyEventKind::Repeat)));
+    assert!(hold.check(&alt(KeyEventKind::Release)));
+
+    // another key in between.
+    assert!(!hold.check(&alt(KeyEventKind::Press)));
+    assert!(!hold.check(&x(KeyEventKind::Press)));
+    assert!(!hold.check(&x(KeyEventKind::Release)));
+    assert!(!hold.check(&alt(KeyEventKind::Release)));
+
+    // a click in between.
+    assert!(!hold.check(&alt(KeyEventKind::Press)));
+    assert!(!hold.check(&mouse_at(
+        MouseEventKind::Down(MouseButton::Left),
+        0,
+        KeyModifiers::ALT
+    )));
+    assert!(!hold.check(&alt(KeyEventKind::Release)));
+
+    // reset
+    assert!(!hold.check(&alt(KeyEventKind::Press)));
+    hold.reset();
+    assert!(!hold.check(&alt(KeyEventKind::Release)));
+}
+
+#[test]
+fn esc_debouncer() {
+    use rat_event::crossterm::{EscDebouncer, EscResolved};
+    use std::time::{Duration, Instant};
+
+    use KeyEventKind::*;
+    let none = KeyModifiers::NONE;
+    let esc = key(KeyCode::Esc, none, Press);
+    let x = key(KeyCode::Char('x'), none, Press);
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+
+    let mut deb = EscDebouncer::new();
+    assert_eq!(deb.get_window(), ms(50));
+    assert_eq!(deb.deadline(), None);
+    assert_eq!(deb.feed(&x, t0), EscResolved::Continue);
+    assert_eq!(deb.resolve(t0), EscResolved::Continue);
+
+    // Esc then x in time: Alt+x
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(deb.deadline(), Some(t0 + ms(50)));
+    assert_eq!(deb.resolve(t0 + ms(10)), EscResolved::Pending);
+    assert_eq!(
+        deb.feed(&x, t0 + ms(20)),
+        EscResolved::AltKey(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT))
+    );
+    assert!(!deb.is_pending());
+
+    // Esc then nothing: Escape
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(deb.resolve(t0 + ms(50)), EscResolved::Escape);
+    assert_eq!(deb.deadline(), None);
+
+    // Esc then x too late: Escape, x is fed again.
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(deb.feed(&x, t0 + ms(60)), EscResolved::Escape);
+    assert_eq!(deb.feed(&x, t0 + ms(60)), EscResolved::Continue);
+
+    // Esc then a mouse event: Escape
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(
+        deb.feed(&mouse_at(MouseEventKind::Moved, 0, none), t0 + ms(1)),
+        EscResolved::Escape
+    );
+
+    // Esc Esc: Alt+Esc
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(
+        deb.feed(&esc, t0 + ms(1)),
+        EscResolved::AltKey(KeyEvent::new(KeyCode::Esc, KeyModifiers::ALT))
+    );
+
+    // releases don't resolve.
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(
+        deb.feed(&key(KeyCode::Esc, none, Release), t0 + ms(1)),
+        EscResolved::Continue
+    );
+    assert!(deb.is_pending());
+    deb.reset();
+    assert!(!deb.is_pending());
+
+    // configured window
+    let mut deb = EscDebouncer::new().window(ms(10));
+    assert_eq!(deb.feed(&esc, t0), EscResolved::Pending);
+    assert_eq!(deb.feed(&x, t0 + ms(20)), EscResolved::Escape);
+}
+
+#[test]
+fn esc_esc() {
+    use rat_event::crossterm::{EscDebouncer, EscEsc, EscResolved};
+    use std::time::{Duration, Instant};
+
+    use KeyEventKind::*;
+    let none = KeyModifiers::NONE;
+    let esc = key(KeyCode::Esc, none, Press);
+    let x = key(KeyCode::Char('x'), none, Press);
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+
+    let mut ee = EscEsc::new().interval(ms(300));
+    assert_eq!(ee.get_interval(), ms(300));
+
+    assert!(!ee.check(&esc, t0));
+    assert!(ee.check(&esc, t0 + ms(300)));
+    // starts over
+    assert!(!ee.check(&esc, t0 + ms(400)));
+
+    // too slow
+    ee.reset();
+    assert!(!ee.check(&esc, t0));
+    assert!(!ee.check(&esc, t0 + ms(301)));
+    assert!(ee.check(&esc, t0 + ms(400)));
+
+    // another key in between
+    assert!(!ee.check(&esc, t0));
+    assert!(!ee.check(&x, t0 + ms(10)));
+    assert!(!ee.check(&esc, t0 + ms(20)));
+    assert!(ee.check(&esc, t0 + ms(30)));
+
+    // releases, repeats and mouse events are ignored
+    assert!(!ee.check(&esc, t0));
+    assert!(!ee.check(&key(KeyCode::Esc, none, Release), t0 + ms(1)));
+    assert!(!ee.check(&key(KeyCode::Char('x'), none, Release), t0 + ms(2)));
+    assert!(!ee.check(&key(KeyCode::Esc, none, Repeat), t0 + ms(3)));
+    assert!(!ee.check(&mouse_at(MouseEventKind::Moved, 0, none), t0 + ms(4)));
+    assert!(ee.check(&esc, t0 + ms(5)));
+
+    // Alt+Esc is not Esc
+    assert!(!ee.check(&esc, t0));
+    assert!(!ee.check(&key(KeyCode::Esc, KeyModifiers::ALT, Press), t0 + ms(1)));
+    assert!(!ee.check(&esc, t0 + ms(2)));
+    ee.reset();
+
+    // with the debouncer: Esc, Alt+x, Esc, Esc
+    let mut deb = EscDebouncer::new();
+    let mut feed = |event: &Event, now: Instant| {
+        let mut quit = false;
+        loop {
+            let r = deb.feed(event, now);
+            quit |= ee.check_resolved(&r, now);
+            if r == EscResolved::Continue {
+                quit |= ee.check(event, now);
+            }
+            if r != EscResolved::Escape {
+                break quit;
+            }
+        }
+    };
+    assert!(!feed(&esc, t0));
+    assert!(!feed(&x, t0 + ms(5)));
+    assert!(!feed(&esc, t0 + ms(100)));
+    assert!(!feed(&x, t0 + ms(105)));
+    assert!(!feed(&esc, t0 + ms(200)));
+    assert!(!feed(&esc, t0 + ms(300)));
+    assert!(feed(&x, t0 + ms(400)));
+}
+
+#[test]
+fn key_combo() {
+    use rat_event::crossterm::{KeyCombo, KeyFmtStyle, KeyParseError};
+    use std::collections::{BTreeSet, HashSet};
+
+    use KeyEventKind::*;
+    let ctrl = KeyModifiers::CONTROL;
+    let shift = KeyModifiers::SHIFT;
+    let none = KeyModifiers::NONE;
+
+    // kind and state don't matter
+    let mut press = KeyEvent::new_with_kind(KeyCode::Char('s'), ctrl, Press);
+    press.state = KeyEventState::NUM_LOCK;
+    let repeat = KeyEvent::new_with_kind(KeyCode::Char('s'), ctrl, Repeat);
+    assert_eq!(KeyCombo::from(&press), KeyCombo::from(repeat));
+
+    // the shifted forms are all the same
+    let combo = KeyCombo::new(KeyCode::Char('A'), none);
+    assert_eq!(KeyCombo::new(KeyCode::Char('a'), shift), combo);
+    assert_eq!(KeyCombo::new(KeyCode::Char('A'), shift), combo);
+    assert_eq!(combo.code(), KeyCode::Char('A'));
+    assert_eq!(combo.modifiers(), none);
+    assert_eq!(
+        KeyCombo::new(KeyCode::Tab, shift),
+        KeyCombo::new(KeyCode::BackTab, none)
+    );
+
+    let set: HashSet<_> = [
+        KeyCombo::from(&press),
+        KeyCombo::from(&repeat),
+        KeyCombo::new(KeyCode::Char('S'), ctrl | shift),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+
+    let set: BTreeSet<_> = [
+        KeyCombo::new(KeyCode::Char('b'), none),
+        KeyCombo::new(KeyCode::Char('a'), ctrl),
+        KeyCombo::new(KeyCode::Char('a'), none),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        set.into_iter().collect::<Vec<_>>(),
+        vec![
+            KeyCombo::new(KeyCode::Char('a'), none),
+            KeyCombo::new(KeyCode::Char('a'), ctrl),
+            KeyCombo::new(KeyCode::Char('b'), none),
+        ]
+    );
+
+    // synthesis
+    assert_eq!(
+        KeyEvent::from(KeyCombo::new(KeyCode::Char('x'), ctrl | shift)),
+        KeyEvent::new(KeyCode::Char('X'), ctrl)
+    );
+
+    // parse and format
+    assert_eq!(
+        "ctrl+shift+s".parse(),
+        Ok(KeyCombo::new(KeyCode::Char('S'), ctrl))
+    );
+    assert_eq!("s-tab".parse(), Ok(KeyCombo::new(KeyCode::BackTab, none)));
+    assert_eq!(
+        "cntrl+s".parse::<KeyCombo>(),
+        Err(KeyParseError::UnknownModifier("cntrl".into()))
+    );
+    let combo = KeyCombo::new(KeyCode::Char('s'), ctrl | shift);
+    assert_eq!(combo.to_string(), "Ctrl+S");
+    assert_eq!(combo.fmt_with_style(KeyFmtStyle::Emacs), "C-S");
+    assert_eq!(combo.to_string().parse(), Ok(combo));
+}
+
+fn scroll_columns(event: &Event) -> i16 {
+    match event {
+        ct_event!(scroll right for _x, _y) => 1,
+        ct_event!(scroll SHIFT right for _x, _y) => 1,
+        ct_event!(scroll SHIFT down for _x, _y) => 1,
+        ct_event!(scroll left) => -1,
+        ct_event!(scroll SHIFT left for _x, _y) => -1,
+        ct_event!(scroll SHIFT up for _x, _y) => -1,
+        _ => 0,
+    }
+}
+
+#[test]
+fn scroll_horizontal() {
+    use rat_event::crossterm::{scroll_direction, ScrollDirection};
+
+    let none = KeyModifiers::NONE;
+    let shift = KeyModifiers::SHIFT;
+    use MouseEventKind::*;
+
+    // one gesture, as sent by different terminals.
+    let events = [
+        mouse(ScrollRight, 1, 2, none),
+        mouse(ScrollRight, 1, 2, shift),
+        mouse(ScrollDown, 1, 2, shift),
+    ];
+    for event in &events {
+        assert_eq!(scroll_columns(event), 1);
+        assert_eq!(scroll_direction(event), Some(ScrollDirection::Right));
+        assert_eq!(scroll_direction(event).map(|v| v.delta()), Some((1, 0)));
+    }
+    let events = [
+        mouse(ScrollLeft, 1, 2, none),
+        mouse(ScrollLeft, 1, 2, shift),
+        mouse(ScrollUp, 1, 2, shift),
+    ];
+    for event in &events {
+        assert_eq!(scroll_columns(event), -1);
+        assert_eq!(scroll_direction(event), Some(ScrollDirection::Left));
+        assert_eq!(scroll_direction(event).map(|v| v.delta()), Some((-1, 0)));
+    }
+
+    assert_eq!(
+        scroll_direction(&mouse(ScrollUp, 1, 2, KeyModifiers::CONTROL)),
+        Some(ScrollDirection::Up)
+    );
+    assert_eq!(
+        scroll_direction(&mouse(ScrollDown, 1, 2, none)).map(|v| v.delta()),
+        Some((0, 1))
+    );
+    assert_eq!(scroll_direction(&mouse(Moved, 1, 2, none)), None);
+    assert_eq!(scroll_direction(&Event::FocusLost), None);
+}
+
+#[test]
+fn resize_debounce() {
+    use rat_event::crossterm::{ResizeDebounce, ResizeResolved};
+    use std::time::{Duration, Instant};
+
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+
+    let mut rd = ResizeDebounce::new().quiet(ms(50));
+    assert_eq!(rd.get_quiet(), ms(50));
+    assert_eq!(rd.pending_deadline(), None);
+    assert_eq!(rd.resolve(t0), ResizeResolved::Continue);
+    assert_eq!(rd.feed(&Event::FocusGained, t0), ResizeResolved::Continue);
+
+    // a burst, followed by silence.
+    assert_eq!(
+        rd.feed(&Event::Resize(80, 24), t0),
+        ResizeResolved::Resized(80, 24)
+    );
+    assert_eq!(rd.pending_deadline(), None);
+    for i in 1..20 {
+        assert_eq!(
+            rd.feed(&Event::Resize(80 + i, 24 + i), t0 + ms(i as u64 * 10)),
+            ResizeResolved::Pending
+        );
+        // other events pass through
+        assert_eq!(
+            rd.feed(&Event::FocusLost, t0 + ms(i as u64 * 10)),
+            ResizeResolved::Continue
+        );
+    }
+    assert!(rd.is_pending());
+    assert_eq!(rd.pending_deadline(), Some(t0 + ms(190 + 50)));
+    assert_eq!(rd.resolve(t0 + ms(200)), ResizeResolved::Pending);
+    assert_eq!(rd.resolve(t0 + ms(240)), ResizeResolved::Resized(99, 43));
+    assert_eq!(rd.resolve(t0 + ms(300)), ResizeResolved::Continue);
+
+    // the next burst starts immediately again.
+    assert_eq!(
+        rd.feed(&Event::Resize(100, 30), t0 + ms(500)),
+        ResizeResolved::Resized(100, 30)
+    );
+    assert_eq!(
+        rd.feed(&Event::Resize(101, 30), t0 + ms(510)),
+        ResizeResolved::Pending
+    );
+    // resolve was missed, the next resize is reported at once.
+    assert_eq!(
+        rd.feed(&Event::Resize(102, 30), t0 + ms(600)),
+        ResizeResolved::Resized(102, 30)
+    );
+    assert!(!rd.is_pending());
+
+    rd.feed(&Event::Resize(103, 30), t0 + ms(610));
+    rd.reset();
+    assert!(!rd.is_pending());
+    assert_eq!(
+        rd.feed(&Event::Resize(104, 30), t0 + ms(620)),
+        ResizeResolved::Resized(104, 30)
+    );
+}
+
+#[test]
+fn key_map() {
+    use rat_event::crossterm::{KeyCombo, KeyMap, Keyed};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Action {
+        Save,
+        SaveAs,
+        Quit,
+    }
+
+    struct Editor {
+        saved: usize,
+    }
+
+    impl HandleEvent<Action, Regular, OutcomeOf<Action>> for Editor {
+        fn handle(&mut self, action: &Action, _qualifier: Regular) -> OutcomeOf<Action> {
+            match action {
+                Action::Save | Action::SaveAs => {
+                    self.saved += 1;
+                    OutcomeOf::Changed
+                }
+                Action::Quit => OutcomeOf::Value(Action::Quit),
+            }
+        }
+    }
+
+    use KeyEventKind::*;
+    let ctrl = KeyModifiers::CONTROL;
+    let shift = KeyModifiers::SHIFT;
+    let combo = |s: &str| s.parse::<KeyCombo>().expect("key");
+
+    let mut keymap = KeyMap::new();
+    assert!(keymap.is_empty());
+    assert_eq!(keymap.bind(combo("ctrl+s"), Action::Save), None);
+    assert_eq!(keymap.bind(combo("ctrl+shift+s"), Action::SaveAs), None);
+    assert_eq!(keymap.bind(combo("ctrl+q"), Action::Save), None);
+    // collision
+    assert_eq!(
+        keymap.bind(combo("ctrl+q"), Action::Quit),
+        Some(Action::Save)
+    );
+    assert_eq!(keymap.bind(combo("f2"), Action::Save), None);
+    assert_eq!(keymap.len(), 4);
+    assert_eq!(keymap.iter().count(), 4);
+
+    assert_eq!(
+        keymap.lookup(&KeyEvent::new(KeyCode::Char('s'), ctrl)),
+        Some(&Action::Save)
+    );
+    assert_eq!(
+        keymap.lookup(&KeyEvent::new(KeyCode::Char('s'), ctrl | shift)),
+        Some(&Action::SaveAs)
+    );
+    assert_eq!(
+        keymap.lookup(&KeyEvent::new(KeyCode::Char('S'), ctrl)),
+        Some(&Action::SaveAs)
+    );
+    assert_eq!(
+        keymap.lookup(&KeyEvent::new_with_kind(KeyCode::Char('s'), ctrl, Repeat)),
+        Some(&Action::Save)
+    );
+    assert_eq!(
+        keymap.lookup(&KeyEvent::new_with_kind(KeyCode::Char('s'), ctrl, Release)),
+        None
+    );
+    assert_eq!(keymap.get(&combo("ctrl+q")), Some(&Action::Quit));
+    assert_eq!(keymap.lookup_event(&Event::FocusLost), None);
+    assert_eq!(
+        keymap.keys_for(&Action::Save),
+        vec![combo("f2"), combo("ctrl+s")]
+    );
+
+    assert_eq!(keymap.unbind(combo("f2")), Some(Action::Save));
+    assert_eq!(keymap.unbind(combo("f2")), None);
+    assert_eq!(keymap.keys_for(&Action::Save), vec![combo("ctrl+s")]);
+
+    let mut editor = Editor { saved: 0 };
+    assert_eq!(
+        Keyed(&mut editor).handle(&key(KeyCode::Char('s'), ctrl, Press), &keymap),
+        OutcomeOf::Changed
+    );
+    assert_eq!(
+        Keyed(&mut editor).handle(&key(KeyCode::Char('s'), ctrl, Release), &keymap),
+        OutcomeOf::Continue
+    );
+    assert_eq!(
+        Keyed(&mut editor).handle(&key(KeyCode::Char('q'), ctrl, Press), &keymap),
+        OutcomeOf::Value(Action::Quit)
+    );
+    assert_eq!(
+        Keyed(&mut editor).handle(&key(KeyCode::Char('x'), ctrl, Press), &keymap),
+        OutcomeOf::Continue
+    );
+    assert_eq!(editor.saved, 1);
+
+    let keymap: KeyMap<Action> = [
+        (combo("ctrl+s"), Action::Save),
+        (combo("ctrl+s"), Action::SaveAs),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(keymap.len(), 1);
+    assert_eq!(keymap.get(&combo("ctrl+s")), Some(&Action::SaveAs));
+}
+
+#[test]
+fn key_sequence() {
+    use rat_event::crossterm::{KeyCombo, KeySequence, SeqResult};
+    use std::time::{Duration, Instant};
+
+    let k = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+    let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+    let seq_of = |keys: &[KeyEvent]| keys.iter().map(KeyCombo::from).collect::<Vec<_>>();
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+
+    let mut seq = KeySequence::new().timeout(ms(500));
+    assert_eq!(seq.get_timeout(), ms(500));
+    assert_eq!(seq.bind(seq_of(&[ctrl('k'), ctrl('c')]), "comment"), None);
+    assert_eq!(seq.bind(seq_of(&[ctrl('k'), ctrl('u')]), "uncomment"), None);
+    // g is a binding and a prefix
+    assert_eq!(seq.bind(seq_of(&[k('g')]), "go"), None);
+    assert_eq!(seq.bind(seq_of(&[k('g'), k('g')]), "top"), None);
+    assert_eq!(seq.bind(seq_of(&[k('z')]), "zap"), None);
+    assert_eq!(seq.bind(seq_of(&[k('z')]), "zip"), Some("zap"));
+
+    // nothing pending
+    assert_eq!(seq.deadline(), None);
+    assert_eq!(seq.resolve(t0), SeqResult::NoMatch(vec![]));
+    assert_eq!(seq.feed(&k('x'), t0), SeqResult::NoMatch(vec![k('x')]));
+    assert_eq!(seq.feed(&k('z'), t0), SeqResult::Matched("zip"));
+
+    // complete sequence
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert!(seq.is_pending());
+    assert_eq!(seq.deadline(), Some(t0 + ms(500)));
+    assert_eq!(
+        seq.feed(&ctrl('u'), t0 + ms(100)),
+        SeqResult::Matched("uncomment")
+    );
+    assert!(!seq.is_pending());
+
+    // releases are swallowed while pending, passed otherwise.
+    let release = KeyEvent::new_with_kind(
+        KeyCode::Char('k'),
+        KeyModifiers::CONTROL,
+        KeyEventKind::Release,
+    );
+    assert_eq!(seq.feed(&release, t0), SeqResult::NoMatch(vec![release]));
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&release, t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&ctrl('c'), t0 + ms(10)),
+        SeqResult::Matched("comment")
+    );
+
+    // no match replays the swallowed keys.
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&k('x'), t0 + ms(10)),
+        SeqResult::NoMatch(vec![ctrl('k'), k('x')])
+    );
+    assert_eq!(seq.deadline(), None);
+
+    // timeout cancels.
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert_eq!(seq.resolve(t0 + ms(499)), SeqResult::Pending);
+    assert_eq!(
+        seq.resolve(t0 + ms(500)),
+        SeqResult::NoMatch(vec![ctrl('k')])
+    );
+
+    // prefix that is a binding: longest match.
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('g'), t0 + ms(10)), SeqResult::Matched("top"));
+    // ... or timeout
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Pending);
+    assert_eq!(seq.resolve(t0 + ms(600)), SeqResult::Matched("go"));
+    // ... or another key. The key is looked at after the match.
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('x'), t0 + ms(10)), SeqResult::Matched("go"));
+    assert_eq!(seq.deadline(), Some(t0 + ms(10)));
+    assert_eq!(seq.resolve(t0 + ms(10)), SeqResult::NoMatch(vec![k('x')]));
+    assert_eq!(seq.deadline(), None);
+    // ... the key can start the next sequence.
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&ctrl('k'), t0 + ms(10)), SeqResult::Matched("go"));
+    assert_eq!(seq.resolve(t0 + ms(10)), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&ctrl('c'), t0 + ms(20)),
+        SeqResult::Matched("comment")
+    );
+
+    // an incomplete sequence followed by a binding.
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&k('z'), t0 + ms(10)),
+        SeqResult::NoMatch(vec![ctrl('k')])
+    );
+    assert_eq!(seq.resolve(t0 + ms(10)), SeqResult::Matched("zip"));
+
+    // a key after the timeout, without resolve in between.
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&ctrl('c'), t0 + ms(800)),
+        SeqResult::NoMatch(vec![ctrl('k')])
+    );
+    assert_eq!(
+        seq.resolve(t0 + ms(800)),
+        SeqResult::NoMatch(vec![ctrl('c')])
+    );
+
+    // reset
+    assert_eq!(seq.feed(&ctrl('k'), t0), SeqResult::Pending);
+    assert_eq!(seq.reset(), vec![ctrl('k')]);
+    assert!(!seq.is_pending());
+
+    assert_eq!(seq.unbind(seq_of(&[k('g')])), Some("go"));
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&k('x'), t0 + ms(10)),
+        SeqResult::NoMatch(vec![k('g'), k('x')])
+    );
+}
+
+#[test]
+fn count_prefix() {
+    use rat_event::crossterm::CountPrefix;
+
+    let k = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+
+    let mut cp = CountPrefix::new();
+    assert_eq!(cp.get_max(), 9999);
+
+    // 0 passes
+    assert!(!cp.feed(&k('0')));
+    assert_eq!(cp.take_count(), None);
+
+    // 10j
+    assert!(cp.feed(&k('1')));
+    assert!(cp.is_active());
+    assert!(cp.feed(&k('0')));
+    assert_eq!(cp.count(), Some(10));
+    assert!(!cp.feed(&k('j')));
+    assert_eq!(cp.take_count(), Some(10));
+    assert_eq!(cp.take_count(), None);
+
+    // Esc cancels, Esc without a count passes.
+    assert!(cp.feed(&k('5')));
+    assert!(cp.feed(&esc));
+    assert!(!cp.is_active());
+    assert!(!cp.feed(&esc));
+    assert!(!cp.feed(&k('0')));
+
+    // modifiers and releases
+    assert!(!cp.feed(&KeyEvent::new(KeyCode::Char('3'), KeyModifiers::CONTROL)));
+    assert!(cp.feed(&KeyEvent::new(KeyCode::Char('3'), KeyModifiers::SHIFT)));
+    assert!(!cp.feed(&KeyEvent::new_with_kind(
+        KeyCode::Char('3'),
+        KeyModifiers::NONE,
+        KeyEventKind::Release
+    )));
+    assert_eq!(cp.take_count(), Some(3));
+
+    // cap
+    let mut cp = CountPrefix::new().max(100);
+    for _ in 0..30 {
+        assert!(cp.feed(&k('9')));
+    }
+    assert_eq!(cp.take_count(), Some(100));
+
+    // as event-handler
+    let mut cp = CountPrefix::new();
+    assert_eq!(
+        cp.handle(
+            &key(KeyCode::Char('4'), KeyModifiers::NONE, KeyEventKind::Press),
+            Regular
+        ),
+        Outcome::Changed
+    );
+    assert_eq!(
+        cp.handle(
+            &key(KeyCode::Char('j'), KeyModifiers::NONE, KeyEventKind::Press),
+            Regular
+        ),
+        Outcome::Continue
+    );
+    assert_eq!(cp.handle(&Event::FocusLost, Regular), Outcome::Continue);
+    assert_eq!(cp.take_count(), Some(4));
+    cp.feed(&k('2'));
+    cp.reset();
+    assert_eq!(cp.count(), None);
+}
+
+#[test]
+fn key_sequence_leader() {
+    use rat_event::crossterm::{KeyCombo, KeySequence, SeqResult};
+    use std::time::{Duration, Instant};
+
+    let k = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+    let combo = |c| KeyCombo::new(KeyCode::Char(c), KeyModifiers::NONE);
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+
+    let mut seq = KeySequence::new().timeout(ms(500));
+    assert!(seq.is_replay_leader());
+    seq.bind_leader([combo('f'), combo('f')], "find file");
+    seq.bind_leader([combo('f'), combo('g')], "grep");
+    seq.bind_leader([combo('w')], "save");
+    seq.bind([combo('g'), combo('g')], "top");
+
+    // no leader, no leader bindings.
+    assert_eq!(seq.leader(), None);
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::NoMatch(vec![k(' ')]));
+
+    seq.set_leader(combo(' '));
+    assert_eq!(seq.leader(), Some(combo(' ')));
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(seq.pending_keys(), &[k(' ')]);
+    assert_eq!(
+        seq.continuations(),
+        vec![
+            (vec![combo('f'), combo('f')], &"find file"),
+            (vec![combo('f'), combo('g')], &"grep"),
+            (vec![combo('w')], &"save"),
+        ]
+    );
+    assert_eq!(seq.feed(&k('f'), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.continuations(),
+        vec![
+            (vec![combo('f')], &"find file"),
+            (vec![combo('g')], &"grep")
+        ]
+    );
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Matched("grep"));
+    assert_eq!(seq.pending_keys(), &[]);
+    assert_eq!(seq.continuations().len(), 4);
+
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('w'), t0), SeqResult::Matched("save"));
+
+    // unknown key and timeout replay the leader
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&k('x'), t0),
+        SeqResult::NoMatch(vec![k(' '), k('x')])
+    );
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(seq.resolve(t0 + ms(500)), SeqResult::NoMatch(vec![k(' ')]));
+
+    // ... or not.
+    let mut seq = seq.replay_leader(false);
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('f'), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('x'), t0), SeqResult::NoMatch(vec![k('x')]));
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(seq.resolve(t0 + ms(500)), SeqResult::NoMatch(vec![]));
+    // the aborting key can start another sequence
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::NoMatch(vec![]));
+    assert_eq!(seq.resolve(t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Matched("top"));
+    // other sequences are still replayed
+    assert_eq!(seq.feed(&k('g'), t0), SeqResult::Pending);
+    assert_eq!(
+        seq.feed(&k('x'), t0),
+        SeqResult::NoMatch(vec![k('g'), k('x')])
+    );
+
+    // changing the leader changes the bindings
+    seq.set_leader(KeyCombo::new(KeyCode::Char('\\'), KeyModifiers::NONE));
+    assert_eq!(seq.feed(&k(' '), t0), SeqResult::NoMatch(vec![k(' ')]));
+    assert_eq!(seq.feed(&k('\\'), t0), SeqResult::Pending);
+    assert_eq!(seq.feed(&k('w'), t0), SeqResult::Matched("save"));
+
+    assert_eq!(seq.unbind_leader([combo('w')]), Some("save"));
+    seq.clear_leader();
+    assert_eq!(seq.feed(&k('\\'), t0), SeqResult::NoMatch(vec![k('\\')]));
+}
+
+#[test]
+fn key_map_rebind() {
+    use rat_event::crossterm::{
+        BindError, Conflict, ConflictKind, KeyCombo, KeyMap, KeyMapChange, KeySequence,
+    };
+
+    let combo = |s: &str| s.parse::<KeyCombo>().expect("key");
+
+    let defaults = KeyMap::from_iter([
+        (combo("ctrl+s"), "save"),
+        (combo("ctrl+q"), "quit"),
+        (combo("ctrl+k"), "kill"),
+        (combo("f1"), "help"),
+    ]);
+
+    let mut keymap = defaults.clone();
+    assert_eq!(keymap.rebind(combo("ctrl+q"), combo("alt+x")), Ok(()));
+    assert_eq!(keymap.get(&combo("alt+x")), Some(&"quit"));
+    assert_eq!(keymap.get(&combo("ctrl+q")), None);
+    assert_eq!(
+        keymap.rebind(combo("ctrl+q"), combo("alt+q")),
+        Err(BindError::NotBound(combo("ctrl+q")))
+    );
+    assert_eq!(
+        keymap.rebind(combo("ctrl+s"), combo("f1")),
+        Err(BindError::Taken(combo("f1")))
+    );
+    assert_eq!(keymap.get(&combo("ctrl+s")), Some(&"save"));
+    assert_eq!(keymap.rebind(combo("ctrl+s"), combo("ctrl+s")), Ok(()));
+    assert_eq!(
+        BindError::Taken(combo("f1")).to_string(),
+        "'F1' is already bound"
+    );
+
+    keymap.unbind(combo("f1"));
+    keymap.bind(combo("ctrl+k"), "cut");
+    keymap.bind(combo("f2"), "rename");
+    assert_eq!(
+        defaults.diff(&keymap),
+        vec![
+            KeyMapChange::Removed(combo("f1"), &"help"),
+            KeyMapChange::Added(combo("f2"), &"rename"),
+            KeyMapChange::Changed(combo("ctrl+k"), &"kill", &"cut"),
+            KeyMapChange::Removed(combo("ctrl+q"), &"quit"),
+            KeyMapChange::Added(combo("alt+x"), &"quit"),
+        ]
+    );
+    assert_eq!(defaults.diff(&defaults), vec![]);
+
+    let mut seq = KeySequence::new();
+    seq.bind([combo("ctrl+k"), combo("ctrl+c")], "comment");
+    seq.bind([combo("g")], "go");
+    seq.bind([combo("g"), combo("g")], "top");
+    seq.bind([combo("f2")], "rename file");
+    seq.set_leader(combo("space"));
+    seq.bind_leader([combo("f")], "find");
+
+    assert_eq!(
+        seq.conflicts(),
+        vec![Conflict {
+            kind: ConflictKind::Prefix,
+            binding: vec![combo("g")],
+            other: vec![combo("g"), combo("g")],
+        }]
+    );
+
+    keymap.bind(combo("space"), "page down");
+    let conflicts = keymap.conflicts(&seq);
+    assert_eq!(
+        conflicts
+            .iter()
+            .map(|v| (v.kind, v.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                ConflictKind::Duplicate,
+                "'F2' is bound in the keymap and as a sequence".to_string()
+            ),
+            (
+                ConflictKind::Shadowed,
+                "'Space' hides the sequence 'Space f'".to_string()
+            ),
+            (
+                ConflictKind::Shadowed,
+                "'Ctrl+k' hides the sequence 'Ctrl+k Ctrl+c'".to_string()
+            ),
+            (
+                ConflictKind::Prefix,
+                "'g' is the start of 'g g' and only fires after a timeout".to_string()
+            ),
+        ]
+    );
+    assert_eq!(conflicts[1].binding, vec![combo("space")]);
+    assert_eq!(conflicts[1].other, vec![combo("space"), combo("f")]);
+}
+
+#[test]
+fn key_layers() {
+    use rat_event::crossterm::{KeyCombo, KeyLayers, KeyMap, Keyed};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Action {
+        Left,
+        Quit,
+        ResizeMode,
+        Grow,
+        Done,
+        Normal,
+    }
+
+    struct App {
+        width: u16,
+        log: Vec<Action>,
+    }
+
+    impl HandleEvent<Action, Regular, OutcomeOf<Action>> for App {
+        fn handle(&mut self, action: &Action, _qualifier: Regular) -> OutcomeOf<Action> {
+            self.log.push(*action);
+            match action {
+                Action::Grow => {
+                    self.width += 1;
+                    OutcomeOf::Changed
+                }
+                Action::ResizeMode | Action::Done | Action::Normal => OutcomeOf::Value(*action),
+                _ => OutcomeOf::Changed,
+            }
+        }
+    }
+
+    let combo = |s: &str| s.parse::<KeyCombo>().expect("key");
+    let k = |c| key(KeyCode::Char(c), KeyModifiers::NONE, KeyEventKind::Press);
+    let esc = key(KeyCode::Esc, KeyModifiers::NONE, KeyEventKind::Press);
+
+    let normal = KeyMap::from_iter([
+        (combo("h"), Action::Left),
+        (combo("q"), Action::Quit),
+        (combo("ctrl+r"), Action::ResizeMode),
+    ]);
+    let resize = KeyMap::from_iter([(combo("l"), Action::Grow), (combo("esc"), Action::Done)]);
+    let insert = KeyMap::from_iter([(combo("esc"), Action::Normal)]);
+
+    let mut layers = KeyLayers::new();
+    assert!(layers.is_empty());
+    assert!(layers
+        .lookup(&KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE))
+        .is_none());
+    layers.push_layer(normal, false);
+
+    let mut app = App {
+        width: 10,
+        log: Vec::new(),
+    };
+
+    // a stream of events; the actions switch the layers.
+    let events = [
+        k('l'),
+        key(
+            KeyCode::Char('r'),
+            KeyModifiers::CONTROL,
+            KeyEventKind::Press,
+        ),
+        k('l'),
+        k('l'),
+        // fall-through
+        k('h'),
+        esc.clone(),
+        k('l'),
+        k('i'),
+    ];
+    let mut unhandled = Vec::new();
+    for event in &events {
+        match Keyed(&mut app).handle(event, &layers) {
+            OutcomeOf::Value(Action::ResizeMode) => layers.push_layer(resize.clone(), false),
+            OutcomeOf::Value(Action::Done) => {
+                layers.pop_layer();
+            }
+            OutcomeOf::Continue => unhandled.push(event.clone()),
+            _ => {}
+        }
+    }
+    assert_eq!(app.width, 12);
+    assert_eq!(
+        app.log,
+        vec![
+            Action::ResizeMode,
+            Action::Grow,
+            Action::Grow,
+            Action::Left,
+            Action::Done,
+        ]
+    );
+    assert_eq!(unhandled, vec![k('l'), k('l'), k('i')]);
+    assert_eq!(layers.len(), 1);
+
+    // an opaque insert layer hides normal mode.
+    layers.push_layer(insert, true);
+    assert_eq!(
+        Keyed(&mut app).handle(&k('q'), &layers),
+        OutcomeOf::Continue
+    );
+    assert_eq!(
+        Keyed(&mut app).handle(&esc, &layers),
+        OutcomeOf::Value(Action::Normal)
+    );
+    assert_eq!(layers.top().map(|v| v.len()), Some(1));
+    layers
+        .top_mut()
+        .expect("layer")
+        .bind(combo("ctrl+q"), Action::Quit);
+    assert_eq!(
+        Keyed(&mut app).handle(
+            &key(
+                KeyCode::Char('q'),
+                KeyModifiers::CONTROL,
+                KeyEventKind::Press
+            ),
+            &layers
+        ),
+        OutcomeOf::Changed
+    );
+    assert_eq!(layers.pop_layer().map(|v| v.len()), Some(2));
+    assert_eq!(Keyed(&mut app).handle(&k('q'), &layers), OutcomeOf::Changed);
+    assert_eq!(app.log.last(), Some(&Action::Quit));
+}
+
+#[test]
+fn key_map_entries() {
+    use rat_event::crossterm::{BindingHelp, KeyCombo, KeyLayers, KeyMap, KeySequence};
+
+    let combo = |s: &str| s.parse::<KeyCombo>().expect("key");
+
+    let mut keymap = KeyMap::new();
+    keymap.bind_with(
+        combo("ctrl+s"),
+        "save",
+        BindingHelp::new("Save").category("File"),
+    );
+    keymap.bind_with(
+        combo("ctrl+o"),
+        "open",
+        BindingHelp::new("Open").category("File"),
+    );
+    keymap.bind_with(combo("f1"), "help", "Help");
+    keymap.bind(combo("up"), "up");
+    assert_eq!(keymap.help(&combo("f1")), Some(&BindingHelp::new("Help")));
+    assert_eq!(keymap.help(&combo("up")), None);
+
+    let entries = keymap
+        .entries()
+        .map(|v| (v.category, v.key, *v.action, v.description))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            (None, "Up".to_string(), "up", None),
+            (None, "F1".to_string(), "help", Some("Help")),
+            (Some("File"), "Ctrl+o".to_string(), "open", Some("Open")),
+            (Some("File"), "Ctrl+s".to_string(), "save", Some("Save")),
+        ]
+    );
+
+    // the help follows the binding
+    keymap.rebind(combo("f1"), combo("f2")).expect("rebind");
+    assert_eq!(keymap.help(&combo("f2")), Some(&BindingHelp::new("Help")));
+    keymap.bind(combo("f2"), "other");
+    assert_eq!(keymap.help(&combo("f2")), None);
+    keymap.unbind(combo("ctrl+o"));
+    assert_eq!(keymap.help(&combo("ctrl+o")), None);
+
+    // sequences
+    let mut seq = KeySequence::new();
+    seq.bind_with([combo("ctrl+k"), combo("ctrl+c")], "comment", "Comment");
+    seq.bind_leader_with(
+        [combo("f")],
+        "find",
+        BindingHelp::new("Find").category("File"),
+    );
+    seq.bind([combo("g"), combo("g")], "top");
+    let entries = seq.entries().map(|v| v.key).collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec!["g g".to_string(), "Ctrl+k Ctrl+c".to_string()]
+    );
+
+    seq.set_leader(combo("space"));
+    let entries = seq
+        .entries()
+        .map(|v| (v.key, v.description))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            ("g g".to_string(), None),
+            ("Ctrl+k Ctrl+c".to_string(), Some("Comment")),
+            ("Space f".to_string(), Some("Find")),
+        ]
+    );
+    let entry = seq.entries().last().expect("entry");
+    assert_eq!(entry.keys, vec![combo("space"), combo("f")]);
+    assert_eq!(entry.category, Some("File"));
+
+    // layers
+    let mut layers = KeyLayers::new();
+    layers.push_layer(keymap, false);
+    layers.push_layer(
+        KeyMap::from_iter([(combo("up"), "grow"), (combo("esc"), "done")]),
+        false,
+    );
+    let entries = layers
+        .entries()
+        .map(|v| (v.key, *v.action))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            ("Up".to_string(), "grow"),
+            ("F2".to_string(), "other"),
+            ("Esc".to_string(), "done"),
+            ("Ctrl+s".to_string(), "save"),
+        ]
+    );
+    layers.push_layer(KeyMap::from_iter([(combo("esc"), "normal")]), true);
+    let entries = layers.entries().map(|v| *v.action).collect::<Vec<_>>();
+    assert_eq!(entries, vec!["normal"]);
+}
+
+#[test]
+fn hot_key_registry() {
+    use rat_event::crossterm::{BindError, HotKeyRegistry, KeyCombo};
+    use rat_event::{ConsumedEvent, HotKey};
+
+    let combo = |s: &str| s.parse::<KeyCombo>().expect("key");
+
+    // a text field that uses ctrl+q for quoted insert.
+    struct TextField {
+        text: String,
+        quote: bool,
+    }
+
+    impl TextField {
+        fn claims(&self) -> Vec<KeyCombo> {
+            vec!["ctrl+q".parse().expect("key")]
+        }
+    }
+
+    impl HandleEvent<Event, Regular, Outcome> for TextField {
+        fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+            match event {
+                ct_event!(key press CONTROL-'q') => {
+                    self.quote = true;
+                    Outcome::Changed
+                }
+                ct_event!(key press c) => {
+                    self.text.push(*c);
+                    Outcome::Changed
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    let mut hotkeys = HotKeyRegistry::new();
+    assert_eq!(hotkeys.register(combo("ctrl+q"), "quit"), Ok(()));
+    assert_eq!(hotkeys.register(combo("f1"), "help"), Ok(()));
+    assert_eq!(
+        hotkeys.register(combo("ctrl+q"), "query"),
+        Err(BindError::Taken(combo("ctrl+q")))
+    );
+    assert_eq!(hotkeys.get(&combo("ctrl+q")), Some(&"quit"));
+    assert_eq!(hotkeys.len(), 2);
+
+    let mut field = TextField {
+        text: String::new(),
+        quote: false,
+    };
+
+    let container = |hotkeys: &mut HotKeyRegistry<&'static str>,
+                     field: &mut TextField,
+                     event: &Event|
+     -> OutcomeOf<&'static str> {
+        let r = hotkeys.handle(event, HotKey);
+        if r.is_consumed() {
+            return r;
+        }
+        field.handle(event, Regular).into()
+    };
+
+    let ctrl_q = key(
+        KeyCode::Char('q'),
+        KeyModifiers::CONTROL,
+        KeyEventKind::Press,
+    );
+    let f1 = key(KeyCode::F(1), KeyModifiers::NONE, KeyEventKind::Press);
+    let a = key(KeyCode::Char('a'), KeyModifiers::NONE, KeyEventKind::Press);
+
+    // field not focused
+    assert_eq!(
+        container(&mut hotkeys, &mut field, &ctrl_q),
+        OutcomeOf::Value("quit")
+    );
+    assert!(!field.quote);
+    assert_eq!(
+        hotkeys.handle(
+            &key(
+                KeyCode::Char('q'),
+                KeyModifiers::CONTROL,
+                KeyEventKind::Release
+            ),
+            HotKey
+        ),
+        OutcomeOf::Continue
+    );
+
+    // field focused
+    hotkeys.set_claims(field.claims());
+    assert!(hotkeys.is_claimed(&combo("ctrl+q")));
+    assert_eq!(
+        container(&mut hotkeys, &mut field, &ctrl_q),
+        OutcomeOf::Changed
+    );
+    assert!(field.quote);
+    assert_eq!(
+        container(&mut hotkeys, &mut field, &f1),
+        OutcomeOf::Value("help")
+    );
+    assert_eq!(container(&mut hotkeys, &mut field, &a), OutcomeOf::Changed);
+    assert_eq!(field.text, "a");
+
+    // focus lost
+    hotkeys.clear_claims();
+    assert_eq!(
+        container(&mut hotkeys, &mut field, &ctrl_q),
+        OutcomeOf::Value("quit")
+    );
+    assert_eq!(hotkeys.unregister(combo("ctrl+q")), Some("quit"));
+    assert_eq!(
+        container(&mut hotkeys, &mut field, &ctrl_q),
+        OutcomeOf::Changed
+    );
+}
+
+#[test]
+fn z_areas() {
+    use rat_event::crossterm::ZAreas;
+
+    let mouse = |kind, column, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let click = |column, row| mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+
+    let mut zareas = ZAreas::new();
+    assert_eq!(zareas.route_mouse(&click(0, 0)), None);
+
+    zareas.add("tooltip", Rect::new(0, 0, 10, 1), 3);
+    zareas.add("dropdown", Rect::new(0, 0, 20, 10), 1);
+    zareas.add("menu", Rect::new(5, 5, 10, 10), 2);
+    zareas.add("other", Rect::new(10, 0, 10, 10), 1);
+    assert_eq!(zareas.len(), 4);
+
+    assert_eq!(zareas.route_mouse(&click(1, 0)), Some(&"tooltip"));
+    assert_eq!(zareas.route_mouse(&click(1, 1)), Some(&"dropdown"));
+    assert_eq!(zareas.route_mouse(&click(6, 6)), Some(&"menu"));
+    assert_eq!(
+        zareas.route_mouse(&mouse(MouseEventKind::ScrollDown, 6, 12)),
+        Some(&"menu")
+    );
+    // same z, added later
+    assert_eq!(zareas.route_mouse(&click(15, 1)), Some(&"other"));
+    assert_eq!(zareas.route_mouse(&click(30, 30)), None);
+    assert_eq!(
+        zareas.route_mouse(&key(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            KeyEventKind::Press
+        )),
+        None
+    );
+
+    let area = Rect::new(0, 0, 40, 40);
+    assert!(zareas.is_obscured(area, 0, &click(6, 6)));
+    assert!(zareas.is_obscured(area, 1, &click(6, 6)));
+    assert!(!zareas.is_obscured(area, 2, &click(6, 6)));
+    assert!(!zareas.is_obscured(area, 0, &click(30, 30)));
+    assert!(!zareas.is_obscured(Rect::new(30, 30, 5, 5), 0, &click(6, 6)));
+    assert!(!zareas.is_obscured(
+        area,
+        0,
+        &key(KeyCode::Enter, KeyModifiers::NONE, KeyEventKind::Press)
+    ));
+
+    let order = zareas.iter().map(|(id, _, z)| (*id, z)).collect::<Vec<_>>();
+    assert_eq!(
+        order,
+        vec![("dropdown", 1), ("other", 1), ("menu", 2), ("tooltip", 3)]
+    );
+
+    zareas.clear();
+    assert!(zareas.is_empty());
+    assert_eq!(zareas.route_mouse(&click(6, 6)), None);
+}
+
+#[test]
+fn dialog_stack() {
+    use rat_event::crossterm::DialogStack;
+    use rat_event::Dialog;
+
+    struct Settings {
+        value: u32,
+    }
+
+    impl HandleEvent<Event, Dialog, OutcomeOf<&'static str>> for Settings {
+        fn handle(&mut self, event: &Event, _qualifier: Dialog) -> OutcomeOf<&'static str> {
+            match event {
+                ct_event!(key press '+') => {
+                    self.value += 1;
+                    OutcomeOf::Changed
+                }
+                ct_event!(keycode press Enter) => OutcomeOf::Value("save"),
+                _ => OutcomeOf::Continue,
+            }
+        }
+    }
+
+    struct ErrorBox;
+
+    impl HandleEvent<Event, Dialog, OutcomeOf<&'static str>> for ErrorBox {
+        fn handle(&mut self, event: &Event, _qualifier: Dialog) -> OutcomeOf<&'static str> {
+            match event {
+                ct_event!(keycode press Enter) => OutcomeOf::Value("ok"),
+                _ => OutcomeOf::Continue,
+            }
+        }
+    }
+
+    type Dlg = Box<dyn HandleEvent<Event, Dialog, OutcomeOf<&'static str>>>;
+
+    let plus = key(KeyCode::Char('+'), KeyModifiers::NONE, KeyEventKind::Press);
+    let enter = key(KeyCode::Enter, KeyModifiers::NONE, KeyEventKind::Press);
+    let esc = key(KeyCode::Esc, KeyModifiers::NONE, KeyEventKind::Press);
+    let esc_release = key(KeyCode::Esc, KeyModifiers::NONE, KeyEventKind::Release);
+
+    let mut dialogs: DialogStack<Dlg> = DialogStack::new();
+    assert_eq!(dialogs.handle(&plus, Dialog), OutcomeOf::Continue);
+    assert_eq!(dialogs.handle(&esc, Dialog), OutcomeOf::Continue);
+
+    dialogs.push(Box::new(Settings { value: 0 }));
+    assert_eq!(dialogs.handle(&plus, Dialog), OutcomeOf::Changed);
+    assert_eq!(dialogs.handle(&enter, Dialog), OutcomeOf::Value("save"));
+
+    // error box on top, settings are frozen.
+    dialogs.push(Box::new(ErrorBox));
+    assert_eq!(dialogs.len(), 2);
+    assert_eq!(dialogs.handle(&plus, Dialog), OutcomeOf::Unchanged);
+    assert_eq!(dialogs.handle(&esc_release, Dialog), OutcomeOf::Unchanged);
+    assert_eq!(dialogs.handle(&enter, Dialog), OutcomeOf::Value("ok"));
+    assert_eq!(dialogs.len(), 2);
+    assert_eq!(dialogs.handle(&esc, Dialog), OutcomeOf::Changed);
+    assert_eq!(dialogs.len(), 1);
+
+    // settings again
+    assert_eq!(dialogs.handle(&plus, Dialog), OutcomeOf::Changed);
+    assert!(dialogs.top_mut().is_some());
+    assert_eq!(dialogs.iter().count(), 1);
+    assert_eq!(dialogs.handle(&esc, Dialog), OutcomeOf::Changed);
+    assert!(dialogs.is_empty());
+    assert_eq!(dialogs.handle(&plus, Dialog), OutcomeOf::Continue);
+
+    // render order
+    let mut names = DialogStack::new();
+    names.push("settings");
+    names.push("error");
+    assert_eq!(
+        names.iter().copied().collect::<Vec<_>>(),
+        vec!["settings", "error"]
+    );
+    assert_eq!(names.top(), Some(&"error"));
+    assert_eq!(names.pop(), Some("error"));
+    names.clear();
+    assert!(names.is_empty());
+}
+
+#[test]
+fn edit_event() {
+    use rat_event::crossterm::is_edit_event;
+
+    let press = |code, modifiers| key(code, modifiers, KeyEventKind::Press);
+
+    assert!(is_edit_event(&press(
+        KeyCode::Char('a'),
+        KeyModifiers::NONE
+    )));
+    assert!(is_edit_event(&press(
+        KeyCode::Char('A'),
+        KeyModifiers::SHIFT
+    )));
+    assert!(is_edit_event(&press(KeyCode::Enter, KeyModifiers::NONE)));
+    assert!(is_edit_event(&press(
+        KeyCode::Backspace,
+        KeyModifiers::CONTROL
+    )));
+    assert!(is_edit_event(&press(KeyCode::Delete, KeyModifiers::NONE)));
+    assert!(is_edit_event(&press(
+        KeyCode::Char('v'),
+        KeyModifiers::CONTROL
+    )));
+    assert!(is_edit_event(&press(
+        KeyCode::Char('Z'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT
+    )));
+    assert!(is_edit_event(&key(
+        KeyCode::Char('a'),
+        KeyModifiers::NONE,
+        KeyEventKind::Repeat
+    )));
+    assert!(is_edit_event(&Event::Paste("text".into())));
+
+    assert!(!is_edit_event(&key(
+        KeyCode::Char('a'),
+        KeyModifiers::NONE,
+        KeyEventKind::Release
+    )));
+    assert!(!is_edit_event(&press(
+        KeyCode::Char('c'),
+        KeyModifiers::CONTROL
+    )));
+    assert!(!is_edit_event(&press(
+        KeyCode::Char('x'),
+        KeyModifiers::ALT
+    )));
+    assert!(!is_edit_event(&press(KeyCode::Left, KeyModifiers::NONE)));
+    assert!(!is_edit_event(&press(KeyCode::Tab, KeyModifiers::NONE)));
+    assert!(!is_edit_event(&Event::FocusGained));
+}
+
+#[test]
+fn hover() {
+    use rat_event::{broadcast, Hover};
+
+    struct ListState {
+        area: Rect,
+        rows: Vec<Rect>,
+        hovered: Option<usize>,
+        mouse: MouseFlags,
+    }
+
+    impl ListState {
+        fn new(area: Rect) -> Self {
+            Self {
+                area,
+                rows: (0..area.height)
+                    .map(|v| Rect::new(area.x, area.y + v, area.width, 1))
+                    .collect(),
+                hovered: None,
+                mouse: Default::default(),
+            }
+        }
+    }
+
+    impl HandleEvent<Event, Hover, OutcomeOf<Option<usize>>> for ListState {
+        fn handle(&mut self, event: &Event, _qualifier: Hover) -> OutcomeOf<Option<usize>> {
+            match event {
+                Event::Mouse(
+                    m @ MouseEvent {
+                        kind: MouseEventKind::Moved,
+                        ..
+                    },
+                ) => {
+                    self.mouse.hover(self.area, m);
+                    let hovered = if self.mouse.hover.get() {
+                        self.mouse.row_at(&self.rows, m.row)
+                    } else {
+                        None
+                    };
+                    if hovered != self.hovered {
+                        self.hovered = hovered;
+                        OutcomeOf::Value(hovered)
+                    } else if hovered.is_some() {
+                        OutcomeOf::Unchanged
+                    } else {
+                        OutcomeOf::Continue
+                    }
+                }
+                _ => OutcomeOf::Continue,
+            }
+        }
+    }
+
+    let mouse = |kind, column, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let moved = |column, row| mouse(MouseEventKind::Moved, column, row);
+
+    let mut left = ListState::new(Rect::new(0, 0, 10, 5));
+    let mut right = ListState::new(Rect::new(10, 0, 10, 5));
+
+    assert_eq!(left.handle(&moved(1, 2), Hover), OutcomeOf::Value(Some(2)));
+    assert_eq!(left.handle(&moved(5, 2), Hover), OutcomeOf::Unchanged);
+    assert_eq!(left.handle(&moved(5, 3), Hover), OutcomeOf::Value(Some(3)));
+    assert_eq!(left.handle(&moved(30, 3), Hover), OutcomeOf::Value(None));
+    assert_eq!(left.handle(&moved(30, 4), Hover), OutcomeOf::Continue);
+
+    // never consumes anything but moves.
+    for event in [
+        mouse(MouseEventKind::Down(MouseButton::Left), 1, 1),
+        mouse(MouseEventKind::Up(MouseButton::Left), 1, 1),
+        mouse(MouseEventKind::ScrollDown, 1, 1),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 1, 1),
+        key(KeyCode::Down, KeyModifiers::NONE, KeyEventKind::Press),
+    ] {
+        assert_eq!(left.handle(&event, Hover), OutcomeOf::Continue);
+    }
+    assert_eq!(left.hovered, None);
+
+    // moving from one list to the other.
+    let mut r = Outcome::Continue;
+    broadcast!(r: left.handle(&moved(9, 0), Hover), right.handle(&moved(9, 0), Hover));
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!((left.hovered, right.hovered), (Some(0), None));
+
+    let mut r = Outcome::Continue;
+    broadcast!(r: left.handle(&moved(10, 0), Hover), right.handle(&moved(10, 0), Hover));
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!((left.hovered, right.hovered), (None, Some(0)));
+}
+
+#[test]
+fn clipped() {
+    use rat_event::util::{clip_hit, row_at};
+    use rat_event::{Clipped, ConsumedEvent};
+
+    let mouse = |kind, column, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let click = |column, row| mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+
+    // a list, scrolled up by 5 rows inside a viewport.
+    struct ListState {
+        area: Rect,
+        rows: Vec<Rect>,
+        selected: Option<usize>,
+    }
+
+    impl HandleEvent<Event, Clipped, Outcome> for ListState {
+        fn handle(&mut self, event: &Event, Clipped(clip): Clipped) -> Outcome {
+            match event {
+                ct_event!(mouse down Left for column, row)
+                    if clip_hit(self.area, clip, *column, *row) =>
+                {
+                    self.selected = row_at(&self.rows, *row);
+                    Outcome::Changed
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    struct ScrollState {
+        viewport: Rect,
+        list: ListState,
+    }
+
+    impl HandleEvent<Event, Regular, Outcome> for ScrollState {
+        fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+            let r = self.list.handle(event, Clipped(self.viewport));
+            if r.is_consumed() {
+                return r;
+            }
+            match event {
+                ct_event!(mouse down Left for column, row)
+                    if self.viewport.contains((*column, *row).into()) =>
+                {
+                    Outcome::Unchanged
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    let area = Rect::new(0, 5, 10, 20);
+    let mut scroll = ScrollState {
+        viewport: Rect::new(0, 10, 10, 5),
+        list: ListState {
+            area,
+            rows: (0..area.height)
+                .map(|v| Rect::new(area.x, area.y + v, area.width, 1))
+                .collect(),
+            selected: None,
+        },
+    };
+
+    assert_eq!(scroll.handle(&click(1, 6), Regular), Outcome::Continue);
+    assert_eq!(scroll.list.selected, None);
+    assert_eq!(scroll.handle(&click(1, 20), Regular), Outcome::Continue);
+    assert_eq!(scroll.list.selected, None);
+    assert_eq!(scroll.handle(&click(1, 12), Regular), Outcome::Changed);
+    assert_eq!(scroll.list.selected, Some(7));
+
+    // MouseFlags
+    let flags = MouseFlags::default();
+    let clip = Some(Rect::new(0, 10, 10, 5));
+    let drag = |column, row| mouse(MouseEventKind::Drag(MouseButton::Left), column, row);
+    let as_mouse = |event: Event| match event {
+        Event::Mouse(m) => m,
+        _ => unreachable!(),
+    };
+
+    assert!(!flags.hover_clipped(area, clip, &as_mouse(mouse(MouseEventKind::Moved, 1, 6))));
+    assert!(!flags.hover.get());
+    assert!(flags.hover_clipped(area, clip, &as_mouse(mouse(MouseEventKind::Moved, 1, 11))));
+    // still hovering without the clip.
+    assert!(!flags.hover_clipped(area, None, &as_mouse(mouse(MouseEventKind::Moved, 1, 6))));
+    assert!(flags.hover.get());
+
+    // drag started in the hidden part.
+    flags.drag_clipped(area, clip, &as_mouse(click(1, 6)), KeyModifiers::NONE);
+    assert!(!flags.drag_clipped(area, clip, &as_mouse(drag(1, 12)), KeyModifiers::NONE));
+    // and in the visible part.
+    flags.drag_clipped(area, clip, &as_mouse(click(1, 11)), KeyModifiers::NONE);
+    assert!(flags.drag_clipped(area, clip, &as_mouse(drag(1, 20)), KeyModifiers::NONE));
+    // without clip
+    flags.drag_clipped(area, None, &as_mouse(click(1, 6)), KeyModifiers::NONE);
+    assert!(flags.drag_clipped(area, None, &as_mouse(drag(1, 7)), KeyModifiers::NONE));
+
+    let up = |column, row| mouse(MouseEventKind::Up(MouseButton::Left), column, row);
+    let dclick = |column, row, clip| {
+        let mut r = false;
+        for event in [
+            click(column, row),
+            up(column, row),
+            click(column, row),
+            up(column, row),
+        ] {
+            r = flags.doubleclick_clipped(area, clip, &as_mouse(event), KeyModifiers::NONE);
+        }
+        r
+    };
+    assert!(!dclick(1, 6, clip));
+    assert!(dclick(1, 11, clip));
+    assert!(dclick(1, 6, None));
+}
+
+#[test]
+fn disabled() {
+    use rat_event::crossterm::Disabled;
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct SliderState {
+        area: Rect,
+        value: u32,
+        text: String,
+        calls: u32,
+    }
+
+    impl HandleEvent<Event, Regular, Outcome> for SliderState {
+        fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+            self.calls += 1;
+            match event {
+                ct_event!(keycode press Right) => {
+                    self.value += 1;
+                    Outcome::Changed
+                }
+                ct_event!(mouse down Left for column, row)
+                    if self.area.contains((*column, *row).into()) =>
+                {
+                    self.value = *column as u32;
+                    Outcome::Changed
+                }
+                Event::Paste(s) => {
+                    self.text.push_str(s);
+                    Outcome::Changed
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    let mouse = |kind, column, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let right = key(KeyCode::Right, KeyModifiers::NONE, KeyEventKind::Press);
+    let right_release = key(KeyCode::Right, KeyModifiers::NONE, KeyEventKind::Release);
+    let click_in = mouse(MouseEventKind::Down(MouseButton::Left), 5, 2);
+    let click_out = mouse(MouseEventKind::Down(MouseButton::Left), 5, 20);
+    let scroll_in = mouse(MouseEventKind::ScrollDown, 5, 2);
+    let paste = Event::Paste("abc".into());
+
+    let area = Rect::new(0, 2, 10, 1);
+    let mut slider = SliderState {
+        area,
+        ..Default::default()
+    };
+
+    let handle = |slider: &mut SliderState, event: &Event, disabled: bool, focused: bool| {
+        Disabled::new(slider, disabled)
+            .focused(focused)
+            .area(area)
+            .handle(event, Regular)
+    };
+
+    // disabled: nothing changes, the inner handler is never called.
+    let before = slider.clone();
+    assert_eq!(handle(&mut slider, &right, true, true), Outcome::Unchanged);
+    assert_eq!(handle(&mut slider, &paste, true, true), Outcome::Unchanged);
+    assert_eq!(
+        handle(&mut slider, &right_release, true, true),
+        Outcome::Continue
+    );
+    assert_eq!(handle(&mut slider, &right, true, false), Outcome::Continue);
+    assert_eq!(handle(&mut slider, &paste, true, false), Outcome::Continue);
+    assert_eq!(
+        handle(&mut slider, &click_in, true, false),
+        Outcome::Unchanged
+    );
+    assert_eq!(
+        handle(&mut slider, &scroll_in, true, false),
+        Outcome::Unchanged
+    );
+    assert_eq!(
+        handle(&mut slider, &click_out, true, true),
+        Outcome::Continue
+    );
+    assert_eq!(
+        handle(&mut slider, &Event::FocusLost, true, true),
+        Outcome::Continue
+    );
+    assert_eq!(slider, before);
+
+    // enabled
+    assert_eq!(handle(&mut slider, &right, false, true), Outcome::Changed);
+    assert_eq!(
+        handle(&mut slider, &click_in, false, false),
+        Outcome::Changed
+    );
+    assert_eq!(handle(&mut slider, &paste, false, true), Outcome::Changed);
+    assert_eq!(
+        handle(&mut slider, &click_out, false, true),
+        Outcome::Continue
+    );
+    assert_eq!(slider.value, 5);
+    assert_eq!(slider.text, "abc");
+    assert_eq!(slider.calls, 4);
+
+    let adapter = Disabled::new((), true).focused(true).area(area);
+    assert!(adapter.is_disabled());
+    assert!(adapter.operates(&right));
+    assert!(!adapter.operates(&click_out));
+}
+
+#[test]
+fn time_out() {
+    use rat_event::crossterm::{
+        EscDebouncer, EscResolved, KeyCombo, KeySequence, ResizeDebounce, ResizeResolved, SeqResult,
+    };
+    use rat_event::{broadcast, Tick, TimeOut};
+    use std::time::{Duration, Instant};
+
+    // a blinking cursor.
+    struct Cursor {
+        visible: bool,
+        next: Instant,
+    }
+
+    impl HandleEvent<TimeOut, Tick, Outcome> for Cursor {
+        fn handle(&mut self, event: &TimeOut, _qualifier: Tick) -> Outcome {
+            if event.tag == 1 && event.now >= self.next {
+                self.visible = !self.visible;
+                self.next = event.now + Duration::from_millis(500);
+                Outcome::Changed
+            } else {
+                Outcome::Continue
+            }
+        }
+    }
+
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+
+    let mut esc = EscDebouncer::new();
+    let mut resize = ResizeDebounce::new();
+    let mut seq = KeySequence::new().timeout(ms(500));
+    seq.bind(
+        [
+            KeyCombo::new(KeyCode::Char('g'), KeyModifiers::NONE),
+            KeyCombo::new(KeyCode::Char('g'), KeyModifiers::NONE),
+        ],
+        "top",
+    );
+    seq.bind(
+        [KeyCombo::new(KeyCode::Char('g'), KeyModifiers::NONE)],
+        "go",
+    );
+    let mut cursor = Cursor {
+        visible: true,
+        next: t0,
+    };
+
+    // nothing pending.
+    let timeout = TimeOut::new(t0);
+    assert_eq!(esc.handle(&timeout, Tick), OutcomeOf::Continue);
+    assert_eq!(resize.handle(&timeout, Tick), OutcomeOf::Continue);
+    assert_eq!(seq.handle(&timeout, Tick), OutcomeOf::Continue);
+    assert_eq!(cursor.handle(&timeout, Tick), Outcome::Continue);
+
+    let esc_key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(esc.feed(&esc_key, t0), EscResolved::Pending);
+    assert_eq!(
+        resize.feed(&Event::Resize(80, 24), t0),
+        ResizeResolved::Resized(80, 24)
+    );
+    assert_eq!(
+        resize.feed(&Event::Resize(90, 30), t0),
+        ResizeResolved::Pending
+    );
+    assert_eq!(
+        seq.feed(&KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE), t0),
+        SeqResult::Pending
+    );
+
+    // too early
+    let timeout = TimeOut::new(t0 + ms(1));
+    assert_eq!(esc.handle(&timeout, Tick), OutcomeOf::Continue);
+    assert_eq!(resize.handle(&timeout, Tick), OutcomeOf::Continue);
+    assert_eq!(seq.handle(&timeout, Tick), OutcomeOf::Continue);
+
+    // the next deadline.
+    let deadline = [esc.deadline(), resize.pending_deadline(), seq.deadline()]
+        .into_iter()
+        .flatten()
+        .min();
+    assert_eq!(deadline, esc.deadline());
+
+    let timeout = TimeOut::tagged(t0 + ms(1000), 1);
+    assert_eq!(
+        esc.handle(&timeout, Tick),
+        OutcomeOf::Value(EscResolved::Escape)
+    );
+    assert_eq!(
+        resize.handle(&timeout, Tick),
+        OutcomeOf::Value(ResizeResolved::Resized(90, 30))
+    );
+    assert_eq!(
+        seq.handle(&timeout, Tick),
+        OutcomeOf::Value(SeqResult::Matched("go"))
+    );
+    assert!(!esc.is_pending());
+    assert!(!resize.is_pending());
+    assert!(!seq.is_pending());
+
+    // broadcast to all of them.
+    let mut r = Outcome::Continue;
+    broadcast!(r:
+        esc.handle(&timeout, Tick),
+        resize.handle(&timeout, Tick),
+        cursor.handle(&timeout, Tick),
+        seq.handle(&timeout, Tick)
+    );
+    assert_eq!(r, Outcome::Changed);
+    assert!(!cursor.visible);
+
+    // other tag
+    let timeout = TimeOut::tagged(t0 + ms(2000), 2);
+    assert_eq!(cursor.handle(&timeout, Tick), Outcome::Continue);
+    assert!(!cursor.visible);
+}
+
+#[test]
+fn drag_drop() {
+    use rat_event::crossterm::DragDropState;
+    use rat_event::DragDrop;
+
+    struct ListState {
+        id: usize,
+        area: Rect,
+        items: Vec<u32>,
+        drag: DragDropState<u32>,
+    }
+
+    impl HandleEvent<Event, DragDrop, Outcome> for ListState {
+        fn handle(&mut self, event: &Event, _qualifier: DragDrop) -> Outcome {
+            match event {
+                ct_event!(mouse up Left for column, row)
+                    if self.area.contains((*column, *row).into()) =>
+                {
+                    if let Some(item) = self.drag.accept(self.id) {
+                        self.items.push(item);
+                        Outcome::Changed
+                    } else {
+                        Outcome::Continue
+                    }
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    let up = |column| {
+        mouse(
+            MouseEventKind::Up(MouseButton::Left),
+            column,
+            0,
+            KeyModifiers::NONE,
+        )
+    };
+    let down = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        0,
+        0,
+        KeyModifiers::NONE,
+    );
+    let esc = key(KeyCode::Esc, KeyModifiers::NONE, KeyEventKind::Press);
+
+    let mut drag = DragDropState::new();
+    let mut target = ListState {
+        id: 1,
+        area: Rect::new(10, 0, 10, 5),
+        items: vec![],
+        drag: drag.clone(),
+    };
+
+    // clones share the state.
+    assert!(!target.drag.is_dragging());
+    drag.start(7, 0);
+    assert!(target.drag.is_dragging());
+    assert_eq!(target.drag.dragging().as_deref(), Some(&7));
+    assert_eq!(target.drag.source(), Some(0));
+
+    // drop on the target.
+    assert_eq!(target.handle(&up(12), DragDrop), Outcome::Changed);
+    assert_eq!(drag.handle(&up(12), DragDrop), Outcome::Continue);
+    assert_eq!(target.items, vec![7]);
+    assert_eq!(drag.dropped(), Some((0, 1)));
+    assert!(!drag.cancelled());
+    assert!(!drag.is_dragging());
+    assert!(drag.dragging().is_none());
+
+    // a mouse-down forgets the outcome.
+    assert_eq!(drag.handle(&down, DragDrop), Outcome::Continue);
+    assert_eq!(drag.dropped(), None);
+    assert_eq!(drag.source(), None);
+
+    // drop on nothing.
+    drag.start(8, 0);
+    assert_eq!(target.handle(&up(5), DragDrop), Outcome::Continue);
+    assert_eq!(drag.handle(&up(5), DragDrop), Outcome::Changed);
+    assert!(drag.cancelled());
+    assert_eq!(drag.dropped(), None);
+    assert_eq!(drag.source(), Some(0));
+    assert_eq!(target.items, vec![7]);
+    // nothing more to cancel.
+    assert_eq!(drag.handle(&up(5), DragDrop), Outcome::Continue);
+    assert_eq!(drag.accept(1), None);
+    assert!(drag.cancelled());
+
+    // esc cancels.
+    drag.start(9, 0);
+    assert_eq!(drag.handle(&esc, DragDrop), Outcome::Changed);
+    assert!(drag.cancelled());
+    assert_eq!(target.handle(&up(12), DragDrop), Outcome::Continue);
+    assert_eq!(target.items, vec![7]);
+
+    // focus lost cancels.
+    drag.start(9, 0);
+    assert_eq!(drag.handle(&Event::FocusLost, DragDrop), Outcome::Changed);
+    assert!(drag.cancelled());
+
+    // a mouse-down while dragging doesn't.
+    drag.start(10, 0);
+    assert_eq!(drag.handle(&down, DragDrop), Outcome::Continue);
+    assert!(drag.is_dragging());
+    assert_eq!(drag.cancel(), Some(10));
+    drag.clear();
+    assert!(!drag.cancelled());
+    assert_eq!(drag.source(), None);
+}
+
+#[test]
+fn popup_core() {
+    use rat_event::crossterm::{PopupCore, PopupOutcome};
+    use rat_event::{flow, ConsumedEvent, Popup};
+
+    // activates on the mouse-up, as buttons do.
+    #[derive(Debug, Default)]
+    struct Button {
+        area: Rect,
+        armed: bool,
+        clicks: u32,
+    }
+
+    impl HandleEvent<Event, Regular, Outcome> for Button {
+        fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+            match event {
+                ct_event!(mouse down Left for column, row)
+                    if self.area.contains((*column, *row).into()) =>
+                {
+                    self.armed = true;
+                    Outcome::Unchanged
+                }
+                ct_event!(mouse up Left for column, row)
+                    if self.area.contains((*column, *row).into()) =>
+                {
+                    if self.armed {
+                        self.clicks += 1;
+                    }
+                    self.armed = false;
+                    Outcome::Changed
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    struct App {
+        popup: PopupCore,
+        items: u32,
+        button: Button,
+    }
+
+    impl App {
+        fn handle(&mut self, event: &Event) -> Outcome {
+            flow!(self.popup.handle(event, Popup));
+            if self.popup.is_active() {
+                // the content of the popup.
+                flow!(match event {
+                    ct_event!(keycode press Down) => {
+                        self.items += 1;
+                        Outcome::Changed
+                    }
+                    _ => Outcome::Continue,
+                });
+            }
+            self.button.handle(event, Regular)
+        }
+    }
+
+    let down = |column| {
+        mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            column,
+            0,
+            KeyModifiers::NONE,
+        )
+    };
+    let up = |column| {
+        mouse(
+            MouseEventKind::Up(MouseButton::Left),
+            column,
+            0,
+            KeyModifiers::NONE,
+        )
+    };
+    let esc = key(KeyCode::Esc, KeyModifiers::NONE, KeyEventKind::Press);
+    let arrow = key(KeyCode::Down, KeyModifiers::NONE, KeyEventKind::Press);
+
+    assert!(!PopupOutcome::Continue.is_consumed());
+    assert!(!PopupOutcome::Closed.is_consumed());
+    assert!(PopupOutcome::ClosedAndConsumed.is_consumed());
+    assert_eq!(Outcome::from(PopupOutcome::Closed), Outcome::Changed);
+    assert_eq!(
+        PopupOutcome::from(Outcome::Unchanged),
+        PopupOutcome::Unchanged
+    );
+
+    let mut app = App {
+        popup: PopupCore::new(),
+        items: 0,
+        button: Button {
+            area: Rect::new(20, 0, 10, 1),
+            ..Default::default()
+        },
+    };
+    app.popup.area = Rect::new(0, 0, 10, 5);
+
+    // without the popup the button works.
+    assert_eq!(app.handle(&down(22)), Outcome::Unchanged);
+    assert_eq!(app.handle(&up(22)), Outcome::Changed);
+    assert_eq!(app.button.clicks, 1);
+
+    // the click that closes the popup doesn't click the button.
+    app.popup.set_active(true);
+    assert_eq!(app.handle(&down(22)), Outcome::Changed);
+    assert!(!app.popup.is_active());
+    assert!(!app.button.armed);
+    assert!(app.popup.swallows_release());
+    assert_eq!(app.handle(&up(22)), Outcome::Unchanged);
+    assert!(!app.popup.swallows_release());
+    assert_eq!(app.button.clicks, 1);
+    // the next click does.
+    assert_eq!(app.handle(&down(22)), Outcome::Unchanged);
+    assert_eq!(app.handle(&up(22)), Outcome::Changed);
+    assert_eq!(app.button.clicks, 2);
+
+    // a lost mouse-up doesn't swallow the next click.
+    app.popup.set_active(true);
+    assert_eq!(app.handle(&down(22)), Outcome::Changed);
+    assert_eq!(app.handle(&down(22)), Outcome::Unchanged);
+    assert_eq!(app.handle(&up(22)), Outcome::Changed);
+    assert_eq!(app.button.clicks, 3);
+
+    // clicks inside are for the content.
+    app.popup.set_active(true);
+    assert_eq!(app.popup.handle(&down(2), Popup), PopupOutcome::Continue);
+    assert!(app.popup.is_active());
+    assert!(!app.popup.swallows_release());
+
+    // content keys.
+    assert_eq!(app.handle(&arrow), Outcome::Changed);
+    assert_eq!(app.items, 1);
+
+    // esc.
+    assert_eq!(
+        app.popup.handle(&esc, Popup),
+        PopupOutcome::ClosedAndConsumed
+    );
+    assert!(!app.popup.is_active());
+    assert!(!app.popup.swallows_release());
+    assert_eq!(app.popup.handle(&esc, Popup), PopupOutcome::Continue);
+    assert_eq!(app.handle(&arrow), Outcome::Continue);
+    assert_eq!(app.items, 1);
+
+    // focus lost isn't consumed.
+    app.popup.set_active(true);
+    assert_eq!(
+        app.popup.handle(&Event::FocusLost, Popup),
+        PopupOutcome::Closed
+    );
+    assert!(!app.popup.is_active());
+
+    // swallow on request.
+    app.popup.swallow_next_release();
+    assert_eq!(app.handle(&up(22)), Outcome::Unchanged);
+    assert_eq!(app.button.clicks, 3);
+}
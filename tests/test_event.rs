@@ -0,0 +1,200 @@
+use rat_event::clock::{Clock, MockClock, SharedClock};
+use rat_event::event::{
+    Event, KeyCode, KeyEvent, KeyKind, Modifiers, MouseButton, MouseEvent, MouseKind,
+};
+use rat_event::util::{double_click_timeout, Clicks, MouseFlags, MouseFlagsN};
+use rat_event::{HandleEvent, Outcome, Regular};
+use ratatui::layout::Rect;
+use std::cell::Cell;
+use std::time::Duration;
+
+fn mouse(kind: MouseKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+#[test]
+fn test_modifiers() {
+    let m = Modifiers::SHIFT | Modifiers::CONTROL;
+    assert!(m.contains(Modifiers::SHIFT));
+    assert!(m.contains(Modifiers::CONTROL));
+    assert!(!m.contains(Modifiers::ALT));
+    assert!(m.contains(Modifiers::NONE));
+    assert!(!m.is_empty());
+    assert!(Modifiers::default().is_empty());
+
+    let mut m = Modifiers::NONE;
+    m |= Modifiers::ALT;
+    assert_eq!(m, Modifiers::ALT);
+
+    let k = KeyEvent::new(KeyCode::Char('a'), Modifiers::NONE);
+    assert!(k.is_press());
+    assert_eq!(k.kind, KeyKind::Press);
+    let k = KeyEvent::new_with_kind(KeyCode::Char('a'), Modifiers::NONE, KeyKind::Release);
+    assert!(!k.is_press());
+}
+
+#[test]
+fn test_mouse_flags() {
+    let area = Rect::new(0, 0, 10, 10);
+    let down = mouse(MouseKind::Down(MouseButton::Left), 1, 1);
+    let up = mouse(MouseKind::Up(MouseButton::Left), 1, 1);
+    let drag = mouse(MouseKind::Drag(MouseButton::Left), 20, 1);
+
+    let clock = MockClock::new();
+    let flags = MouseFlags::default();
+    let dclick = |m| flags.doubleclick2_event_at(area, m, Modifiers::NONE, clock.system_time());
+    assert!(!flags.drag_event(area, &down));
+    assert!(flags.drag_event(area, &drag));
+    assert!(!flags.drag_event(area, &up));
+    assert!(!flags.drag_event(area, &drag));
+
+    // only with the same modifiers.
+    assert!(!flags.drag2_event(area, &down, Modifiers::CONTROL));
+    assert!(!flags.drag2_event(area, &drag, Modifiers::CONTROL));
+
+    assert!(!dclick(&down));
+    assert!(!dclick(&up));
+    assert!(!dclick(&down));
+    assert!(dclick(&up));
+    // too slow.
+    assert!(!dclick(&down));
+    assert!(!dclick(&up));
+    clock.advance(Duration::from_millis(double_click_timeout() as u64 + 1));
+    assert!(!dclick(&down));
+    assert!(!dclick(&up));
+
+    assert!(flags.hover_event(area, &mouse(MouseKind::Moved, 2, 2)));
+    assert!(!flags.hover_event(area, &mouse(MouseKind::Moved, 3, 2)));
+    assert!(flags.hover_event(area, &mouse(MouseKind::Moved, 30, 2)));
+    assert_eq!(flags.pos_of_event(&up), (1, 1));
+
+    let mut flags = MouseFlagsN::default();
+    let areas = [Rect::new(0, 0, 10, 1), Rect::new(0, 1, 10, 1)];
+    assert!(!flags.drag_event(&areas, &mouse(MouseKind::Down(MouseButton::Left), 1, 1)));
+    assert!(flags.drag_event(&areas, &drag));
+    assert_eq!(flags.drag.get(), Some(1));
+    assert!(flags.hover_event(&areas, &mouse(MouseKind::Moved, 1, 0)));
+    assert_eq!(flags.hover.get(), Some(0));
+
+    assert_eq!(flags.handle(&Event::FocusLost, Regular), Outcome::Continue);
+    assert_eq!(flags.drag.get(), None);
+    assert_eq!(flags.hover.get(), None);
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_crossterm_conversion() {
+    use rat_event::crossterm::event as ct;
+
+    assert_eq!(
+        Modifiers::from(ct::KeyModifiers::SHIFT | ct::KeyModifiers::ALT),
+        Modifiers::SHIFT | Modifiers::ALT
+    );
+    assert_eq!(Modifiers::from(ct::KeyModifiers::META), Modifiers::META);
+    assert_eq!(Modifiers::from(ct::KeyModifiers::NONE), Modifiers::NONE);
+
+    let key = ct::KeyEvent::new_with_kind(
+        ct::KeyCode::Char('x'),
+        ct::KeyModifiers::CONTROL,
+        ct::KeyEventKind::Release,
+    );
+    assert_eq!(
+        Event::from(ct::Event::Key(key)),
+        Event::Key(KeyEvent::new_with_kind(
+            KeyCode::Char('x'),
+            Modifiers::CONTROL,
+            KeyKind::Release
+        ))
+    );
+    assert_eq!(KeyCode::from(ct::KeyCode::F(5)), KeyCode::F(5));
+    assert_eq!(KeyCode::from(ct::KeyCode::CapsLock), KeyCode::Other);
+
+    let m = ct::MouseEvent {
+        kind: ct::MouseEventKind::Drag(ct::MouseButton::Right),
+        column: 3,
+        row: 4,
+        modifiers: ct::KeyModifiers::SHIFT,
+    };
+    assert_eq!(
+        Event::from(&ct::Event::Mouse(m)),
+        Event::Mouse(MouseEvent {
+            kind: MouseKind::Drag(MouseButton::Right),
+            column: 3,
+            row: 4,
+            modifiers: Modifiers::SHIFT,
+        })
+    );
+
+    assert_eq!(
+        Event::from(ct::Event::Paste("abc".into())),
+        Event::Paste("abc".into())
+    );
+    assert_eq!(
+        Event::from(&ct::Event::Resize(80, 24)),
+        Event::Resize(80, 24)
+    );
+    assert_eq!(Event::from(&ct::Event::FocusLost), Event::FocusLost);
+
+    // the crossterm-typed calls still work, and share the state.
+    let area = Rect::new(0, 0, 10, 10);
+    let flags = MouseFlags::default();
+    let down = ct::MouseEvent {
+        kind: ct::MouseEventKind::Down(ct::MouseButton::Left),
+        column: 1,
+        row: 1,
+        modifiers: ct::KeyModifiers::CONTROL,
+    };
+    assert!(!flags.drag2(area, &down, ct::KeyModifiers::CONTROL));
+    let drag = MouseEvent {
+        kind: MouseKind::Drag(MouseButton::Left),
+        column: 12,
+        row: 1,
+        modifiers: Modifiers::CONTROL,
+    };
+    assert!(flags.drag2_event(area, &drag, Modifiers::CONTROL));
+}
+
+#[test]
+fn test_clock() {
+    let clock = MockClock::new();
+    let t0 = clock.now();
+    assert_eq!(clock.now(), t0);
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(clock.now(), t0 + Duration::from_millis(100));
+
+    // clones share the time.
+    let shared = clock.shared();
+    clock.advance(Duration::from_millis(5));
+    assert_eq!(shared.now(), t0 + Duration::from_millis(105));
+    assert!(!shared.is_system());
+
+    assert_eq!(shared, shared.clone());
+    assert_eq!(shared, clock.shared());
+    assert_eq!(shared, clock.clone().shared());
+    assert_eq!(shared, SharedClock::from(clock.clone()));
+    assert_ne!(shared, MockClock::new().shared());
+    assert_ne!(shared, SharedClock::system());
+    assert_eq!(SharedClock::default(), SharedClock::system());
+    assert!(SharedClock::default().is_system());
+
+    // the wall-clock moves too.
+    let w0 = shared.system_time();
+    clock.advance(Duration::from_millis(5));
+    assert_eq!(shared.system_time(), w0 + Duration::from_millis(5));
+
+    // the flags are plain state.
+    let flags = MouseFlags {
+        time: Cell::new(Some(w0)),
+        click: Cell::new(Clicks::Up1(0)),
+        drag: Cell::new(false),
+        hover: Cell::new(false),
+    };
+    assert_eq!(flags, flags.clone());
+    flags.clear();
+    assert_eq!(flags, MouseFlags::default());
+}
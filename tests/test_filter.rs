@@ -0,0 +1,54 @@
+#![cfg(feature = "crossterm")]
+
+use rat_event::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::handler::HandleEventExt;
+use rat_event::util::{keys_only, mouse_in_rect, mouse_only};
+use rat_event::{ct_event, HandleEvent, Outcome, Regular};
+use ratatui::layout::Rect;
+
+struct AnyState;
+
+impl HandleEvent<Event, Regular, Outcome> for AnyState {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            ct_event!(mouse any for _m) => Outcome::Changed,
+            Event::Key(_) => Outcome::Changed,
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+fn mouse(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn key() -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+}
+
+#[test]
+fn test_mouse_in_rect() {
+    let mut h = AnyState.filter(mouse_in_rect(Rect::new(5, 5, 10, 10)));
+    assert_eq!(h.handle(&mouse(5, 5), Regular), Outcome::Changed);
+    assert_eq!(h.handle(&mouse(4, 5), Regular), Outcome::Continue);
+    assert_eq!(h.handle(&mouse(15, 5), Regular), Outcome::Continue);
+    assert_eq!(h.handle(&key(), Regular), Outcome::Changed);
+}
+
+#[test]
+fn test_keys_mouse_only() {
+    let mut h = AnyState.filter(keys_only());
+    assert_eq!(h.handle(&mouse(5, 5), Regular), Outcome::Continue);
+    assert_eq!(h.handle(&key(), Regular), Outcome::Changed);
+
+    let mut h = AnyState.filter(mouse_only());
+    assert_eq!(h.handle(&mouse(5, 5), Regular), Outcome::Changed);
+    assert_eq!(h.handle(&key(), Regular), Outcome::Continue);
+}
@@ -0,0 +1,480 @@
+use rat_event::{
+    broadcast, dispatch, flow, handle_chain, max_flow, or_else, or_else_ok, then, try_flow, Dialog,
+    HandleEvent, MouseOnly, Outcome, OutcomeOf, Popup, Regular,
+};
+use std::cell::Cell;
+
+#[test]
+fn test_flow_label_loop() {
+    let mut seen = Vec::new();
+    let children = [Outcome::Continue, Outcome::Unchanged, Outcome::Changed];
+
+    let outcome = 'handled: {
+        for (i, child) in children.into_iter().enumerate() {
+            seen.push(i);
+            flow!('handled: child);
+        }
+        Outcome::Continue
+    };
+
+    assert_eq!(outcome, Outcome::Unchanged);
+    assert_eq!(seen, vec![0, 1]);
+}
+
+#[test]
+fn test_flow_label_block() {
+    let widget = OutcomeOf::<u32>::Continue;
+
+    let outcome: Outcome = 'handled: {
+        flow!('handled: widget);
+        flow!('handled: OutcomeOf::Value(1));
+        Outcome::Unchanged
+    };
+    assert_eq!(outcome, Outcome::Changed);
+
+    let outcome: Outcome = 'handled: {
+        flow!('handled: Outcome::Continue);
+        Outcome::Unchanged
+    };
+    assert_eq!(outcome, Outcome::Unchanged);
+}
+
+#[test]
+fn test_flow_label_closure() {
+    let handle = |v: Outcome| -> Outcome {
+        'handled: {
+            flow!('handled: v);
+            Outcome::Unchanged
+        }
+    };
+
+    let outcomes: Vec<_> = [Outcome::Continue, Outcome::Changed]
+        .into_iter()
+        .map(handle)
+        .collect();
+    assert_eq!(outcomes, vec![Outcome::Unchanged, Outcome::Changed]);
+}
+
+#[derive(Debug, PartialEq)]
+struct AppError(u32);
+
+impl From<u8> for AppError {
+    fn from(value: u8) -> Self {
+        AppError(value as u32)
+    }
+}
+
+fn try_chain(r1: Result<Outcome, u8>, r2: Result<Outcome, u8>) -> Result<Outcome, AppError> {
+    try_flow!(r1?);
+    try_flow!(r2?);
+    Ok(Outcome::Continue)
+}
+
+#[test]
+fn test_try_flow_propagate() {
+    assert_eq!(
+        try_chain(Ok(Outcome::Continue), Ok(Outcome::Continue)),
+        Ok(Outcome::Continue)
+    );
+    assert_eq!(
+        try_chain(Ok(Outcome::Unchanged), Err(2)),
+        Ok(Outcome::Unchanged)
+    );
+    assert_eq!(try_chain(Ok(Outcome::Continue), Err(2)), Err(AppError(2)));
+    assert_eq!(try_chain(Err(1), Ok(Outcome::Changed)), Err(AppError(1)));
+}
+
+#[test]
+fn test_handle_chain_lazy() {
+    let calls = Cell::new(0);
+    let eval = |v: Outcome| {
+        calls.set(calls.get() + 1);
+        v
+    };
+
+    let r = handle_chain!(
+        eval(Outcome::Continue),
+        eval(Outcome::Unchanged),
+        eval(Outcome::Changed),
+    );
+    assert_eq!(r, Outcome::Unchanged);
+    assert_eq!(calls.get(), 2);
+
+    calls.set(0);
+    let r = handle_chain!(eval(Outcome::Continue), eval(Outcome::Continue));
+    assert_eq!(r, Outcome::Continue);
+    assert_eq!(calls.get(), 2);
+
+    calls.set(0);
+    let r = handle_chain!(eval(Outcome::Changed));
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_handle_chain_into() {
+    let calls = Cell::new(0);
+    let value = |v: OutcomeOf<u32>| {
+        calls.set(calls.get() + 1);
+        v
+    };
+
+    let r = handle_chain!(
+        value(OutcomeOf::Continue),
+        Outcome::Continue,
+        value(OutcomeOf::Value(1)),
+        value(OutcomeOf::Unchanged)
+        => Outcome
+    );
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(calls.get(), 2);
+
+    let mut s = String::from("borrowed");
+    let r = handle_chain!(
+        { s.push('!'); Outcome::Continue },
+        OutcomeOf::Value(s.len())
+        => OutcomeOf<usize>
+    );
+    assert_eq!(r, OutcomeOf::Value(9));
+}
+
+fn consider(widget: OutcomeOf<u32>, focus: Outcome, scroll: Outcome) -> Outcome {
+    flow!(widget, consider focus);
+    flow!(widget, consider focus, scroll, OutcomeOf::<u32>::Unchanged,);
+    Outcome::Continue
+}
+
+fn try_consider(widget: Outcome, focus: OutcomeOf<u32>) -> Result<OutcomeOf<u32>, AppError> {
+    try_flow!(widget, consider focus, Outcome::Unchanged);
+    Ok(focus)
+}
+
+#[test]
+fn test_flow_consider() {
+    assert_eq!(
+        consider(OutcomeOf::Unchanged, Outcome::Changed, Outcome::Continue),
+        Outcome::Changed
+    );
+    assert_eq!(
+        consider(OutcomeOf::Unchanged, Outcome::Continue, Outcome::Continue),
+        Outcome::Unchanged
+    );
+    assert_eq!(
+        consider(OutcomeOf::Value(1), Outcome::Unchanged, Outcome::Continue),
+        Outcome::Changed
+    );
+    assert_eq!(
+        consider(OutcomeOf::Continue, Outcome::Changed, Outcome::Changed),
+        Outcome::Continue
+    );
+
+    assert_eq!(
+        try_consider(Outcome::Continue, OutcomeOf::Value(1)),
+        Ok(OutcomeOf::Value(1))
+    );
+    assert_eq!(
+        try_consider(Outcome::Unchanged, OutcomeOf::Continue),
+        Ok(OutcomeOf::Unchanged)
+    );
+    assert_eq!(
+        try_consider(Outcome::Unchanged, OutcomeOf::Value(1)),
+        Ok(OutcomeOf::Value(1))
+    );
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn test_flow_log() {
+    fn handle(v: Outcome) -> Outcome {
+        flow!(log widget: v);
+        Outcome::Continue
+    }
+    fn try_handle(v: Outcome) -> Result<Outcome, AppError> {
+        try_flow!(log widget: v);
+        Ok(Outcome::Continue)
+    }
+
+    assert_eq!(handle(Outcome::Changed), Outcome::Changed);
+    assert_eq!(handle(Outcome::Continue), Outcome::Continue);
+    assert_eq!(try_handle(Outcome::Unchanged), Ok(Outcome::Unchanged));
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_flow_trace() {
+    fn handle(v: Outcome) -> Outcome {
+        flow!(trace widget: v);
+        Outcome::Continue
+    }
+    fn try_handle(v: Outcome) -> Result<Outcome, AppError> {
+        try_flow!(trace widget: v);
+        Ok(Outcome::Continue)
+    }
+
+    assert_eq!(handle(Outcome::Changed), Outcome::Changed);
+    assert_eq!(handle(Outcome::Continue), Outcome::Continue);
+    assert_eq!(try_handle(Outcome::Unchanged), Ok(Outcome::Unchanged));
+}
+
+#[test]
+fn test_then() {
+    let mut r = Outcome::Continue;
+    then!(r, Outcome::Changed);
+    then!(r, Outcome::Unchanged);
+    assert_eq!(r, Outcome::Changed);
+
+    let mut r = OutcomeOf::<u32>::Unchanged;
+    then!(r, Outcome::Continue);
+    assert_eq!(r, OutcomeOf::Unchanged);
+    then!(r, OutcomeOf::Value(3));
+    assert_eq!(r, OutcomeOf::Value(3));
+}
+
+#[test]
+fn test_broadcast() {
+    let seen = Cell::new(0);
+    let child = |r: Outcome| {
+        seen.set(seen.get() + 1);
+        r
+    };
+
+    let mut r = Outcome::Continue;
+    broadcast!(r: child(Outcome::Changed), child(Outcome::Continue), child(Outcome::Unchanged));
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(seen.get(), 3);
+
+    let mut r = Outcome::Continue;
+    broadcast!(r: OutcomeOf::<u32>::Continue, Outcome::Unchanged,);
+    assert_eq!(r, Outcome::Unchanged);
+}
+
+#[test]
+fn test_or_else() {
+    let seen = Cell::new(0);
+    let child = |r: Outcome| {
+        seen.set(seen.get() + 1);
+        r
+    };
+
+    let mut r = Outcome::Continue;
+    or_else!(r, child(Outcome::Continue));
+    or_else!(r, OutcomeOf::<u32>::Unchanged);
+    or_else!(r, child(Outcome::Changed));
+    assert_eq!(r, Outcome::Unchanged);
+    assert_eq!(seen.get(), 1);
+}
+
+#[test]
+fn test_or_else_consider() {
+    let mut r = OutcomeOf::<u32>::Continue;
+    or_else!(r, Outcome::Unchanged, consider Outcome::Changed);
+    assert_eq!(r, OutcomeOf::Changed);
+
+    let mut r = Outcome::Continue;
+    or_else!(r, Outcome::Continue, consider Outcome::Unchanged, OutcomeOf::<u32>::Value(1),);
+    assert_eq!(r, Outcome::Changed);
+
+    let mut r = Outcome::Unchanged;
+    or_else!(r, Outcome::Changed, consider Outcome::Changed);
+    assert_eq!(r, Outcome::Unchanged);
+}
+
+fn or_else_chain(
+    r1: Result<Outcome, u8>,
+    r2: Result<OutcomeOf<u32>, u8>,
+) -> Result<Outcome, AppError> {
+    let mut r = Outcome::Continue;
+    or_else_ok!(r, r1);
+    or_else_ok!(r, r2);
+    Ok(r)
+}
+
+#[test]
+fn test_or_else_ok() {
+    assert_eq!(
+        or_else_chain(Ok(Outcome::Continue), Ok(OutcomeOf::Value(1))),
+        Ok(Outcome::Changed)
+    );
+    assert_eq!(
+        or_else_chain(Ok(Outcome::Unchanged), Err(2)),
+        Ok(Outcome::Unchanged)
+    );
+    assert_eq!(
+        or_else_chain(Ok(Outcome::Continue), Err(2)),
+        Err(AppError(2))
+    );
+    assert_eq!(
+        or_else_chain(Err(1), Ok(OutcomeOf::Changed)),
+        Err(AppError(1))
+    );
+}
+
+struct Phased {
+    name: &'static str,
+    consumes: &'static str,
+    log: Vec<String>,
+}
+
+impl Phased {
+    fn new(name: &'static str, consumes: &'static str) -> Self {
+        Self {
+            name,
+            consumes,
+            log: Vec::new(),
+        }
+    }
+
+    fn call(&mut self, phase: &str, event: &str) -> Outcome {
+        self.log.push(format!("{}-{}", phase, self.name));
+        if event == self.consumes {
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+impl HandleEvent<&'static str, Dialog, Outcome> for Phased {
+    fn handle(&mut self, event: &&'static str, _qualifier: Dialog) -> Outcome {
+        self.call("dialog", event)
+    }
+}
+
+impl HandleEvent<&'static str, Popup, Outcome> for Phased {
+    fn handle(&mut self, event: &&'static str, _qualifier: Popup) -> Outcome {
+        self.call("popup", event)
+    }
+}
+
+impl HandleEvent<&'static str, Regular, Outcome> for Phased {
+    fn handle(&mut self, event: &&'static str, _qualifier: Regular) -> Outcome {
+        self.call("regular", event)
+    }
+}
+
+impl HandleEvent<&'static str, MouseOnly, Outcome> for Phased {
+    fn handle(&mut self, event: &&'static str, _qualifier: MouseOnly) -> Outcome {
+        self.call("mouse", event)
+    }
+}
+
+fn dispatch_phases(event: &&'static str, a: &mut Phased, b: &mut Phased) -> Outcome {
+    dispatch!(event: dialog { a }, popup { a, b }, regular { b, a }, mouse { b } else {
+        Outcome::Unchanged
+    })
+}
+
+fn dispatch_no_else(event: &&'static str, a: &mut Phased) -> Outcome {
+    dispatch!(event: regular { a });
+    Outcome::Continue
+}
+
+#[test]
+fn test_dispatch() {
+    let mut a = Phased::new("a", "x");
+    let mut b = Phased::new("b", "y");
+    assert_eq!(dispatch_phases(&"z", &mut a, &mut b), Outcome::Unchanged);
+    assert_eq!(a.log, vec!["dialog-a", "popup-a", "regular-a"]);
+    assert_eq!(b.log, vec!["popup-b", "regular-b", "mouse-b"]);
+
+    let mut a = Phased::new("a", "x");
+    let mut b = Phased::new("b", "y");
+    assert_eq!(dispatch_phases(&"y", &mut a, &mut b), Outcome::Changed);
+    assert_eq!(a.log, vec!["dialog-a", "popup-a"]);
+    assert_eq!(b.log, vec!["popup-b"]);
+
+    let mut a = Phased::new("a", "x");
+    let mut b = Phased::new("b", "y");
+    assert_eq!(dispatch_phases(&"x", &mut a, &mut b), Outcome::Changed);
+    assert_eq!(a.log, vec!["dialog-a"]);
+    assert!(b.log.is_empty());
+
+    let mut a = Phased::new("a", "x");
+    assert_eq!(dispatch_no_else(&"x", &mut a), Outcome::Changed);
+    assert_eq!(dispatch_no_else(&"z", &mut a), Outcome::Continue);
+}
+
+#[cfg(feature = "log")]
+#[derive(Debug, PartialEq)]
+enum WidgetError {
+    Widget(&'static str),
+}
+
+#[cfg(feature = "log")]
+fn map_err_chain(
+    r1: Result<Outcome, &'static str>,
+    r2: Result<Outcome, &'static str>,
+) -> Result<Outcome, WidgetError> {
+    try_flow!(r1.map_err(WidgetError::Widget)?);
+    try_flow!(log second: r2.map_err(WidgetError::Widget)?);
+    Ok(Outcome::Continue)
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn test_try_flow_map_err() {
+    assert_eq!(
+        map_err_chain(Ok(Outcome::Continue), Ok(Outcome::Changed)),
+        Ok(Outcome::Changed)
+    );
+    assert_eq!(
+        map_err_chain(Err("first"), Ok(Outcome::Changed)),
+        Err(WidgetError::Widget("first"))
+    );
+    assert_eq!(
+        map_err_chain(Ok(Outcome::Continue), Err("second")),
+        Err(WidgetError::Widget("second"))
+    );
+}
+
+#[test]
+fn test_max_flow() {
+    let children = [
+        OutcomeOf::<u32>::Unchanged,
+        OutcomeOf::Continue,
+        OutcomeOf::Value(1),
+        OutcomeOf::Continue,
+    ];
+
+    let mut acc = Outcome::Continue;
+    let mut seen = 0;
+    for child in children {
+        seen += 1;
+        max_flow!(acc, child);
+    }
+    assert_eq!(acc, Outcome::Changed);
+    assert_eq!(seen, 4);
+}
+
+#[test]
+fn test_max_flow_break() {
+    let children = [
+        Outcome::Continue,
+        Outcome::Continue,
+        Outcome::Unchanged,
+        Outcome::Changed,
+    ];
+
+    let mut acc = OutcomeOf::<u32>::Continue;
+    let mut seen = 0;
+    for child in children {
+        seen += 1;
+        max_flow!(acc, child, if_consumed break);
+    }
+    assert_eq!(acc, OutcomeOf::Unchanged);
+    assert_eq!(seen, 3);
+
+    let mut acc = Outcome::Continue;
+    let mut seen = 0;
+    'children: for group in [
+        [Outcome::Continue, Outcome::Changed],
+        [Outcome::Changed, Outcome::Changed],
+    ] {
+        for child in group {
+            seen += 1;
+            max_flow!(acc, child, if_consumed break 'children);
+        }
+    }
+    assert_eq!(acc, Outcome::Changed);
+    assert_eq!(seen, 2);
+}
@@ -0,0 +1,671 @@
+use rat_event::handler::{
+    handle_popups, handle_rev, handler_fn, read_only, Chain, Filtered, HandleEventExt, ModalGuard,
+    Nop,
+};
+use rat_event::util::dispatch;
+use rat_event::{
+    chain, dispatch, Clipped, Dialog, DynHandleEvent, Focused, HandleEvent, HasQualifier,
+    MouseOnly, Outcome, OutcomeOf, Popup, PopupZ, ReadOnly, ReadOnlyOf, Regular,
+};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+struct WidgetState {
+    count: u32,
+}
+
+impl HandleEvent<char, Regular, Outcome> for WidgetState {
+    fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+        if *event == 'a' {
+            self.count += 1;
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+fn route<H: HandleEvent<char, Regular, Outcome>>(mut h: H, event: &char) -> Outcome {
+    h.handle(event, Regular)
+}
+
+#[test]
+fn test_mut_ref() {
+    let mut state = WidgetState::default();
+    assert_eq!(route(&mut state, &'a'), Outcome::Changed);
+    assert_eq!(route(&mut state, &'b'), Outcome::Continue);
+    assert_eq!(route(&mut &mut state, &'a'), Outcome::Changed);
+    assert_eq!(state.count, 2);
+}
+
+#[test]
+fn test_option() {
+    let mut detail: Option<WidgetState> = None;
+    assert_eq!(detail.handle(&'a', Regular), Outcome::Continue);
+
+    detail = Some(WidgetState::default());
+    assert_eq!(detail.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(detail.handle(&'b', Regular), Outcome::Continue);
+    assert_eq!(detail.map(|v| v.count), Some(1));
+}
+
+#[test]
+fn test_smart_pointers() {
+    let mut boxed: Box<dyn HandleEvent<char, Regular, Outcome>> = Box::new(WidgetState::default());
+    assert_eq!(boxed.handle(&'a', Regular), Outcome::Changed);
+
+    let mut cell = RefCell::new(WidgetState::default());
+    assert_eq!(cell.handle(&'a', Regular), Outcome::Changed);
+
+    let shared = Rc::new(RefCell::new(WidgetState::default()));
+    let mut other = shared.clone();
+    assert_eq!(other.handle(&'a', Regular), Outcome::Changed);
+    {
+        let _borrow = shared.borrow_mut();
+        assert_eq!(other.handle(&'a', Regular), Outcome::Continue);
+    }
+    assert_eq!(shared.borrow().count, 1);
+
+    let mut mutex = Mutex::new(WidgetState::default());
+    assert_eq!(mutex.handle(&'a', Regular), Outcome::Changed);
+
+    let shared = Arc::new(Mutex::new(WidgetState::default()));
+    let mut other = shared.clone();
+    assert_eq!(other.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(shared.lock().expect("lock").count, 1);
+}
+
+#[test]
+fn test_dispatch() {
+    let mut first = WidgetState::default();
+    let mut second = WidgetState::default();
+    {
+        let mut handlers: Vec<Box<dyn DynHandleEvent<char, Regular, Outcome>>> =
+            vec![Box::new(&mut first), Box::new(&mut second)];
+        assert_eq!(dispatch(&mut handlers, &'a', &Regular), Outcome::Changed);
+        assert_eq!(dispatch(&mut handlers, &'b', &Regular), Outcome::Continue);
+    }
+    assert_eq!(first.count, 1);
+    assert_eq!(second.count, 0);
+
+    let mut empty: Vec<Box<dyn DynHandleEvent<char, Regular, Outcome>>> = Vec::new();
+    assert_eq!(dispatch(&mut empty, &'a', &Regular), Outcome::Continue);
+}
+
+#[test]
+fn test_chain() {
+    let mut first = WidgetState::default();
+    let mut second = WidgetState::default();
+    {
+        let mut h = (&mut first).chain(&mut second);
+        assert_eq!(h.handle(&'a', Regular), Outcome::Changed);
+        assert_eq!(h.handle(&'b', Regular), Outcome::Continue);
+    }
+    assert_eq!(first.count, 1);
+    assert_eq!(second.count, 0);
+
+    {
+        let mut h = chain!(Nop, Nop, &mut second, &mut first);
+        assert_eq!(h.handle(&'a', Regular), Outcome::Changed);
+    }
+    assert_eq!(first.count, 1);
+    assert_eq!(second.count, 1);
+
+    let mut h = Nop;
+    let r: Outcome = h.handle(&'a', Regular);
+    assert_eq!(r, Outcome::Continue);
+}
+
+#[test]
+fn test_handler_fn() {
+    let mut count = 0;
+    {
+        let mut h = handler_fn(|event: &char, _qualifier: Regular| {
+            if *event == 'x' {
+                count += 1;
+                Outcome::Unchanged
+            } else {
+                Outcome::Continue
+            }
+        });
+        assert_eq!(h.handle(&'x', Regular), Outcome::Unchanged);
+        assert_eq!(h.handle(&'y', Regular), Outcome::Continue);
+    }
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_map_result() {
+    let mut state = WidgetState::default();
+    let mut h = (&mut state).map_result(|r: Outcome| -> OutcomeOf<u32> {
+        match r {
+            Outcome::Changed => OutcomeOf::Value(1),
+            r => r.into(),
+        }
+    });
+    assert_eq!(h.handle(&'a', Regular), OutcomeOf::Value(1));
+    assert_eq!(h.handle(&'b', Regular), OutcomeOf::Continue);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_map_result_assert() {
+    let mut state = WidgetState::default();
+    let mut h = (&mut state).map_result(|_r: Outcome| Outcome::Continue);
+    h.handle(&'a', Regular);
+}
+
+#[test]
+fn test_filter() {
+    let mut state = WidgetState::default();
+    {
+        let flag = Cell::new(false);
+        let mut h = (&mut state).filter(|_event: &char| flag.get());
+        assert_eq!(h.handle(&'a', Regular), Outcome::Continue);
+        flag.set(true);
+        assert_eq!(h.handle(&'a', Regular), Outcome::Changed);
+    }
+    assert_eq!(state.count, 1);
+}
+
+#[test]
+fn test_vec() {
+    let mut children = vec![WidgetState::default(), WidgetState::default()];
+    assert_eq!(children.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(children.handle(&'b', Regular), Outcome::Continue);
+    assert_eq!(children[0].count, 1);
+    assert_eq!(children[1].count, 0);
+
+    assert_eq!(children[..].handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(children[0].count, 2);
+
+    assert_eq!(handle_rev(&mut children, &'a', Regular), Outcome::Changed);
+    assert_eq!(children[0].count, 2);
+    assert_eq!(children[1].count, 1);
+
+    let mut empty: Vec<WidgetState> = Vec::new();
+    assert_eq!(empty.handle(&'a', Regular), Outcome::Continue);
+}
+
+#[test]
+fn test_tuple() {
+    let mut a = WidgetState::default();
+    let mut b = WidgetState::default();
+    let mut c = WidgetState::default();
+
+    assert_eq!(
+        (&mut a, &mut b, &mut c).handle(&'a', Regular),
+        Outcome::Changed
+    );
+    assert_eq!(
+        (Nop, &mut b, &mut c).handle(&'a', Regular),
+        Outcome::Changed
+    );
+    assert_eq!(
+        (Nop, Nop, Nop, Nop, Nop, &mut c).handle(&'a', Regular),
+        Outcome::Changed
+    );
+    assert_eq!((Nop, &mut a).handle(&'b', Regular), Outcome::Continue);
+
+    assert_eq!(a.count, 1);
+    assert_eq!(b.count, 1);
+    assert_eq!(c.count, 1);
+}
+
+#[test]
+fn test_handle_all() {
+    let mut state = WidgetState::default();
+    assert_eq!(
+        state.handle_all(&['x', 'y', 'a', 'b', 'c'], Regular),
+        Outcome::Changed
+    );
+    assert_eq!(
+        state.handle_all(&['a', 'a', 'a'], Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.count, 4);
+    assert_eq!(state.handle_all(&['x', 'y'], Regular), Outcome::Continue);
+    assert_eq!(state.handle_all(&[], Regular), Outcome::Continue);
+}
+
+/// Qualifier with some data, not Clone.
+#[derive(Debug)]
+struct HotKeys {
+    keys: Vec<char>,
+}
+
+#[derive(Debug, Default)]
+struct HotKeyState {
+    count: u32,
+}
+
+impl<'a> HandleEvent<char, &'a HotKeys, Outcome> for HotKeyState {
+    fn handle(&mut self, event: &char, qualifier: &'a HotKeys) -> Outcome {
+        if qualifier.keys.contains(event) {
+            self.count += 1;
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+#[test]
+fn test_ref_qualifier() {
+    let hotkeys = HotKeys {
+        keys: vec!['x', 'y'],
+    };
+
+    let mut children = vec![HotKeyState::default(), HotKeyState::default()];
+    assert_eq!(children.handle(&'x', &hotkeys), Outcome::Changed);
+    assert_eq!(children.handle(&'a', &hotkeys), Outcome::Continue);
+
+    let mut a = HotKeyState::default();
+    let mut b = HotKeyState::default();
+    assert_eq!((&mut a, &mut b).handle(&'y', &hotkeys), Outcome::Changed);
+    assert_eq!(
+        (&mut a).chain(&mut b).handle(&'y', &hotkeys),
+        Outcome::Changed
+    );
+    assert_eq!(a.count, 2);
+    assert_eq!(children[0].count, 1);
+}
+
+/// Counts the keys given with the qualifier.
+#[derive(Debug, Default)]
+struct KeyCounter {
+    count: u32,
+}
+
+/// By value, for the last handler of a chain.
+impl HandleEvent<char, HotKeys, Outcome> for KeyCounter {
+    fn handle(&mut self, event: &char, qualifier: HotKeys) -> Outcome {
+        self.dyn_handle(event, &qualifier)
+    }
+}
+
+/// The containers pass the qualifier on as a reference.
+impl DynHandleEvent<char, HotKeys, Outcome> for KeyCounter {
+    fn dyn_handle(&mut self, event: &char, qualifier: &HotKeys) -> Outcome {
+        if qualifier.keys.contains(event) {
+            self.count += 1;
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+#[test]
+fn test_owned_qualifier() {
+    let hotkeys = || HotKeys {
+        keys: vec!['x', 'y'],
+    };
+
+    let mut children = vec![KeyCounter::default(), KeyCounter::default()];
+    assert_eq!(children.handle(&'x', hotkeys()), Outcome::Changed);
+    assert_eq!(children.handle(&'a', hotkeys()), Outcome::Continue);
+    assert_eq!(handle_rev(&mut children, &'y', hotkeys()), Outcome::Changed);
+    assert_eq!(children[0].count, 1);
+    assert_eq!(children[1].count, 1);
+
+    let mut pair = (KeyCounter::default(), KeyCounter::default());
+    assert_eq!(pair.handle(&'y', hotkeys()), Outcome::Changed);
+    let mut chain = chain!(
+        KeyCounter::default(),
+        KeyCounter::default(),
+        KeyCounter::default()
+    );
+    assert_eq!(chain.handle(&'a', hotkeys()), Outcome::Continue);
+    assert_eq!(chain.handle(&'x', hotkeys()), Outcome::Changed);
+    assert_eq!(pair.0.count, 1);
+    assert_eq!(chain.first.count, 1);
+}
+
+#[test]
+fn test_zst_qualifier_by_ref() {
+    let mut state = WidgetState::default();
+    assert_eq!(state.handle(&'a', Regular), Outcome::Changed);
+    assert_eq!(state.dyn_handle(&'a', &Regular), Outcome::Changed);
+    assert_eq!(state.count, 2);
+}
+
+fn modal(event: &char, dialog: &mut ModalGuard<WidgetState>, sibling: &mut WidgetState) -> Outcome {
+    dispatch!(event: dialog { dialog }, regular { sibling } else {
+        Outcome::Continue
+    })
+}
+
+#[test]
+fn test_modal_guard() {
+    let mut dialog = ModalGuard::new(WidgetState::default());
+    let mut sibling = WidgetState::default();
+    assert!(!dialog.is_active());
+
+    assert_eq!(dialog.handle(&'a', Dialog), Outcome::Continue);
+    assert_eq!(modal(&'a', &mut dialog, &mut sibling), Outcome::Changed);
+    assert_eq!(sibling.count, 1);
+    assert_eq!(dialog.inner.count, 0);
+
+    dialog.activate();
+    assert_eq!(modal(&'a', &mut dialog, &mut sibling), Outcome::Changed);
+    assert_eq!(modal(&'x', &mut dialog, &mut sibling), Outcome::Unchanged);
+    assert_eq!(sibling.count, 1);
+    assert_eq!(dialog.inner.count, 1);
+
+    dialog.deactivate();
+    assert_eq!(modal(&'x', &mut dialog, &mut sibling), Outcome::Continue);
+    assert_eq!(modal(&'a', &mut dialog, &mut sibling), Outcome::Changed);
+    assert_eq!(sibling.count, 2);
+    assert_eq!(dialog.inner.count, 1);
+}
+
+#[derive(Debug, Default)]
+struct TextState {
+    text: String,
+    cursor: usize,
+}
+
+impl HandleEvent<char, Regular, Outcome> for TextState {
+    fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+        match event {
+            '>' => {
+                self.cursor += 1;
+                Outcome::Changed
+            }
+            c if c.is_alphabetic() => {
+                self.text.push(*c);
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+impl HandleEvent<char, MouseOnly, Outcome> for TextState {
+    fn handle(&mut self, event: &char, _qualifier: MouseOnly) -> Outcome {
+        match event {
+            '*' => {
+                self.cursor = 0;
+                Outcome::Changed
+            }
+            '#' => {
+                self.text.clear();
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+impl HandleEvent<char, ReadOnly, Outcome> for TextState {
+    fn handle(&mut self, event: &char, qualifier: ReadOnly) -> Outcome {
+        read_only(self, event, qualifier, |c| c.is_alphabetic())
+    }
+}
+
+impl HandleEvent<char, ReadOnlyOf<MouseOnly>, Outcome> for TextState {
+    fn handle(&mut self, event: &char, qualifier: ReadOnlyOf<MouseOnly>) -> Outcome {
+        read_only(self, event, qualifier, |c| *c == '#')
+    }
+}
+
+#[test]
+fn test_read_only() {
+    let mut state = TextState::default();
+
+    assert_eq!(state.handle(&'>', ReadOnly), Outcome::Changed);
+    assert_eq!(state.handle(&'x', ReadOnly), Outcome::Unchanged);
+    assert_eq!(state.handle(&'1', ReadOnly), Outcome::Continue);
+    assert_eq!(state.text, "");
+    assert_eq!(state.cursor, 1);
+
+    assert_eq!(state.handle(&'x', Regular), Outcome::Changed);
+    assert_eq!(state.text, "x");
+
+    assert_eq!(
+        state.handle(&'#', ReadOnlyOf(MouseOnly)),
+        Outcome::Unchanged
+    );
+    assert_eq!(state.handle(&'*', ReadOnlyOf(MouseOnly)), Outcome::Changed);
+    assert_eq!(state.handle(&'>', ReadOnlyOf(MouseOnly)), Outcome::Continue);
+    assert_eq!(state.text, "x");
+    assert_eq!(state.cursor, 0);
+}
+
+#[derive(Debug, Default)]
+struct Layer {
+    keys: &'static str,
+    seen: Vec<u16>,
+}
+
+impl HandleEvent<char, PopupZ, Outcome> for Layer {
+    fn handle(&mut self, event: &char, qualifier: PopupZ) -> Outcome {
+        self.seen.push(qualifier.0);
+        if self.keys.contains(*event) {
+            Outcome::Changed
+        } else if *event == 'u' {
+            Outcome::Unchanged
+        } else {
+            Outcome::Continue
+        }
+    }
+}
+
+#[test]
+fn test_handle_popups() {
+    let mut tooltip = Layer {
+        keys: "t",
+        ..Default::default()
+    };
+    let mut dropdown = Layer {
+        keys: "dx",
+        ..Default::default()
+    };
+    let mut menu = Layer {
+        keys: "mx",
+        ..Default::default()
+    };
+    let mut other = Layer {
+        keys: "ox",
+        ..Default::default()
+    };
+
+    let mut popups: [(u16, &mut dyn HandleEvent<char, PopupZ, Outcome>); 4] = [
+        (3, &mut tooltip),
+        (1, &mut dropdown),
+        (2, &mut menu),
+        (1, &mut other),
+    ];
+
+    assert_eq!(
+        handle_popups(&mut popups, &'t'),
+        (Outcome::Changed, Some(3))
+    );
+    assert_eq!(
+        handle_popups(&mut popups, &'x'),
+        (Outcome::Changed, Some(2))
+    );
+    // same z, the later one first.
+    assert_eq!(
+        handle_popups(&mut popups, &'d'),
+        (Outcome::Changed, Some(1))
+    );
+    assert_eq!(
+        handle_popups(&mut popups, &'u'),
+        (Outcome::Unchanged, Some(3))
+    );
+    assert_eq!(handle_popups(&mut popups, &'?'), (Outcome::Continue, None));
+
+    assert_eq!(tooltip.seen, vec![3, 3, 3, 3, 3]);
+    assert_eq!(menu.seen, vec![2, 2, 2]);
+    assert_eq!(other.seen, vec![1, 1]);
+    assert_eq!(dropdown.seen, vec![1, 1]);
+
+    let mut none: [(u16, &mut dyn HandleEvent<char, PopupZ, Outcome>); 0] = [];
+    assert_eq!(handle_popups(&mut none, &'t'), (Outcome::Continue, None));
+
+    assert_eq!(PopupZ::from(Popup), PopupZ(1));
+    assert_eq!(PopupZ::default(), PopupZ(1));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Theme(u8);
+
+#[derive(Debug, Default)]
+struct Probe {
+    seen: Vec<(bool, bool, Option<u8>)>,
+}
+
+impl<Q> HandleEvent<char, Q, Outcome> for Probe
+where
+    Q: HasQualifier<Clipped> + HasQualifier<ReadOnly> + HasQualifier<Theme>,
+{
+    fn handle(&mut self, _event: &char, qualifier: Q) -> Outcome {
+        let clipped: Option<&Clipped> = qualifier.get();
+        let read_only: Option<&ReadOnly> = qualifier.get();
+        let theme: Option<&Theme> = qualifier.get();
+        self.seen
+            .push((clipped.is_some(), read_only.is_some(), theme.map(|v| v.0)));
+        Outcome::Continue
+    }
+}
+
+#[test]
+fn test_has_qualifier() {
+    let clip = Clipped(ratatui::layout::Rect::new(1, 2, 3, 4));
+
+    let q: Option<&Clipped> = clip.get();
+    assert_eq!(q.map(|v| v.0), Some(clip.0));
+    let q: Option<&Regular> = clip.get();
+    assert!(q.is_none());
+
+    let q: Option<&MouseOnly> = ReadOnlyOf(MouseOnly).get();
+    assert!(q.is_none());
+    let q: Option<&ReadOnlyOf<MouseOnly>> = ReadOnlyOf(MouseOnly).get();
+    assert!(q.is_some());
+
+    let q: Option<&Regular> = (Regular,).get();
+    assert!(q.is_some());
+    let q: Option<&Theme> = (Regular, Theme(1)).get();
+    assert_eq!(q, Some(&Theme(1)));
+    // the first one wins.
+    let q: Option<&Theme> = (Theme(1), ReadOnly, Theme(2)).get();
+    assert_eq!(q, Some(&Theme(1)));
+    let q: Option<&Popup> = (Theme(1), ReadOnly, Theme(2)).get();
+    assert!(q.is_none());
+
+    // a tuple doesn't look into its elements, a Focused outside does.
+    assert!(HasQualifier::<ReadOnly>::get(&Focused(true, ReadOnly)).is_some());
+    assert!(HasQualifier::<ReadOnly>::get(&(Focused(true, ReadOnly), clip)).is_none());
+    assert!(HasQualifier::<Focused<ReadOnly>>::get(&(Focused(true, ReadOnly), clip)).is_some());
+    assert!(HasQualifier::<ReadOnly>::get(&((ReadOnly,), clip)).is_none());
+    assert!(HasQualifier::<ReadOnly>::get(&Focused(true, (ReadOnly, clip))).is_some());
+    assert!(HasQualifier::<Clipped>::get(&Focused(true, (ReadOnly, clip))).is_some());
+
+    let mut probe = Probe::default();
+    probe.handle(&'a', Regular);
+    probe.handle(&'a', (clip, ReadOnly));
+    probe.handle(&'a', (Theme(7), clip, Regular));
+    assert_eq!(
+        probe.seen,
+        vec![
+            (false, false, None),
+            (true, true, None),
+            (true, false, Some(7))
+        ]
+    );
+}
+
+#[test]
+fn test_focused() {
+    #[derive(Debug, Default)]
+    struct Counter {
+        keys: u32,
+        mouse: u32,
+    }
+
+    // 'k' is a key, 'm' is the mouse.
+    impl<Q> HandleEvent<char, Focused<Q>, Outcome> for Counter {
+        fn handle(&mut self, event: &char, qualifier: Focused<Q>) -> Outcome {
+            match event {
+                'k' if qualifier.is_focused() => {
+                    self.keys += 1;
+                    Outcome::Changed
+                }
+                'm' => {
+                    self.mouse += 1;
+                    Outcome::Changed
+                }
+                _ => Outcome::Continue,
+            }
+        }
+    }
+
+    let mut counter = Counter::default();
+    assert_eq!(
+        counter.handle(&'k', Focused(false, Regular)),
+        Outcome::Continue
+    );
+    assert_eq!(
+        counter.handle(&'m', Focused(false, Regular)),
+        Outcome::Changed
+    );
+    assert_eq!(
+        counter.handle(&'k', Focused(true, Regular)),
+        Outcome::Changed
+    );
+    assert_eq!(
+        counter.handle(&'m', Focused(true, Regular)),
+        Outcome::Changed
+    );
+    assert_eq!(counter.keys, 1);
+    assert_eq!(counter.mouse, 2);
+
+    let q = Focused(true, ReadOnly);
+    assert!(q.is_focused());
+    let _: &ReadOnly = q.qualifier();
+
+    // extraction looks into the wrapped qualifier.
+    let clip = Clipped(ratatui::layout::Rect::new(1, 2, 3, 4));
+    let q = Focused(true, (clip, ReadOnly));
+    let found: Option<&Clipped> = q.get();
+    assert_eq!(found.map(|v| v.0), Some(clip.0));
+    let found: Option<&ReadOnly> = q.get();
+    assert!(found.is_some());
+    let found: Option<&Focused<(Clipped, ReadOnly)>> = q.get();
+    assert_eq!(found.map(|v| v.0), Some(true));
+    let found: Option<&Regular> = q.get();
+    assert!(found.is_none());
+    let found: Option<&Theme> = Focused(false, (Theme(1),)).get();
+    assert_eq!(found, Some(&Theme(1)));
+
+    // a widget that doesn't care ignores it.
+    let mut probe = Probe::default();
+    probe.handle(&'a', Focused(true, Regular));
+    probe.handle(&'a', Focused(false, (clip, ReadOnly, Theme(3))));
+    assert_eq!(
+        probe.seen,
+        vec![(false, false, None), (true, true, Some(3))]
+    );
+}
+
+#[test]
+fn test_tuple_qualifier_pass_through() {
+    let mut probe = Probe::default();
+    let q = (Clipped::default(), Theme(3));
+
+    let r: Outcome = Chain::new(&mut probe, Nop).handle(&'a', q);
+    assert_eq!(r, Outcome::Continue);
+    let r: Outcome = Filtered::new(&mut probe, |_: &char| true).handle(&'a', q);
+    assert_eq!(r, Outcome::Continue);
+    let r: Outcome = handle_rev(&mut [&mut probe], &'a', q);
+    assert_eq!(r, Outcome::Continue);
+    let r: Outcome = Box::new(&mut probe).handle(&'a', q);
+    assert_eq!(r, Outcome::Continue);
+
+    assert_eq!(probe.seen, vec![(true, false, Some(3)); 4]);
+}
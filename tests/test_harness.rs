@@ -0,0 +1,403 @@
+#![cfg(feature = "testing")]
+
+use rat_event::testing::Harness;
+use rat_event::{HandleEvent, Outcome, OutcomeOf, Regular};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[derive(Debug, Default)]
+struct Counter(u32);
+
+impl HandleEvent<char, Regular, Outcome> for Counter {
+    fn handle(&mut self, event: &char, _qualifier: Regular) -> Outcome {
+        match event {
+            '+' => {
+                self.0 += 1;
+                Outcome::Changed
+            }
+            ' ' => Outcome::Unchanged,
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Step(u32);
+
+impl HandleEvent<char, Step, OutcomeOf<u32>> for Counter {
+    fn handle(&mut self, event: &char, qualifier: Step) -> OutcomeOf<u32> {
+        match event {
+            '+' => {
+                self.0 += qualifier.0;
+                OutcomeOf::Value(self.0)
+            }
+            _ => OutcomeOf::Continue,
+        }
+    }
+}
+
+#[test]
+fn test_harness() {
+    let mut h = Harness::new(Counter::default());
+    assert!(h.last().is_none());
+
+    assert_eq!(h.feed(&'+'), Outcome::Changed);
+    h.assert_consumed();
+    assert_eq!(h.feed(&'x'), Outcome::Continue);
+    h.assert_not_consumed();
+    assert_eq!(h.outcomes(), &[Outcome::Changed, Outcome::Continue]);
+
+    h.clear();
+    h.feed_all(&['+', ' ', 'x']).assert_outcomes(&[
+        Outcome::Changed,
+        Outcome::Unchanged,
+        Outcome::Continue,
+    ]);
+    // starts fresh after a check.
+    assert!(h.outcomes().is_empty());
+    h.feed(&' ');
+    h.assert_outcomes(&[Outcome::Unchanged]);
+
+    assert_eq!(h.into_state().0, 2);
+}
+
+#[test]
+fn test_harness_qualifier() {
+    let mut h = Harness::with_qualifier(Counter::default(), Step(5));
+    h.feed_all(&['+', '-', '+']);
+    h.assert_outcomes(&[
+        OutcomeOf::Value(5),
+        OutcomeOf::Continue,
+        OutcomeOf::Value(10),
+    ]);
+    h.qualifier = Step(1);
+    assert_eq!(h.feed(&'+'), OutcomeOf::Value(11));
+}
+
+#[test]
+fn test_harness_failures() {
+    let fails = |f: &mut dyn FnMut(&mut Harness<Counter>)| {
+        let mut h = Harness::new(Counter::default());
+        catch_unwind(AssertUnwindSafe(|| f(&mut h))).is_err()
+    };
+
+    assert!(fails(&mut |h| h.assert_consumed()));
+    assert!(fails(&mut |h| h.assert_not_consumed()));
+    assert!(fails(&mut |h| {
+        h.feed(&'x');
+        h.assert_consumed();
+    }));
+    assert!(fails(&mut |h| {
+        h.feed(&'+');
+        h.assert_not_consumed();
+    }));
+    assert!(fails(&mut |h| {
+        h.feed(&'+');
+        h.assert_outcomes(&[Outcome::Changed, Outcome::Changed]);
+    }));
+    assert!(!fails(&mut |h| {
+        h.feed(&'+');
+        h.assert_outcomes(&[Outcome::Changed]);
+    }));
+}
+
+#[cfg(feature = "crossterm")]
+mod mouse_flags {
+    use rat_event::clock::{Clock, SharedClock};
+    use rat_event::crossterm::event::{Event, KeyModifiers};
+    use rat_event::testing::crossterm::{
+        click, click_ctrl, double_click_seq, drag_seq, key, mouse_down, mouse_drag, mouse_moved,
+        mouse_up, scroll_down_at, scroll_up_at,
+    };
+    use rat_event::testing::Harness;
+    use rat_event::util::{double_click_timeout, MouseFlags, MouseFlagsN};
+    use rat_event::{ct_event, HandleEvent, Outcome, Regular};
+    use ratatui::layout::Rect;
+    use std::time::Duration;
+
+    /// Reports drag and double-click as Changed.
+    #[derive(Debug, Default)]
+    struct Area {
+        area: Rect,
+        mouse: MouseFlags,
+        clock: SharedClock,
+        dragged: u32,
+        clicked: u32,
+    }
+
+    impl HandleEvent<Event, Regular, Outcome> for Area {
+        fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+            match event {
+                ct_event!(mouse any for m) if self.mouse.drag(self.area, m) => {
+                    self.dragged += 1;
+                    Outcome::Changed
+                }
+                ct_event!(mouse any for m)
+                    if self.mouse.doubleclick2_at(
+                        self.area,
+                        m,
+                        KeyModifiers::NONE,
+                        self.clock.system_time(),
+                    ) =>
+                {
+                    self.clicked += 1;
+                    Outcome::Changed
+                }
+                ct_event!(mouse any for m) if self.area.contains((m.column, m.row).into()) => {
+                    Outcome::Unchanged
+                }
+                _ => self.mouse.handle(event, Regular),
+            }
+        }
+    }
+
+    fn area() -> Harness<Area> {
+        let mut h = Harness::new(Area {
+            area: Rect::new(0, 0, 10, 10),
+            ..Default::default()
+        });
+        h.state.clock = h.clock.shared();
+        h
+    }
+
+    /// Drag and double-click over a list of rows.
+    #[derive(Debug, Default)]
+    struct Rows {
+        rows: Vec<Rect>,
+        mouse: MouseFlagsN,
+        clock: SharedClock,
+        dragged: Vec<usize>,
+        clicked: Vec<usize>,
+    }
+
+    impl HandleEvent<Event, Regular, Outcome> for Rows {
+        fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+            match event {
+                ct_event!(mouse any for m) if self.mouse.drag(&self.rows, m) => {
+                    self.dragged.extend(self.mouse.drag.get());
+                    Outcome::Changed
+                }
+                ct_event!(mouse any for m)
+                    if self.mouse.doubleclick2_at(
+                        &self.rows,
+                        m,
+                        KeyModifiers::NONE,
+                        self.clock.system_time(),
+                    ) =>
+                {
+                    self.clicked
+                        .extend(self.mouse.item_at(&self.rows, m.column, m.row));
+                    Outcome::Changed
+                }
+                _ => self.mouse.handle(event, Regular),
+            }
+        }
+    }
+
+    fn rows() -> Harness<Rows> {
+        let mut h = Harness::new(Rows {
+            rows: (0..3).map(|v| Rect::new(0, v, 10, 1)).collect(),
+            ..Default::default()
+        });
+        h.state.clock = h.clock.shared();
+        h
+    }
+
+    #[test]
+    fn test_mouse_flags_focus_lost() {
+        let mut h = Harness::new(MouseFlags::default());
+        h.state.drag.set(true);
+        h.state.hover.set(true);
+        h.feed(&Event::FocusGained);
+        assert!(h.state.drag.get());
+        h.feed(&Event::FocusLost);
+        h.assert_outcomes(&[Outcome::Continue, Outcome::Continue]);
+        assert_eq!(h.state, MouseFlags::default());
+
+        let mut h = Harness::new(MouseFlagsN::default());
+        h.state.drag.set(Some(1));
+        h.feed(&Event::FocusLost);
+        h.assert_not_consumed();
+        assert_eq!(h.state, MouseFlagsN::default());
+    }
+
+    #[test]
+    fn test_mouse_flags_drag() {
+        let mut h = area();
+
+        // outside the area, still dragging.
+        h.feed_all(&drag_seq((1, 1), (20, 1), 2));
+        h.assert_outcomes(&[
+            Outcome::Unchanged,
+            Outcome::Changed,
+            Outcome::Changed,
+            Outcome::Continue,
+        ]);
+        assert_eq!(h.state.dragged, 2);
+        h.feed(&mouse_drag(20, 1));
+        h.assert_not_consumed();
+
+        // the focus loss ends the drag.
+        h.clear();
+        h.feed_all(&[mouse_down(1, 1), Event::FocusLost, mouse_drag(2, 1)]);
+        h.assert_outcomes(&[Outcome::Unchanged, Outcome::Continue, Outcome::Unchanged]);
+        assert_eq!(h.state.dragged, 2);
+
+        // starts outside.
+        h.feed_all(&drag_seq((20, 1), (1, 1), 3));
+        assert_eq!(h.state.dragged, 2);
+    }
+
+    #[test]
+    fn test_mouse_flags_double_click() {
+        let mut h = area();
+
+        h.feed_all(&double_click_seq(1, 1));
+        h.assert_outcomes(&[
+            Outcome::Unchanged,
+            Outcome::Unchanged,
+            Outcome::Unchanged,
+            Outcome::Changed,
+        ]);
+        assert_eq!(h.state.clicked, 1);
+
+        // the second click outside.
+        h.feed_all(&click(1, 1));
+        h.feed_all(&click(11, 1));
+        h.assert_not_consumed();
+        assert_eq!(h.state.clicked, 1);
+
+        // with modifiers it's something else.
+        h.feed_all(&click_ctrl(1, 1));
+        h.feed_all(&click_ctrl(1, 1));
+        assert_eq!(h.state.clicked, 1);
+
+        // keys and scrolling don't interfere.
+        h.clear();
+        h.feed_all(&[
+            mouse_down(3, 3),
+            mouse_up(3, 3),
+            key('x'),
+            scroll_up_at(3, 3),
+        ]);
+        h.feed_all(&click(3, 3));
+        h.assert_consumed();
+        assert_eq!(h.state.clicked, 2);
+    }
+
+    #[test]
+    fn test_mouse_flags_double_click_timeout() {
+        let timeout = Duration::from_millis(double_click_timeout() as u64);
+        let mut h = area();
+
+        // just in time.
+        h.feed_all(&click(1, 1));
+        h.advance(timeout);
+        h.feed_all(&click(1, 1));
+        h.assert_consumed();
+        assert_eq!(h.state.clicked, 1);
+
+        // too slow.
+        h.clear();
+        h.feed_all(&click(1, 1));
+        h.advance(timeout + Duration::from_millis(1));
+        h.feed_all(&click(1, 1));
+        h.assert_outcomes(&[Outcome::Unchanged; 4]);
+        assert_eq!(h.state.clicked, 1);
+
+        // the slow click starts a new double-click.
+        h.feed_all(&click(1, 1));
+        h.assert_consumed();
+        assert_eq!(h.state.clicked, 2);
+
+        let mut h = rows();
+        h.feed_all(&click(1, 1));
+        h.advance(timeout * 2);
+        h.feed_all(&click(1, 1));
+        assert!(h.state.clicked.is_empty());
+    }
+
+    #[test]
+    fn test_mouse_flags_hover() {
+        let flags = MouseFlags::default();
+        let area = Rect::new(0, 0, 10, 10);
+        let moved = |x, y| match mouse_moved(x, y) {
+            Event::Mouse(m) => m,
+            _ => unreachable!(),
+        };
+
+        assert!(flags.hover(area, &moved(1, 1)));
+        assert!(!flags.hover(area, &moved(2, 1)));
+        assert!(flags.hover(area, &moved(20, 1)));
+        assert!(!flags.hover(area, &moved(21, 1)));
+        assert!(flags.hover(area, &moved(9, 9)));
+    }
+
+    #[test]
+    fn test_mouse_flags_n() {
+        let mut h = rows();
+
+        h.feed_all(&drag_seq((1, 0), (1, 2), 2));
+        h.assert_outcomes(&[
+            Outcome::Continue,
+            Outcome::Changed,
+            Outcome::Changed,
+            Outcome::Continue,
+        ]);
+        // the drag stays with the row where it started.
+        assert_eq!(h.state.dragged, vec![0, 0]);
+
+        h.feed_all(&double_click_seq(1, 1));
+        h.feed_all(&double_click_seq(1, 2));
+        assert_eq!(h.state.clicked, vec![1, 2]);
+
+        // the second click on another row.
+        h.feed_all(&click(1, 0));
+        h.feed_all(&click(1, 1));
+        assert_eq!(h.state.clicked, vec![1, 2]);
+
+        h.feed(&scroll_down_at(1, 1));
+        h.assert_not_consumed();
+        h.feed(&Event::FocusLost);
+        assert_eq!(h.state.mouse.drag.get(), None);
+    }
+
+    #[test]
+    fn test_builders() {
+        use rat_event::crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+        use rat_event::testing::crossterm::{key_alt, key_code, key_ctrl, type_text};
+
+        let seq = drag_seq((0, 0), (10, 4), 4);
+        assert_eq!(seq.len(), 6);
+        let pos = seq
+            .iter()
+            .map(|v| match v {
+                Event::Mouse(m) => (m.kind, m.column, m.row),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        let drag = MouseEventKind::Drag(MouseButton::Left);
+        assert_eq!(
+            pos,
+            vec![
+                (MouseEventKind::Down(MouseButton::Left), 0, 0),
+                (drag, 2, 1),
+                (drag, 5, 2),
+                (drag, 7, 3),
+                (drag, 10, 4),
+                (MouseEventKind::Up(MouseButton::Left), 10, 4),
+            ]
+        );
+        // backwards, and at least one drag.
+        assert_eq!(drag_seq((5, 5), (1, 5), 0)[1], mouse_drag(1, 5));
+
+        assert!(matches!(key('A'), ct_event!(key press 'A')));
+        assert!(matches!(key_ctrl('s'), ct_event!(key press CONTROL-'s')));
+        assert!(matches!(key_alt('f'), ct_event!(key press ALT-'f')));
+        assert!(matches!(
+            key_code(KeyCode::Enter, KeyModifiers::NONE),
+            ct_event!(keycode press Enter)
+        ));
+        assert_eq!(type_text("ab"), vec![key('a'), key('b')]);
+        assert!(matches!(scroll_up_at(1, 2), ct_event!(scroll up for x, y) if x == 1 && y == 2));
+    }
+}
@@ -0,0 +1,617 @@
+use rat_event::util::{assert_outcome_compatible, assert_outcome_order, max_outcome};
+use rat_event::{
+    flow, impl_outcome, try_flow, Consumed, ConsumedEvent, CursorOutcome, Direction, Fallible,
+    Ignored, IntoOutcome, MaxInto, MaybeConsumed, Navigation, Outcome, OutcomeOf, PollOutcome,
+};
+use ratatui::layout::Position;
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+#[test]
+fn test_predicates() {
+    assert!(Outcome::Continue.is_continue());
+    assert!(!Outcome::Continue.is_consumed());
+    assert!(!Outcome::Continue.needs_render());
+
+    assert!(Outcome::Unchanged.is_unchanged());
+    assert!(Outcome::Unchanged.is_consumed());
+    assert!(!Outcome::Unchanged.needs_render());
+
+    assert!(Outcome::Changed.is_changed());
+    assert!(Outcome::Changed.is_consumed());
+    assert!(Outcome::Changed.needs_render());
+}
+
+#[test]
+fn test_bool() {
+    assert!(!bool::from(Outcome::Continue));
+    assert!(!bool::from(Outcome::Unchanged));
+    assert!(bool::from(Outcome::Changed));
+
+    assert_eq!(Outcome::from(true), Outcome::Changed);
+    assert_eq!(Outcome::from(false), Outcome::Unchanged);
+
+    assert_eq!(Outcome::from(None), Outcome::Continue);
+    assert_eq!(Outcome::from(Some(true)), Outcome::Changed);
+    assert_eq!(Outcome::from(Some(false)), Outcome::Unchanged);
+
+    assert_eq!(Outcome::changed_if(false, true), Outcome::Continue);
+    assert_eq!(Outcome::changed_if(false, false), Outcome::Continue);
+    assert_eq!(Outcome::changed_if(true, false), Outcome::Unchanged);
+    assert_eq!(Outcome::changed_if(true, true), Outcome::Changed);
+}
+
+#[test]
+fn test_outcome_of() {
+    assert!(!OutcomeOf::<u32>::Continue.is_consumed());
+    assert!(OutcomeOf::<u32>::Unchanged.is_consumed());
+    assert!(OutcomeOf::Value(1).is_consumed());
+
+    assert!(OutcomeOf::Value(1) > OutcomeOf::Changed);
+    assert!(OutcomeOf::<u32>::Changed > OutcomeOf::Unchanged);
+    assert_eq!(
+        OutcomeOf::Value(1).and(|| OutcomeOf::Value(2)),
+        OutcomeOf::Value(2)
+    );
+    // Ord agrees with Eq.
+    assert_eq!(
+        OutcomeOf::Value(1).cmp(&OutcomeOf::Value(2)),
+        Ordering::Less
+    );
+    assert_eq!(
+        OutcomeOf::Value(1).cmp(&OutcomeOf::Value(1)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        OutcomeOf::Value(2).and(|| OutcomeOf::Value(1)),
+        OutcomeOf::Value(2)
+    );
+    assert_eq!(
+        OutcomeOf::Value(1).and(|| OutcomeOf::Changed),
+        OutcomeOf::Value(1)
+    );
+
+    assert_eq!(Outcome::from(OutcomeOf::Value(1)), Outcome::Changed);
+    assert_eq!(Outcome::from(OutcomeOf::<u32>::Continue), Outcome::Continue);
+    assert_eq!(
+        OutcomeOf::<u32>::from(Outcome::Unchanged),
+        OutcomeOf::Unchanged
+    );
+}
+
+fn outcome_of_to_outcome(v: OutcomeOf<u32>) -> Outcome {
+    flow!(v);
+    Outcome::Continue
+}
+
+fn outcome_to_outcome_of(v: Outcome) -> OutcomeOf<u32> {
+    flow!(v);
+    flow!(OutcomeOf::Value(42));
+    OutcomeOf::Continue
+}
+
+fn try_outcome_of(v: OutcomeOf<u32>) -> Result<Outcome, ()> {
+    try_flow!(v);
+    Ok(Outcome::Continue)
+}
+
+#[test]
+fn test_outcome_of_flow() {
+    assert_eq!(outcome_of_to_outcome(OutcomeOf::Value(1)), Outcome::Changed);
+    assert_eq!(
+        outcome_of_to_outcome(OutcomeOf::Continue),
+        Outcome::Continue
+    );
+    assert_eq!(outcome_to_outcome_of(Outcome::Changed), OutcomeOf::Changed);
+    assert_eq!(
+        outcome_to_outcome_of(Outcome::Continue),
+        OutcomeOf::Value(42)
+    );
+    assert_eq!(try_outcome_of(OutcomeOf::Value(1)), Ok(Outcome::Changed));
+    assert_eq!(try_outcome_of(OutcomeOf::Continue), Ok(Outcome::Continue));
+}
+
+#[test]
+fn test_bitor() {
+    assert_eq!(Outcome::Continue | Outcome::Unchanged, Outcome::Unchanged);
+    assert_eq!(Outcome::Changed | Outcome::Unchanged, Outcome::Changed);
+
+    let mut r = Outcome::Continue;
+    r |= Outcome::Continue;
+    assert_eq!(r, Outcome::Continue);
+    r |= Outcome::Changed;
+    r |= Outcome::Unchanged;
+    assert_eq!(r, Outcome::Changed);
+
+    let mut r = OutcomeOf::Value(1);
+    r |= OutcomeOf::Changed;
+    assert_eq!(r, OutcomeOf::Value(1));
+    r |= OutcomeOf::Value(2);
+    assert_eq!(r, OutcomeOf::Value(2));
+    r |= OutcomeOf::Value(1);
+    assert_eq!(r, OutcomeOf::Value(2));
+
+    assert_eq!(max_outcome([]), Outcome::Continue);
+    assert_eq!(
+        max_outcome([Outcome::Unchanged, Outcome::Continue]),
+        Outcome::Unchanged
+    );
+    assert_eq!(
+        max_outcome(vec![Outcome::Changed, Outcome::Unchanged]),
+        Outcome::Changed
+    );
+}
+
+#[test]
+fn test_control_flow() {
+    assert!(ControlFlow::<(), Outcome>::Break(()).is_consumed());
+    assert!(ControlFlow::<(), Outcome>::Continue(Outcome::Changed).is_consumed());
+    assert!(!ControlFlow::<(), Outcome>::Continue(Outcome::Continue).is_consumed());
+
+    assert_eq!(
+        ControlFlow::from(Outcome::Unchanged),
+        ControlFlow::Continue(Outcome::Unchanged)
+    );
+    assert_eq!(
+        Outcome::Changed.continue_or_break(|v| v == Outcome::Changed),
+        ControlFlow::Break(())
+    );
+    assert_eq!(
+        Outcome::Unchanged.continue_or_break(|v| v == Outcome::Changed),
+        ControlFlow::Continue(Outcome::Unchanged)
+    );
+}
+
+#[test]
+fn test_fallible() {
+    assert!(!Fallible::<Outcome, ()>(Err(())).is_consumed());
+    assert!(!Fallible::<Outcome, ()>(Ok(Outcome::Continue)).is_consumed());
+    assert!(Fallible::<Outcome, ()>(Ok(Outcome::Changed)).is_consumed());
+    assert!(Result::<Outcome, ()>::Err(()).is_consumed());
+}
+
+fn fallible_flow(r1: Result<Outcome, u32>, r2: Result<Outcome, u32>) -> Result<Outcome, u32> {
+    let mut err = None;
+    try_flow!(fallible err: r1);
+    try_flow!(fallible err: Fallible(r2));
+    if let Some(err) = err {
+        return Err(err);
+    }
+    Ok(Outcome::Continue)
+}
+
+#[test]
+fn test_fallible_flow() {
+    assert_eq!(
+        fallible_flow(Ok(Outcome::Continue), Ok(Outcome::Changed)),
+        Ok(Outcome::Changed)
+    );
+    assert_eq!(
+        fallible_flow(Ok(Outcome::Unchanged), Err(2)),
+        Ok(Outcome::Unchanged)
+    );
+    assert_eq!(fallible_flow(Err(1), Ok(Outcome::Continue)), Err(1));
+    assert_eq!(fallible_flow(Err(1), Err(2)), Err(1));
+    assert_eq!(fallible_flow(Err(1), Ok(Outcome::Changed)), Err(1));
+    assert_eq!(
+        fallible_flow(Ok(Outcome::Continue), Ok(Outcome::Continue)),
+        Ok(Outcome::Continue)
+    );
+}
+
+fn cursor_chain(r1: CursorOutcome, r2: Outcome, r3: CursorOutcome) -> CursorOutcome {
+    flow!(r1);
+    flow!(r2);
+    flow!(r3);
+    CursorOutcome::default()
+}
+
+#[test]
+fn test_cursor_outcome() {
+    let p1 = Some(Position::new(1, 1));
+    let p2 = Some(Position::new(2, 2));
+
+    assert_eq!(
+        cursor_chain(
+            CursorOutcome::new(Outcome::Changed, p1),
+            Outcome::Changed,
+            CursorOutcome::new(Outcome::Changed, p2),
+        ),
+        CursorOutcome::new(Outcome::Changed, p1)
+    );
+    assert_eq!(
+        cursor_chain(
+            CursorOutcome::new(Outcome::Continue, p1),
+            Outcome::Unchanged,
+            CursorOutcome::new(Outcome::Changed, p2),
+        ),
+        CursorOutcome::new(Outcome::Unchanged, None)
+    );
+    assert_eq!(
+        cursor_chain(
+            CursorOutcome::new(Outcome::Continue, p1),
+            Outcome::Continue,
+            CursorOutcome::new(Outcome::Changed, p2),
+        ),
+        CursorOutcome::new(Outcome::Changed, p2)
+    );
+    assert_eq!(
+        Outcome::from(CursorOutcome::new(Outcome::Changed, p2)),
+        Outcome::Changed
+    );
+
+    let mut r = CursorOutcome::new(Outcome::Changed, p1);
+    r |= CursorOutcome::new(Outcome::Unchanged, p2);
+    assert_eq!(r, CursorOutcome::new(Outcome::Changed, p2));
+    r |= CursorOutcome::new(Outcome::Continue, p1);
+    assert_eq!(r, CursorOutcome::new(Outcome::Changed, p2));
+    r |= CursorOutcome::new(Outcome::Unchanged, None);
+    assert_eq!(r, CursorOutcome::new(Outcome::Changed, p2));
+
+    assert_eq!(
+        CursorOutcome::new(Outcome::Unchanged, p1).and(|| Outcome::Changed.into()),
+        CursorOutcome::new(Outcome::Changed, None)
+    );
+
+    // Ord agrees with Eq.
+    let c = |o, p| CursorOutcome::new(o, p);
+    assert_eq!(
+        c(Outcome::Changed, p1).cmp(&c(Outcome::Changed, p1)),
+        Ordering::Equal
+    );
+    assert_ne!(
+        c(Outcome::Changed, p1).cmp(&c(Outcome::Changed, p2)),
+        Ordering::Equal
+    );
+    assert!(c(Outcome::Changed, None) < c(Outcome::Changed, p1));
+    assert!(c(Outcome::Unchanged, p1) < c(Outcome::Changed, None));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BadOutcome {
+    Continue,
+    Changed,
+    Unchanged,
+}
+
+impl ConsumedEvent for BadOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != BadOutcome::Continue
+    }
+}
+
+impl From<Outcome> for BadOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => BadOutcome::Continue,
+            Outcome::Unchanged => BadOutcome::Unchanged,
+            Outcome::Changed => BadOutcome::Changed,
+        }
+    }
+}
+
+impl From<BadOutcome> for Outcome {
+    fn from(value: BadOutcome) -> Self {
+        match value {
+            BadOutcome::Continue => Outcome::Continue,
+            BadOutcome::Unchanged => Outcome::Unchanged,
+            BadOutcome::Changed => Outcome::Changed,
+        }
+    }
+}
+
+#[test]
+fn test_compatible() {
+    assert_outcome_compatible::<Outcome>();
+    assert_outcome_compatible::<OutcomeOf<u32>>();
+    assert_outcome_compatible::<CursorOutcome>();
+
+    assert_eq!(BadOutcome::Changed.into_outcome(), Outcome::Changed);
+}
+
+#[test]
+#[should_panic]
+fn test_not_compatible() {
+    assert_outcome_compatible::<BadOutcome>();
+}
+
+#[test]
+fn test_order() {
+    assert_outcome_order(&[Outcome::Continue, Outcome::Unchanged, Outcome::Changed]);
+    assert_outcome_order(&[
+        OutcomeOf::Continue,
+        OutcomeOf::Unchanged,
+        OutcomeOf::Changed,
+        OutcomeOf::Value(1),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn test_bad_order() {
+    assert_outcome_order(&[
+        BadOutcome::Continue,
+        BadOutcome::Unchanged,
+        BadOutcome::Changed,
+    ]);
+}
+
+fn consumed_flow(
+    a: Ignored<&'static str>,
+    b: MaybeConsumed<&'static str>,
+) -> MaybeConsumed<&'static str> {
+    flow!(a);
+    flow!(b);
+    Consumed("end").into()
+}
+
+#[test]
+fn test_consumed() {
+    assert!(Consumed(1).is_consumed());
+    assert!(!Ignored(1).is_consumed());
+    assert!(MaybeConsumed::new(1, true).is_consumed());
+    assert!(!MaybeConsumed::new(1, false).is_consumed());
+    assert_eq!(*Consumed(1), 1);
+    assert_eq!(Ignored(1).into_inner(), 1);
+
+    assert_eq!(
+        consumed_flow(Ignored("a"), MaybeConsumed::new("b", true)),
+        MaybeConsumed::new("b", true)
+    );
+    assert_eq!(
+        consumed_flow(Ignored("a"), MaybeConsumed::new("b", false)),
+        MaybeConsumed::new("end", true)
+    );
+    assert_eq!(
+        Ignored(1).or_else(|| Ignored(2)).or_else(|| Ignored(3)),
+        Ignored(3)
+    );
+}
+
+fn pair_flow(widget: OutcomeOf<u32>, focus: Outcome) -> Outcome {
+    flow!((widget, focus));
+    Outcome::Continue
+}
+
+#[test]
+fn test_pair() {
+    assert!(!(Outcome::Continue, Outcome::Continue).is_consumed());
+    assert!((Outcome::Unchanged, Outcome::Continue).is_consumed());
+    assert!((Outcome::Continue, Outcome::Changed).is_consumed());
+
+    let r: Outcome = (Outcome::Unchanged, Outcome::Changed).max_into();
+    assert_eq!(r, Outcome::Changed);
+    let r: OutcomeOf<u32> = (Outcome::Changed, OutcomeOf::Value(1)).max_into();
+    assert_eq!(r, OutcomeOf::Value(1));
+
+    assert_eq!(
+        pair_flow(OutcomeOf::Continue, Outcome::Continue),
+        Outcome::Continue
+    );
+    assert_eq!(
+        pair_flow(OutcomeOf::Continue, Outcome::Unchanged),
+        Outcome::Unchanged
+    );
+    assert_eq!(
+        pair_flow(OutcomeOf::Value(1), Outcome::Unchanged),
+        Outcome::Changed
+    );
+}
+
+fn count_consumed<T: ConsumedEvent>(results: &[T]) -> usize {
+    results.iter().filter(|v| v.is_consumed()).count()
+}
+
+#[test]
+fn test_ref() {
+    let mut r0 = Outcome::Changed;
+    let r1 = Outcome::Continue;
+    assert_eq!(count_consumed(&[&r0, &r1]), 1);
+    assert_eq!(count_consumed(&[&mut r0]), 1);
+
+    let dynr: &dyn ConsumedEvent = &Outcome::Changed;
+    assert!(dynr.is_consumed());
+
+    assert_eq!(
+        count_consumed(&[Outcome::Continue, Outcome::Changed, Outcome::Unchanged]),
+        2
+    );
+}
+
+fn poll_chain(r1: PollOutcome, r2: PollOutcome, r3: Outcome) -> PollOutcome {
+    let mut r = PollOutcome::default();
+    r |= r1;
+    flow!(r);
+    r |= r2;
+    flow!(r);
+    r |= r3.into();
+    r
+}
+
+#[test]
+fn test_poll_outcome() {
+    let d1 = Some(Duration::from_millis(100));
+    let d2 = Some(Duration::from_millis(500));
+
+    // Ord agrees with Eq, and max() keeps the smaller duration.
+    let p = |o, d| PollOutcome::new(o, d);
+    assert_eq!(
+        p(Outcome::Changed, d1).cmp(&p(Outcome::Changed, d1)),
+        Ordering::Equal
+    );
+    assert!(p(Outcome::Changed, d2) < p(Outcome::Changed, d1));
+    assert!(p(Outcome::Changed, None) < p(Outcome::Changed, d2));
+    assert!(p(Outcome::Unchanged, d1) < p(Outcome::Changed, None));
+    assert_eq!(
+        p(Outcome::Changed, d1).and(|| p(Outcome::Changed, d2)),
+        p(Outcome::Changed, d1)
+    );
+
+    assert_eq!(
+        PollOutcome::new(Outcome::Changed, d2) | PollOutcome::new(Outcome::Unchanged, d1),
+        PollOutcome::new(Outcome::Changed, d1)
+    );
+    assert_eq!(
+        PollOutcome::new(Outcome::Continue, None) | PollOutcome::new(Outcome::Continue, d2),
+        PollOutcome::new(Outcome::Continue, d2)
+    );
+
+    // hint from a not consumed handler survives
+    assert_eq!(
+        poll_chain(
+            PollOutcome::new(Outcome::Continue, d2),
+            PollOutcome::new(Outcome::Changed, None),
+            Outcome::Changed
+        ),
+        PollOutcome::new(Outcome::Changed, d2)
+    );
+    assert_eq!(
+        poll_chain(
+            PollOutcome::new(Outcome::Continue, d2),
+            PollOutcome::new(Outcome::Continue, d1),
+            Outcome::Continue
+        ),
+        PollOutcome::new(Outcome::Continue, d1)
+    );
+    assert_eq!(
+        Outcome::from(PollOutcome::new(Outcome::Changed, d1)),
+        Outcome::Changed
+    );
+    assert_outcome_compatible::<PollOutcome>();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ListOutcome {
+    NotUsed,
+    Unchanged,
+    Changed,
+    Selected(usize),
+    Scrolled { delta: i16 },
+    Ignored(String),
+}
+
+impl_outcome!(ListOutcome {
+    NotUsed => Continue,
+    Unchanged => Unchanged,
+    Changed => Changed,
+    Selected(_) => Changed,
+    Scrolled { .. } => Unchanged,
+    Ignored(_) => Continue,
+});
+
+fn list_flow(r: ListOutcome) -> Outcome {
+    flow!(r);
+    Outcome::Continue
+}
+
+#[test]
+fn test_impl_outcome() {
+    assert!(!ListOutcome::NotUsed.is_consumed());
+    assert!(!ListOutcome::Ignored("x".into()).is_consumed());
+    assert!(ListOutcome::Unchanged.is_consumed());
+    assert!(ListOutcome::Selected(1).is_consumed());
+    assert!(ListOutcome::Scrolled { delta: 1 }.is_consumed());
+
+    assert_eq!(ListOutcome::from(Outcome::Continue), ListOutcome::NotUsed);
+    assert_eq!(
+        ListOutcome::from(Outcome::Unchanged),
+        ListOutcome::Unchanged
+    );
+    assert_eq!(ListOutcome::from(Outcome::Changed), ListOutcome::Changed);
+
+    assert_eq!(Outcome::from(ListOutcome::Selected(1)), Outcome::Changed);
+    assert_eq!(
+        Outcome::from(ListOutcome::Scrolled { delta: -1 }),
+        Outcome::Unchanged
+    );
+    assert_eq!(list_flow(ListOutcome::Selected(2)), Outcome::Changed);
+    assert_eq!(
+        list_flow(ListOutcome::Ignored("y".into())),
+        Outcome::Continue
+    );
+}
+
+fn field_flow(r: Navigation, other: Outcome) -> Navigation {
+    flow!(r);
+    flow!(other);
+    Navigation::Continue
+}
+
+#[test]
+fn test_navigation() {
+    assert!(!Navigation::Continue.is_consumed());
+    assert!(Navigation::Unchanged.is_consumed());
+    assert!(Navigation::FocusNext.is_consumed());
+    assert!(Navigation::Leave(Direction::Left).is_consumed());
+
+    assert!(!Navigation::Changed.is_navigation());
+    assert!(Navigation::FocusPrev.is_navigation());
+    assert!(Navigation::Leave(Direction::Up).is_navigation());
+
+    // navigation outranks everything else.
+    assert!(Navigation::Continue < Navigation::Unchanged);
+    assert!(Navigation::Unchanged < Navigation::Changed);
+    assert!(Navigation::Changed < Navigation::FocusNext);
+    assert!(Navigation::Changed < Navigation::FocusPrev);
+    assert!(Navigation::Changed < Navigation::Leave(Direction::Right));
+    // Ord agrees with Eq.
+    assert!(Navigation::FocusNext < Navigation::FocusPrev);
+    assert!(Navigation::FocusPrev < Navigation::Leave(Direction::Up));
+    assert!(Navigation::Leave(Direction::Up) < Navigation::Leave(Direction::Right));
+    assert_eq!(
+        Navigation::Leave(Direction::Down).cmp(&Navigation::Leave(Direction::Down)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        Navigation::FocusNext | Navigation::Changed,
+        Navigation::FocusNext
+    );
+    assert_eq!(
+        Navigation::Changed | Navigation::FocusPrev,
+        Navigation::FocusPrev
+    );
+    // independent of the order.
+    assert_eq!(
+        Navigation::FocusNext | Navigation::Leave(Direction::Down),
+        Navigation::Leave(Direction::Down)
+    );
+    assert_eq!(
+        Navigation::Leave(Direction::Down) | Navigation::FocusNext,
+        Navigation::Leave(Direction::Down)
+    );
+    let mut r = Navigation::Leave(Direction::Up);
+    r |= Navigation::Changed;
+    assert_eq!(r, Navigation::Leave(Direction::Up));
+    r |= Navigation::FocusNext;
+    assert_eq!(r, Navigation::Leave(Direction::Up));
+
+    assert_outcome_compatible::<Navigation>();
+    assert_outcome_order(&[
+        Navigation::Continue,
+        Navigation::Unchanged,
+        Navigation::Changed,
+        Navigation::FocusNext,
+        Navigation::FocusPrev,
+        Navigation::Leave(Direction::Left),
+    ]);
+    assert_eq!(Outcome::from(Navigation::FocusNext), Outcome::Changed);
+    assert_eq!(
+        Outcome::from(Navigation::Leave(Direction::Left)),
+        Outcome::Changed
+    );
+    assert_eq!(Navigation::from(Outcome::Continue), Navigation::Continue);
+
+    assert_eq!(
+        field_flow(Navigation::FocusNext, Outcome::Changed),
+        Navigation::FocusNext
+    );
+    assert_eq!(
+        field_flow(Navigation::Continue, Outcome::Unchanged),
+        Navigation::Unchanged
+    );
+    assert_eq!(
+        field_flow(Navigation::Continue, Outcome::Continue),
+        Navigation::Continue
+    );
+}
@@ -0,0 +1,168 @@
+#![cfg(all(feature = "recorder", feature = "crossterm"))]
+
+use rat_event::clock::MockClock;
+use rat_event::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode,
+    ModifierKeyCode, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::crossterm::{EventRecorder, EventReplay, RecordedEvent};
+use rat_event::source::{EventSource, SourceError};
+use std::io::Cursor;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+fn events() -> Vec<Event> {
+    let mouse = |kind, column, row, modifiers| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers,
+        })
+    };
+    vec![
+        Event::FocusGained,
+        Event::Resize(80, 24),
+        Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)),
+        Event::Key(KeyEvent::new_with_kind(
+            KeyCode::Char('"'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+            KeyEventKind::Release,
+        )),
+        Event::Key(KeyEvent::new(KeyCode::F(12), KeyModifiers::SHIFT)),
+        Event::Key(KeyEvent {
+            code: KeyCode::Media(MediaKeyCode::PlayPause),
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Repeat,
+            state: KeyEventState::KEYPAD | KeyEventState::NUM_LOCK,
+        }),
+        Event::Key(KeyEvent::new(
+            KeyCode::Modifier(ModifierKeyCode::IsoLevel3Shift),
+            KeyModifiers::NONE,
+        )),
+        mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            3,
+            4,
+            KeyModifiers::NONE,
+        ),
+        mouse(
+            MouseEventKind::Drag(MouseButton::Left),
+            5,
+            4,
+            KeyModifiers::SHIFT,
+        ),
+        mouse(
+            MouseEventKind::Up(MouseButton::Right),
+            5,
+            4,
+            KeyModifiers::NONE,
+        ),
+        mouse(MouseEventKind::ScrollUp, 0, 0, KeyModifiers::CONTROL),
+        Event::Paste("multi\nline \"paste\" ✓".into()),
+        Event::FocusLost,
+    ]
+}
+
+#[test]
+fn test_record_replay() -> Result<(), SourceError> {
+    let (tx, rx) = mpsc::channel();
+    for event in events() {
+        tx.send(event).expect("send");
+    }
+    drop(tx);
+
+    let mut recorder = EventRecorder::new(rx, Vec::new());
+    let mut recorded = Vec::new();
+    loop {
+        match recorder.poll(Some(Duration::ZERO)) {
+            Ok(Some(event)) => recorded.push(event),
+            Ok(None) => {}
+            Err(SourceError::Disconnected) => break,
+            Err(e) => return Err(e),
+        }
+    }
+    assert_eq!(recorded, events());
+
+    let (_, file) = recorder.into_inner();
+    let text = String::from_utf8(file).expect("utf8");
+    assert_eq!(text.lines().count(), events().len());
+
+    let mut replay = EventReplay::new(Cursor::new(text));
+    assert!(!replay.is_real_time());
+    let mut replayed = Vec::new();
+    loop {
+        match replay.poll(None) {
+            Ok(Some(event)) => replayed.push(event),
+            Ok(None) => {}
+            Err(SourceError::Disconnected) => break,
+            Err(e) => return Err(e),
+        }
+    }
+    assert_eq!(replayed, events());
+    Ok(())
+}
+
+#[test]
+fn test_recorded_event() {
+    for event in events() {
+        let recorded = RecordedEvent::from(&event);
+        assert_eq!(Event::from(recorded), event);
+    }
+}
+
+#[test]
+fn test_replay_real_time() -> Result<(), SourceError> {
+    let text = r#"{"t":0,"e":"FocusGained"}
+
+{"t":30000,"e":{"Resize":[10,20]}}
+"#;
+    let mut replay = EventReplay::new(Cursor::new(text)).real_time(true);
+    let start = Instant::now();
+    assert_eq!(replay.poll(Some(Duration::ZERO))?, Some(Event::FocusGained));
+    // not yet.
+    assert_eq!(replay.poll(Some(Duration::ZERO))?, None);
+    assert_eq!(replay.poll(None)?, Some(Event::Resize(10, 20)));
+    assert!(start.elapsed() >= Duration::from_millis(30));
+    assert!(matches!(replay.poll(None), Err(SourceError::Disconnected)));
+
+    // garbage
+    let mut replay = EventReplay::new(Cursor::new("{\"t\":0}\n"));
+    assert!(matches!(replay.poll(None), Err(SourceError::Io(_))));
+    Ok(())
+}
+
+#[test]
+fn test_mock_clock() -> Result<(), SourceError> {
+    let clock = MockClock::new();
+
+    let (tx, rx) = mpsc::channel();
+    let mut recorder = EventRecorder::new(rx, Vec::new()).with_clock(clock.shared());
+    tx.send(Event::FocusGained).expect("send");
+    assert_eq!(recorder.poll(None)?, Some(Event::FocusGained));
+    clock.advance(Duration::from_millis(1500));
+    tx.send(Event::Resize(10, 20)).expect("send");
+    assert_eq!(recorder.poll(None)?, Some(Event::Resize(10, 20)));
+
+    let (_, file) = recorder.into_inner();
+    let text = String::from_utf8(file).expect("utf8");
+    assert_eq!(
+        text,
+        "{\"t\":0,\"e\":\"FocusGained\"}\n{\"t\":1500000,\"e\":{\"Resize\":[10,20]}}\n"
+    );
+
+    // no sleeping, only the clock moves.
+    let start = Instant::now();
+    let mut replay = EventReplay::new(Cursor::new(text))
+        .real_time(true)
+        .with_clock(clock.shared());
+    assert_eq!(replay.poll(None)?, Some(Event::FocusGained));
+    assert_eq!(replay.poll(None)?, None);
+    clock.advance(Duration::from_millis(1000));
+    assert_eq!(replay.poll(Some(Duration::from_secs(10)))?, None);
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(replay.poll(None)?, Some(Event::Resize(10, 20)));
+    assert!(matches!(replay.poll(None), Err(SourceError::Disconnected)));
+    assert!(start.elapsed() < Duration::from_secs(1));
+    Ok(())
+}
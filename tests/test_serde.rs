@@ -0,0 +1,113 @@
+#![cfg(all(feature = "serde", feature = "crossterm"))]
+
+use rat_event::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use rat_event::crossterm::{KeyCombo, KeyMap, KeyMapError, KeyParseError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Action {
+    Save,
+    SaveAs,
+    Quit,
+    Help,
+}
+
+#[test]
+fn key_combo() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        key: KeyCombo,
+    }
+
+    let config = Config {
+        key: KeyCombo::new(KeyCode::F(5), KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+    };
+    let text = toml::to_string(&config).expect("toml");
+    assert_eq!(text.trim(), r#"key = "Ctrl+Shift+F5""#);
+    assert_eq!(toml::from_str::<Config>(&text).expect("toml"), config);
+
+    let config: Config = toml::from_str(r#"key = "C-S""#).expect("toml");
+    assert_eq!(
+        config.key,
+        KeyCombo::new(KeyCode::Char('S'), KeyModifiers::CONTROL)
+    );
+
+    let err = toml::from_str::<Config>(r#"key = "cntrl+s""#).unwrap_err();
+    assert!(err.to_string().contains("cntrl+s"), "{}", err);
+}
+
+#[test]
+fn key_map_roundtrip() {
+    let keymap: KeyMap<Action> = toml::from_str(
+        r#"
+        "ctrl+s" = "save"
+        "ctrl+shift+s" = "save-as"
+        "C-q" = "quit"
+        "f1" = "help"
+        "#,
+    )
+    .expect("toml");
+    assert_eq!(keymap.len(), 4);
+
+    let text = toml::to_string(&keymap).expect("toml");
+    let reload: KeyMap<Action> = toml::from_str(&text).expect("toml");
+
+    let keys = [
+        KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+        KeyEvent::new(KeyCode::Char('S'), KeyModifiers::CONTROL),
+        KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ),
+        KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
+        KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE),
+        KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE),
+    ];
+    for key in &keys {
+        assert_eq!(reload.lookup(key), keymap.lookup(key), "{:?}", key);
+    }
+    assert_eq!(keymap.lookup(&keys[1]), Some(&Action::SaveAs));
+    assert_eq!(keymap.lookup(&keys[3]), Some(&Action::Quit));
+    assert_eq!(keymap.lookup(&keys[5]), None);
+
+    // stable output
+    assert_eq!(toml::to_string(&reload).expect("toml"), text);
+}
+
+#[test]
+fn key_map_errors() {
+    let err = toml::from_str::<KeyMap<Action>>(r#""ctrl+sv" = "save""#).unwrap_err();
+    assert!(err.to_string().contains("'ctrl+sv'"), "{}", err);
+
+    let err = toml::from_str::<KeyMap<Action>>(
+        r#"
+        "ctrl+S" = "save"
+        "ctrl+shift+s" = "save-as"
+        "#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("bound twice"), "{}", err);
+
+    // unknown action
+    assert!(toml::from_str::<KeyMap<Action>>(r#""ctrl+s" = "sav""#).is_err());
+
+    // from_pairs
+    let table: BTreeMap<String, Action> = toml::from_str(r#""ctrl+s" = "save""#).expect("toml");
+    let keymap = KeyMap::from_pairs(table).expect("keymap");
+    assert_eq!(keymap.len(), 1);
+
+    assert_eq!(
+        KeyMap::from_pairs([("ctrl+".to_string(), Action::Save)]).unwrap_err(),
+        KeyMapError::Parse("ctrl+".into(), KeyParseError::MissingKey("ctrl+".into()))
+    );
+    let err = KeyMap::from_pairs([
+        ("f1".to_string(), Action::Help),
+        ("F1".to_string(), Action::Quit),
+    ])
+    .unwrap_err();
+    assert_eq!(err, KeyMapError::Duplicate("F1".into()));
+    assert_eq!(err.binding(), "F1");
+    assert_eq!(err.to_string(), "key binding 'F1': the key is bound twice");
+}
@@ -0,0 +1,198 @@
+use rat_event::source::{merge, EventSource, Merged, Priority, SourceError};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Ticks at most every interval.
+struct Ticks {
+    interval: Duration,
+    last: Instant,
+    count: u32,
+}
+
+impl EventSource<u32> for Ticks {
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<Option<u32>, SourceError> {
+        let next = self.last + self.interval;
+        let now = Instant::now();
+        if next > now {
+            let wait = next - now;
+            match timeout {
+                Some(timeout) if timeout < wait => {
+                    thread::sleep(timeout);
+                    return Ok(None);
+                }
+                _ => thread::sleep(wait),
+            }
+        }
+        self.last = next;
+        self.count += 1;
+        Ok(Some(self.count))
+    }
+}
+
+fn drain<A, B>(
+    source: &mut impl EventSource<Merged<A, B>>,
+) -> Result<Vec<Merged<A, B>>, SourceError> {
+    let mut r = Vec::new();
+    while let Some(v) = source.poll(Some(Duration::ZERO))? {
+        r.push(v);
+    }
+    Ok(r)
+}
+
+#[test]
+fn test_receiver() -> Result<(), SourceError> {
+    let (tx, mut rx) = mpsc::channel();
+    tx.send(1).expect("send");
+    assert_eq!(rx.poll(Some(Duration::ZERO))?, Some(1));
+    assert_eq!(rx.poll(Some(Duration::ZERO))?, None);
+    tx.send(2).expect("send");
+    assert_eq!(rx.poll(None)?, Some(2));
+
+    drop(tx);
+    assert!(matches!(
+        rx.poll(Some(Duration::ZERO)),
+        Err(SourceError::Disconnected)
+    ));
+    assert!(matches!(rx.poll(None), Err(SourceError::Disconnected)));
+    Ok(())
+}
+
+#[test]
+fn test_merge_priority() -> Result<(), SourceError> {
+    use Merged::{First, Second};
+
+    let table = [
+        (
+            Priority::First,
+            vec![First(1), First(2), First(3), Second('a'), Second('b')],
+        ),
+        (
+            Priority::Second,
+            vec![Second('a'), Second('b'), First(1), First(2), First(3)],
+        ),
+        (
+            Priority::Alternate,
+            vec![First(1), Second('a'), First(2), Second('b'), First(3)],
+        ),
+    ];
+
+    for (priority, expect) in table {
+        let (tx_a, rx_a) = mpsc::channel();
+        let (tx_b, rx_b) = mpsc::channel();
+        for v in [1, 2, 3] {
+            tx_a.send(v).expect("send");
+        }
+        for v in ['a', 'b'] {
+            tx_b.send(v).expect("send");
+        }
+
+        let mut source = merge(rx_a, rx_b).priority(priority);
+        assert_eq!(drain(&mut source)?, expect, "{:?}", priority);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merge_fairness() -> Result<(), SourceError> {
+    // alternate continues with the other source when one is empty,
+    // and takes turns again when both have something.
+    let (tx_a, rx_a) = mpsc::channel();
+    let (tx_b, rx_b) = mpsc::channel();
+    let mut source = merge(rx_a, rx_b).priority(Priority::Alternate);
+
+    tx_b.send('a').expect("send");
+    tx_b.send('b').expect("send");
+    assert_eq!(
+        drain(&mut source)?,
+        vec![Merged::Second('a'), Merged::Second('b')]
+    );
+
+    for v in [1, 2] {
+        tx_a.send(v).expect("send");
+    }
+    for v in ['c', 'd'] {
+        tx_b.send(v).expect("send");
+    }
+    assert_eq!(
+        drain(&mut source)?,
+        vec![
+            Merged::First(1),
+            Merged::Second('c'),
+            Merged::First(2),
+            Merged::Second('d')
+        ]
+    );
+
+    // with priority, a busy first source starves the second.
+    let (tx_a, rx_a) = mpsc::channel();
+    let (tx_b, rx_b) = mpsc::channel();
+    let mut source = merge(rx_a, rx_b);
+    tx_b.send('x').expect("send");
+    for v in 0..100 {
+        tx_a.send(v).expect("send");
+    }
+    let events = drain(&mut source)?;
+    assert_eq!(events.len(), 101);
+    assert_eq!(events[100], Merged::Second('x'));
+    Ok(())
+}
+
+#[test]
+fn test_merge_timeout() -> Result<(), SourceError> {
+    let (_tx_a, rx_a) = mpsc::channel::<u32>();
+    let (_tx_b, rx_b) = mpsc::channel::<char>();
+    let mut source = merge(rx_a, rx_b).slice(Duration::from_millis(5));
+
+    let now = Instant::now();
+    assert_eq!(source.poll(Some(Duration::ZERO))?, None);
+    assert_eq!(source.poll(Some(Duration::from_millis(30)))?, None);
+    assert!(now.elapsed() >= Duration::from_millis(30));
+
+    // an event from the second source while waiting.
+    let (tx_a, rx_a) = mpsc::channel::<u32>();
+    let (tx_b, rx_b) = mpsc::channel::<char>();
+    let mut source = merge(rx_a, rx_b).slice(Duration::from_millis(5));
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        tx_b.send('z').expect("send");
+    });
+    assert_eq!(source.poll(None)?, Some(Merged::Second('z')));
+    t.join().expect("join");
+
+    // errors are passed on.
+    drop(tx_a);
+    assert!(matches!(
+        source.poll(Some(Duration::ZERO)),
+        Err(SourceError::Disconnected)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_merge_timer() -> Result<(), SourceError> {
+    let (tx, rx) = mpsc::channel::<&str>();
+    let ticks = Ticks {
+        interval: Duration::from_millis(20),
+        last: Instant::now(),
+        count: 0,
+    };
+    let mut source = merge(rx, ticks);
+
+    tx.send("event").expect("send");
+    assert_eq!(source.poll(None)?, Some(Merged::First("event")));
+    assert_eq!(source.poll(Some(Duration::ZERO))?, None);
+    assert_eq!(source.poll(None)?, Some(Merged::Second(1)));
+
+    // merged sources can be merged again.
+    let (tx2, rx2) = mpsc::channel::<bool>();
+    let mut source = merge(rx2, &mut source);
+    tx2.send(true).expect("send");
+    tx.send("other").expect("send");
+    assert_eq!(source.poll(None)?, Some(Merged::First(true)));
+    assert_eq!(
+        source.poll(None)?,
+        Some(Merged::Second(Merged::First("other")))
+    );
+    Ok(())
+}
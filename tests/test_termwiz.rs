@@ -0,0 +1,241 @@
+#![cfg(feature = "termwiz")]
+
+use rat_event::event::{Event, KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseKind};
+use rat_event::termwiz::input::{self, InputEvent, MouseButtons};
+use rat_event::termwiz::InputConverter;
+use rat_event::util::MouseFlags;
+use rat_event::{HandleEvent, Outcome, Regular};
+use ratatui::layout::Rect;
+
+fn key(key: input::KeyCode, modifiers: input::Modifiers) -> InputEvent {
+    InputEvent::Key(input::KeyEvent { key, modifiers })
+}
+
+// 1-based, as termwiz reports them from the terminal.
+fn mouse(x: u16, y: u16, mouse_buttons: MouseButtons) -> InputEvent {
+    InputEvent::Mouse(input::MouseEvent {
+        x: if cfg!(windows) { x - 1 } else { x },
+        y: if cfg!(windows) { y - 1 } else { y },
+        mouse_buttons,
+        modifiers: input::Modifiers::NONE,
+    })
+}
+
+fn kind(c: &mut InputConverter, event: InputEvent) -> MouseKind {
+    match c.convert(&event) {
+        Some(Event::Mouse(m)) => m.kind,
+        e => panic!("not a mouse event {:?}", e),
+    }
+}
+
+#[test]
+fn test_keys() {
+    let mut c = InputConverter::new();
+    let none = input::Modifiers::NONE;
+
+    assert_eq!(
+        c.convert(&key(input::KeyCode::Char('a'), input::Modifiers::CTRL)),
+        Some(Event::Key(KeyEvent::new(
+            KeyCode::Char('a'),
+            Modifiers::CONTROL
+        )))
+    );
+    assert_eq!(
+        c.convert(&key(input::KeyCode::Tab, input::Modifiers::SHIFT)),
+        Some(Event::Key(KeyEvent::new(
+            KeyCode::BackTab,
+            Modifiers::SHIFT
+        )))
+    );
+    assert_eq!(
+        KeyCode::from(input::KeyCode::ApplicationUpArrow),
+        KeyCode::Up
+    );
+    assert_eq!(KeyCode::from(input::KeyCode::Function(7)), KeyCode::F(7));
+    assert_eq!(KeyCode::from(input::KeyCode::Numpad4), KeyCode::Char('4'));
+    assert_eq!(KeyCode::from(input::KeyCode::Escape), KeyCode::Esc);
+    assert_eq!(KeyCode::from(input::KeyCode::CapsLock), KeyCode::Other);
+
+    assert_eq!(
+        Modifiers::from(input::Modifiers::LEFT_ALT | input::Modifiers::SUPER),
+        Modifiers::ALT | Modifiers::SUPER
+    );
+    assert_eq!(Modifiers::from(none), Modifiers::NONE);
+
+    let k = KeyEvent::from(input::KeyEvent {
+        key: input::KeyCode::Enter,
+        modifiers: none,
+    });
+    assert!(k.is_press());
+}
+
+#[test]
+fn test_other_events() {
+    let mut c = InputConverter::new();
+
+    assert_eq!(
+        c.convert(&InputEvent::Resized { cols: 80, rows: 24 }),
+        Some(Event::Resize(80, 24))
+    );
+    assert_eq!(
+        c.convert(&InputEvent::Resized {
+            cols: 100_000,
+            rows: 24
+        }),
+        Some(Event::Resize(u16::MAX, 24))
+    );
+    assert_eq!(
+        c.convert(&InputEvent::Paste("abc".into())),
+        Some(Event::Paste("abc".into()))
+    );
+    assert_eq!(c.convert(&InputEvent::Wake), None);
+}
+
+#[test]
+fn test_mouse() {
+    let mut c = InputConverter::new();
+
+    assert_eq!(
+        c.convert(&mouse(3, 4, MouseButtons::NONE)),
+        Some(Event::Mouse(MouseEvent {
+            kind: MouseKind::Moved,
+            column: 2,
+            row: 3,
+            modifiers: Modifiers::NONE,
+        }))
+    );
+    assert_eq!(
+        kind(&mut c, mouse(3, 4, MouseButtons::LEFT)),
+        MouseKind::Down(MouseButton::Left)
+    );
+    assert_eq!(
+        kind(&mut c, mouse(5, 4, MouseButtons::LEFT)),
+        MouseKind::Drag(MouseButton::Left)
+    );
+    assert_eq!(
+        kind(
+            &mut c,
+            mouse(5, 4, MouseButtons::LEFT | MouseButtons::RIGHT)
+        ),
+        MouseKind::Down(MouseButton::Right)
+    );
+    assert_eq!(
+        kind(&mut c, mouse(5, 4, MouseButtons::RIGHT)),
+        MouseKind::Up(MouseButton::Left)
+    );
+    assert_eq!(
+        kind(&mut c, mouse(5, 4, MouseButtons::NONE)),
+        MouseKind::Up(MouseButton::Right)
+    );
+    assert_eq!(
+        kind(&mut c, mouse(6, 4, MouseButtons::NONE)),
+        MouseKind::Moved
+    );
+
+    // the wheel doesn't change the held buttons.
+    assert_eq!(
+        kind(
+            &mut c,
+            mouse(
+                1,
+                1,
+                MouseButtons::VERT_WHEEL | MouseButtons::WHEEL_POSITIVE
+            )
+        ),
+        MouseKind::ScrollUp
+    );
+    assert_eq!(
+        kind(&mut c, mouse(1, 1, MouseButtons::VERT_WHEEL)),
+        MouseKind::ScrollDown
+    );
+    assert_eq!(
+        kind(
+            &mut c,
+            mouse(
+                1,
+                1,
+                MouseButtons::HORZ_WHEEL | MouseButtons::WHEEL_POSITIVE
+            )
+        ),
+        MouseKind::ScrollLeft
+    );
+    assert_eq!(
+        kind(&mut c, mouse(1, 1, MouseButtons::HORZ_WHEEL)),
+        MouseKind::ScrollRight
+    );
+
+    assert_eq!(
+        kind(&mut c, mouse(1, 1, MouseButtons::MIDDLE)),
+        MouseKind::Down(MouseButton::Middle)
+    );
+    c.clear();
+    assert_eq!(
+        kind(&mut c, mouse(1, 1, MouseButtons::MIDDLE)),
+        MouseKind::Down(MouseButton::Middle)
+    );
+}
+
+#[test]
+fn test_mouse_flags() {
+    let mut c = InputConverter::new();
+    let flags = MouseFlags::default();
+    let area = Rect::new(0, 0, 10, 10);
+
+    let mut drag = |event: InputEvent| match c.convert(&event) {
+        Some(Event::Mouse(m)) => flags.drag_event(area, &m),
+        _ => false,
+    };
+    assert!(!drag(mouse(2, 2, MouseButtons::LEFT)));
+    assert!(drag(mouse(20, 2, MouseButtons::LEFT)));
+    assert!(!drag(mouse(20, 2, MouseButtons::NONE)));
+    assert!(!drag(mouse(30, 2, MouseButtons::NONE)));
+
+    let mut c = InputConverter::new();
+    let flags = MouseFlags::default();
+    let mut dclick = |event: InputEvent| match c.convert(&event) {
+        Some(Event::Mouse(m)) => flags.doubleclick_event(area, &m),
+        _ => false,
+    };
+    assert!(!dclick(mouse(2, 2, MouseButtons::LEFT)));
+    assert!(!dclick(mouse(2, 2, MouseButtons::NONE)));
+    assert!(!dclick(mouse(2, 2, MouseButtons::LEFT)));
+    assert!(dclick(mouse(2, 2, MouseButtons::NONE)));
+}
+
+struct Counter(u32);
+
+impl HandleEvent<Event, Regular, Outcome> for Counter {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        match event {
+            Event::Key(k) if k.code == KeyCode::Char('+') => {
+                self.0 += 1;
+                Outcome::Changed
+            }
+            Event::Mouse(m) if m.kind == MouseKind::Down(MouseButton::Left) => {
+                self.0 += 10;
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
+
+#[test]
+fn test_handle() {
+    let mut c = InputConverter::new();
+    let mut counter = Counter(0);
+
+    let r: Outcome = c.handle(
+        &mut counter,
+        &key(input::KeyCode::Char('+'), input::Modifiers::NONE),
+        Regular,
+    );
+    assert_eq!(r, Outcome::Changed);
+    let r: Outcome = c.handle(&mut counter, &mouse(1, 1, MouseButtons::LEFT), Regular);
+    assert_eq!(r, Outcome::Changed);
+    let r: Outcome = c.handle(&mut counter, &mouse(2, 1, MouseButtons::LEFT), Regular);
+    assert_eq!(r, Outcome::Continue);
+    let r: Outcome = c.handle(&mut counter, &InputEvent::Wake, Regular);
+    assert_eq!(r, Outcome::Continue);
+    assert_eq!(counter.0, 11);
+}
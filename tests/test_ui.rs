@@ -0,0 +1,7 @@
+#![cfg(feature = "crossterm")]
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -1,5 +1,6 @@
-use rat_event::util::{column_at, column_at_drag, row_at, row_at_drag};
+use rat_event::util::{clip_hit, column_at, column_at_drag, row_at, row_at_drag, sanitize_paste};
 use ratatui::layout::Rect;
+use std::borrow::Cow;
 
 #[test]
 fn test_rows0() {
@@ -162,3 +163,56 @@ fn test_cols_empty1() {
     assert_eq!(column_at_drag(area, &cols, 19), Err(9));
     assert_eq!(column_at_drag(area, &cols, 16384), Err(16374));
 }
+
+#[test]
+fn test_sanitize_paste() {
+    assert!(matches!(
+        sanitize_paste("plain\ttext\n", true),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(
+        sanitize_paste("line\r\n", false),
+        Cow::Borrowed(_)
+    ));
+
+    // whole escape sequences.
+    assert_eq!(sanitize_paste("a\x1b[31mb\x07c", false), "abc");
+    assert_eq!(sanitize_paste("a\x1b[1;2Hb\x1b[?25lc", false), "abc");
+    assert_eq!(sanitize_paste("x\x1b]0;title\x07y", false), "xy");
+    assert_eq!(
+        sanitize_paste("x\x1b]8;;http://x.y\x1b\\link\x1b]8;;\x1b\\y", false),
+        "xlinky"
+    );
+    assert_eq!(sanitize_paste("a\x1b(Bb\x1bcc", false), "abc");
+    assert_eq!(sanitize_paste("a\u{9b}31mb\u{9d}0;t\u{9c}c", false), "abc");
+    // cut off at the end.
+    assert_eq!(sanitize_paste("a\x1b[31", false), "a");
+    assert_eq!(sanitize_paste("a\x1b]0;title", false), "a");
+    assert_eq!(sanitize_paste("a\x1b", false), "a");
+    // a lone ESC doesn't take the next char with it.
+    assert_eq!(sanitize_paste("a\x1b\nb", false), "a\nb");
+    assert_eq!(sanitize_paste("a\x1b\tb", false), "a\tb");
+    assert_eq!(sanitize_paste("a\x1bäb", false), "aäb");
+    assert_eq!(sanitize_paste("a\x1b\r\nb", true), "a\nb");
+    assert_eq!(sanitize_paste("a\x1b(\nb", false), "a\nb");
+    assert_eq!(
+        sanitize_paste("one\r\ntwo\rthree\n", true),
+        "one\ntwo\nthree\n"
+    );
+    assert_eq!(sanitize_paste("one\r\ntwo\x00", false), "one\r\ntwo");
+    assert_eq!(sanitize_paste("\r\r\n", true), "\n\n");
+}
+
+#[test]
+fn test_clip_hit() {
+    let area = Rect::new(0, 0, 10, 20);
+    let clip = Rect::new(0, 5, 40, 10);
+
+    assert!(clip_hit(area, clip, 0, 5));
+    assert!(clip_hit(area, clip, 9, 14));
+    assert!(!clip_hit(area, clip, 0, 4));
+    assert!(!clip_hit(area, clip, 0, 15));
+    assert!(!clip_hit(area, clip, 10, 6));
+    assert!(!clip_hit(area, Rect::new(20, 0, 5, 5), 0, 0));
+    assert!(clip_hit(area, area, 0, 0));
+}
@@ -0,0 +1,210 @@
+#![cfg(feature = "web")]
+
+use rat_event::event::{KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseKind};
+use rat_event::web::{
+    button_from_buttons, key_from_dom, modifiers_from_dom, mouse_from_dom, wheel_from_dom,
+};
+
+#[test]
+fn test_modifiers_from_dom() {
+    let table = [
+        ((false, false, false, false), Modifiers::NONE),
+        ((true, false, false, false), Modifiers::CONTROL),
+        ((false, true, false, false), Modifiers::ALT),
+        ((false, false, true, false), Modifiers::SHIFT),
+        ((false, false, false, true), Modifiers::SUPER),
+        (
+            (true, true, true, true),
+            Modifiers::CONTROL | Modifiers::ALT | Modifiers::SHIFT | Modifiers::SUPER,
+        ),
+    ];
+    for ((ctrl, alt, shift, meta), m) in table {
+        assert_eq!(
+            modifiers_from_dom(ctrl, alt, shift, meta),
+            m,
+            "{:?}",
+            (ctrl, alt, shift, meta)
+        );
+    }
+}
+
+#[test]
+fn test_key_from_dom() {
+    const N: Modifiers = Modifiers::NONE;
+    const S: Modifiers = Modifiers::SHIFT;
+    const C: Modifiers = Modifiers::CONTROL;
+
+    // key, code, modifiers, result
+    let table = [
+        ("a", "KeyA", N, Some(KeyCode::Char('a'))),
+        ("A", "KeyA", S, Some(KeyCode::Char('A'))),
+        ("a", "KeyA", C, Some(KeyCode::Char('a'))),
+        ("ä", "Quote", N, Some(KeyCode::Char('ä'))),
+        ("€", "KeyE", N, Some(KeyCode::Char('€'))),
+        ("1", "Digit1", N, Some(KeyCode::Char('1'))),
+        (" ", "Space", N, Some(KeyCode::Char(' '))),
+        ("Spacebar", "Space", N, Some(KeyCode::Char(' '))),
+        ("Enter", "Enter", N, Some(KeyCode::Enter)),
+        ("Enter", "NumpadEnter", N, Some(KeyCode::Enter)),
+        ("Backspace", "Backspace", N, Some(KeyCode::Backspace)),
+        ("Tab", "Tab", N, Some(KeyCode::Tab)),
+        ("Tab", "Tab", S, Some(KeyCode::BackTab)),
+        ("Escape", "Escape", N, Some(KeyCode::Esc)),
+        ("Esc", "Escape", N, Some(KeyCode::Esc)),
+        ("ArrowUp", "ArrowUp", N, Some(KeyCode::Up)),
+        ("ArrowDown", "ArrowDown", N, Some(KeyCode::Down)),
+        ("ArrowLeft", "ArrowLeft", C, Some(KeyCode::Left)),
+        ("ArrowRight", "ArrowRight", S, Some(KeyCode::Right)),
+        ("Up", "ArrowUp", N, Some(KeyCode::Up)),
+        ("Home", "Home", N, Some(KeyCode::Home)),
+        ("End", "End", N, Some(KeyCode::End)),
+        ("PageUp", "PageUp", N, Some(KeyCode::PageUp)),
+        ("PageDown", "PageDown", N, Some(KeyCode::PageDown)),
+        ("Delete", "Delete", N, Some(KeyCode::Delete)),
+        ("Del", "Delete", N, Some(KeyCode::Delete)),
+        ("Insert", "Insert", N, Some(KeyCode::Insert)),
+        ("F1", "F1", N, Some(KeyCode::F(1))),
+        ("F5", "F5", N, Some(KeyCode::F(5))),
+        ("F12", "F12", C, Some(KeyCode::F(12))),
+        ("F24", "F24", N, Some(KeyCode::F(24))),
+        ("F25", "F25", N, Some(KeyCode::Other)),
+        ("F0", "F0", N, Some(KeyCode::Other)),
+        ("Fn", "Fn", N, None),
+        // dead keys and composition.
+        ("Dead", "Quote", N, None),
+        ("Dead", "BracketRight", S, None),
+        ("Process", "KeyA", N, None),
+        // modifiers alone.
+        ("Shift", "ShiftLeft", S, None),
+        ("Control", "ControlRight", C, None),
+        ("Alt", "AltLeft", N, None),
+        ("AltGraph", "AltRight", N, None),
+        ("Meta", "MetaLeft", N, None),
+        ("CapsLock", "CapsLock", N, None),
+        // fallback to the physical key.
+        ("Unidentified", "KeyQ", N, Some(KeyCode::Char('q'))),
+        ("Unidentified", "KeyQ", S, Some(KeyCode::Char('Q'))),
+        ("Unidentified", "Digit7", N, Some(KeyCode::Char('7'))),
+        ("Unidentified", "Numpad7", N, Some(KeyCode::Char('7'))),
+        ("Unidentified", "Space", N, Some(KeyCode::Char(' '))),
+        ("Unidentified", "Keyboard", N, None),
+        ("Unidentified", "IntlBackslash", N, None),
+        ("Unidentified", "", N, None),
+        // not part of the model.
+        ("ContextMenu", "ContextMenu", N, Some(KeyCode::Other)),
+        ("PrintScreen", "PrintScreen", N, Some(KeyCode::Other)),
+        ("AudioVolumeUp", "AudioVolumeUp", N, Some(KeyCode::Other)),
+    ];
+
+    for (key, code, m, result) in table {
+        let ctrl = m.contains(C);
+        let shift = m.contains(S);
+        assert_eq!(
+            key_from_dom(key, code, ctrl, false, shift, false),
+            result.map(|c| KeyEvent::new(c, m)),
+            "{:?} {:?}",
+            key,
+            code
+        );
+    }
+}
+
+#[test]
+fn test_mouse_from_dom() {
+    // kind, button, result
+    let table = [
+        ("mousedown", 0, Some(MouseKind::Down(MouseButton::Left))),
+        ("mousedown", 1, Some(MouseKind::Down(MouseButton::Middle))),
+        ("mousedown", 2, Some(MouseKind::Down(MouseButton::Right))),
+        ("mousedown", 3, None),
+        ("mousedown", 4, None),
+        ("mouseup", 0, Some(MouseKind::Up(MouseButton::Left))),
+        ("mouseup", 2, Some(MouseKind::Up(MouseButton::Right))),
+        ("mousemove", -1, Some(MouseKind::Moved)),
+        ("mousemove", 0, Some(MouseKind::Drag(MouseButton::Left))),
+        ("mousemove", 1, Some(MouseKind::Drag(MouseButton::Middle))),
+        ("mousemove", 3, Some(MouseKind::Moved)),
+        ("click", 0, None),
+        ("dblclick", 0, None),
+        ("contextmenu", 2, None),
+        ("mouseenter", -1, None),
+    ];
+
+    for (kind, button, result) in table {
+        assert_eq!(
+            mouse_from_dom(kind, button, 3, 4, Modifiers::ALT),
+            result.map(|kind| MouseEvent {
+                kind,
+                column: 3,
+                row: 4,
+                modifiers: Modifiers::ALT,
+            }),
+            "{:?} {:?}",
+            kind,
+            button
+        );
+    }
+}
+
+#[test]
+fn test_button_from_buttons() {
+    let table = [(0, -1), (1, 0), (2, 2), (4, 1), (3, 0), (6, 2), (8, -1)];
+    for (buttons, button) in table {
+        assert_eq!(button_from_buttons(buttons), button, "{:?}", buttons);
+    }
+
+    assert_eq!(
+        mouse_from_dom("mousemove", button_from_buttons(4), 0, 0, Modifiers::NONE).map(|v| v.kind),
+        Some(MouseKind::Drag(MouseButton::Middle))
+    );
+}
+
+#[test]
+fn test_wheel_from_dom() {
+    let table = [
+        (0.0, 100.0, Some(MouseKind::ScrollDown)),
+        (0.0, -3.0, Some(MouseKind::ScrollUp)),
+        (1.0, 0.0, Some(MouseKind::ScrollRight)),
+        (-1.0, 0.0, Some(MouseKind::ScrollLeft)),
+        (5.0, 1.0, Some(MouseKind::ScrollDown)),
+        (0.0, 0.0, None),
+    ];
+    for (dx, dy, result) in table {
+        assert_eq!(
+            wheel_from_dom(dx, dy, 1, 2, Modifiers::SHIFT),
+            result.map(|kind| MouseEvent {
+                kind,
+                column: 1,
+                row: 2,
+                modifiers: Modifiers::SHIFT,
+            }),
+            "{:?}",
+            (dx, dy)
+        );
+    }
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_web_to_crossterm() {
+    use rat_event::crossterm::event as ct;
+    use rat_event::ct_event;
+
+    let key = key_from_dom("s", "KeyS", true, false, false, false).expect("key");
+    let event = ct::Event::Key(key.into());
+    assert!(matches!(event, ct_event!(key press CONTROL-'s')));
+
+    let key = key_from_dom("Tab", "Tab", false, false, true, false).expect("key");
+    let event = ct::Event::Key(key.into());
+    assert!(matches!(event, ct_event!(keycode press SHIFT-BackTab)));
+
+    let mouse = mouse_from_dom("mousedown", 0, 5, 6, Modifiers::NONE).expect("mouse");
+    let event = ct::Event::Mouse(mouse.into());
+    assert!(matches!(
+        event,
+        ct_event!(mouse down Left for column, row) if column == 5 && row == 6
+    ));
+
+    let key = key_from_dom("ContextMenu", "ContextMenu", false, false, false, false).expect("key");
+    assert_eq!(ct::KeyEvent::from(key).code, ct::KeyCode::Null);
+}
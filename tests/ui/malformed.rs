@@ -0,0 +1,13 @@
+use rat_event::crossterm::event::Event;
+use rat_event::ct_event;
+
+fn handle(event: &Event) -> bool {
+    match event {
+        ct_event!(key CONTROL-'s') => true,
+        ct_event!(keycode press 'a') => true,
+        ct_event!(press 'x') => true,
+        _ => false,
+    }
+}
+
+fn main() {}
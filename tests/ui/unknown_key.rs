@@ -0,0 +1,12 @@
+use rat_event::crossterm::event::Event;
+use rat_event::ct_event;
+
+fn handle(event: &Event) -> bool {
+    match event {
+        ct_event!(keycode press Entr) => true,
+        ct_event!(mouse down Center for _x, _y) => true,
+        _ => false,
+    }
+}
+
+fn main() {}
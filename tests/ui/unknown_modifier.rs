@@ -0,0 +1,11 @@
+use rat_event::crossterm::event::Event;
+use rat_event::ct_event;
+
+fn handle(event: &Event) -> bool {
+    match event {
+        ct_event!(key press CNTRL-'s') => true,
+        _ => false,
+    }
+}
+
+fn main() {}